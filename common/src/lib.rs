@@ -338,6 +338,18 @@ pub trait BalanceOps<AccountId> {
         netuid: NetUid,
         alpha: AlphaBalance,
     ) -> Result<(), DispatchError>;
+    /// Moves exactly `tao` from the coldkey free balance to the subnet account.
+    fn deposit_tao_to_subnet(
+        coldkey: &AccountId,
+        netuid: NetUid,
+        tao: TaoBalance,
+    ) -> Result<(), DispatchError>;
+    /// Moves `tao` from the subnet account to the coldkey free balance.
+    fn withdraw_tao_from_subnet(
+        coldkey: &AccountId,
+        netuid: NetUid,
+        tao: TaoBalance,
+    ) -> Result<(), DispatchError>;
 }
 
 /// Allows to query the current block author
@@ -252,6 +252,7 @@ parameter_types! {
     pub const SubtensorPalletId: PalletId = PalletId(*b"subtensr");
     pub const BurnAccountId: PalletId = PalletId(*b"burntnsr");
     pub const MaxEpochsPerBlock: u8 = 32;
+    pub const EpochHistoryLength: u32 = 20;
 }
 
 impl pallet_subtensor::Config for Test {
@@ -335,6 +336,7 @@ impl pallet_subtensor::Config for Test {
     type SubtensorPalletId = SubtensorPalletId;
     type BurnAccountId = BurnAccountId;
     type InitialMaxEpochsPerBlock = MaxEpochsPerBlock;
+    type EpochHistoryLength = EpochHistoryLength;
     type WeightInfo = ();
     type AlphaAssets = AlphaAssets;
 }
@@ -345,6 +347,9 @@ parameter_types! {
     pub const SwapMaxFeeRate: u16 = 10000; // 15.26%
     pub const SwapMinimumLiquidity: u64 = 1_000;
     pub const SwapMinimumReserve: NonZeroU64 = NonZeroU64::new(100).unwrap();
+    pub const SwapPriceCandlePeriod: u32 = 360;
+    pub const SwapMaxPriceCandles: u32 = 240;
+    pub const SwapMaxPriceSamplesPerBlock: u32 = 32;
 }
 
 impl pallet_subtensor_swap::Config for Test {
@@ -356,6 +361,9 @@ impl pallet_subtensor_swap::Config for Test {
     type MaxFeeRate = SwapMaxFeeRate;
     type MinimumLiquidity = SwapMinimumLiquidity;
     type MinimumReserve = SwapMinimumReserve;
+    type PriceCandlePeriod = SwapPriceCandlePeriod;
+    type MaxPriceCandles = SwapMaxPriceCandles;
+    type MaxPriceSamplesPerBlock = SwapMaxPriceSamplesPerBlock;
     type WeightInfo = ();
 }
 
@@ -613,7 +621,10 @@ mod api_mocks {
     use codec::Compact;
     use pallet_subtensor::rpc_info::delegate_info::DelegateInfo;
     use pallet_subtensor::rpc_info::stake_info::StakeInfo;
-    use pallet_subtensor_swap_runtime_api::{SimSwapResult, SubnetPrice, SwapRuntimeApi};
+    use pallet_subtensor_swap_runtime_api::{
+        AlphaSwapResult, LiquidityPositionInfo, PriceCandle, SimSwapResult, SubnetPrice,
+        SwapRuntimeApi,
+    };
     use sp_runtime::AccountId32;
     use subtensor_custom_rpc_runtime_api::{DelegateInfoRuntimeApi, StakeInfoRuntimeApi};
     use subtensor_runtime_common::{AlphaBalance, NetUid, TaoBalance};
@@ -683,6 +694,34 @@ mod api_mocks {
                     alpha_slippage: 0u64.into(),
                 }
             }
+            fn sim_swap_alpha_for_alpha(
+                _origin_netuid: NetUid,
+                _destination_netuid: NetUid,
+                _alpha: AlphaBalance,
+            ) -> AlphaSwapResult {
+                AlphaSwapResult::default()
+            }
+            fn sim_swap_alpha_for_alpha_limit(
+                _origin_netuid: NetUid,
+                _destination_netuid: NetUid,
+                _alpha: AlphaBalance,
+                _min_alpha_out: AlphaBalance,
+                _allow_partial: bool,
+            ) -> AlphaSwapResult {
+                AlphaSwapResult::default()
+            }
+            fn liquidity_positions(_netuid: NetUid, _coldkey: AccountId32) -> Vec<LiquidityPositionInfo<AccountId32>> {
+                Vec::new()
+            }
+            fn price_candles(_netuid: NetUid, _count: u32) -> Vec<PriceCandle> {
+                Vec::new()
+            }
+            fn current_price_candle(_netuid: NetUid) -> Option<PriceCandle> {
+                None
+            }
+            fn twap_price(_netuid: NetUid, _candles: u32) -> Option<u64> {
+                None
+            }
         }
     }
 }
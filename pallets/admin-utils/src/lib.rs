@@ -118,6 +118,22 @@ pub mod pallet {
             /// Whether pool-side emission injections and chain buys are enabled.
             enabled: bool,
         },
+        /// Event emitted when the consensus algorithm is set for a subnet.
+        ConsensusAlgorithmSet {
+            /// The network identifier.
+            netuid: NetUid,
+            /// The consensus algorithm run by the subnet epoch.
+            algorithm: pallet_subtensor::ConsensusAlgorithm,
+        },
+        /// Event emitted when the hyperparameter overrides of a subnet mechanism are set.
+        MechanismHyperparamsSet {
+            /// The network identifier.
+            netuid: NetUid,
+            /// The mechanism identifier.
+            mecid: MechId,
+            /// The overrides, unset fields fall back to the subnet value.
+            hyperparams: pallet_subtensor::MechanismHyperparams,
+        },
     }
 
     // Errors inform users that something went wrong.
@@ -181,6 +197,8 @@ pub mod pallet {
         AddressMapping,
         /// Voting power precompile
         VotingPower,
+        /// Limit orders precompile
+        LimitOrders,
     }
 
     #[pallet::type_value]
@@ -2293,6 +2311,190 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Sets the consensus algorithm run by the epoch of a subnet.
+        ///
+        /// # Parameters
+        /// - `origin`: The origin of the call, which must be the root account or subnet owner.
+        /// - `netuid`: The unique identifier for the subnet.
+        /// - `algorithm`: The consensus algorithm to run. Bonds are only kept by `Yuma`.
+        #[pallet::call_index(97)]
+        #[pallet::weight((
+            Weight::from_parts(25_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(4))
+                .saturating_add(T::DbWeight::get().writes(2)),
+            DispatchClass::Operational,
+            Pays::Yes,
+        ))]
+        pub fn sudo_set_consensus_algorithm(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            algorithm: pallet_subtensor::ConsensusAlgorithm,
+        ) -> DispatchResult {
+            let maybe_owner = pallet_subtensor::Pallet::<T>::ensure_sn_owner_or_root_with_limits(
+                origin,
+                netuid,
+                &[Hyperparameter::ConsensusAlgorithm.into()],
+            )?;
+            pallet_subtensor::Pallet::<T>::ensure_admin_window_open(netuid)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(!netuid.is_root(), Error::<T>::NotPermittedOnRootSubnet);
+
+            pallet_subtensor::Pallet::<T>::set_consensus_algorithm(netuid, algorithm);
+            Self::deposit_event(Event::ConsensusAlgorithmSet { netuid, algorithm });
+            log::debug!("ConsensusAlgorithmSet( netuid: {netuid:?}, algorithm: {algorithm:?} )");
+
+            pallet_subtensor::Pallet::<T>::record_owner_rl(
+                maybe_owner,
+                netuid,
+                &[Hyperparameter::ConsensusAlgorithm.into()],
+            );
+            Ok(())
+        }
+
+        /// Sets the hyperparameter overrides of a subnet mechanism.
+        ///
+        /// Unset fields fall back to the subnet value. Setting every field to `None` clears the
+        /// overrides. `kappa` and `max_weight_limit` are root-only, a subnet owner must pass them
+        /// unchanged.
+        ///
+        /// # Parameters
+        /// - `origin`: The origin of the call, which must be the root account or subnet owner.
+        /// - `netuid`: The unique identifier for the subnet.
+        /// - `mecid`: The mechanism identifier.
+        /// - `hyperparams`: The overrides of the mechanism.
+        #[pallet::call_index(98)]
+        #[pallet::weight((
+            Weight::from_parts(28_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(5))
+                .saturating_add(T::DbWeight::get().writes(2)),
+            DispatchClass::Operational,
+            Pays::Yes,
+        ))]
+        pub fn sudo_set_mechanism_hyperparams(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            mecid: MechId,
+            hyperparams: pallet_subtensor::MechanismHyperparams,
+        ) -> DispatchResult {
+            let maybe_owner = pallet_subtensor::Pallet::<T>::ensure_sn_owner_or_root_with_limits(
+                origin,
+                netuid,
+                &[Hyperparameter::MechanismHyperparams.into()],
+            )?;
+            pallet_subtensor::Pallet::<T>::ensure_admin_window_open(netuid)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            if maybe_owner.is_some() {
+                let netuid_index =
+                    pallet_subtensor::Pallet::<T>::get_mechanism_storage_index(netuid, mecid);
+                let current =
+                    pallet_subtensor::MechanismHyperparamOverrides::<T>::get(netuid_index);
+                ensure!(
+                    hyperparams.kappa == current.kappa
+                        && hyperparams.max_weight_limit == current.max_weight_limit,
+                    DispatchError::BadOrigin
+                );
+                ensure!(
+                    hyperparams
+                        .bonds_moving_average
+                        .is_none_or(|bonds_moving_average| bonds_moving_average <= 975000),
+                    Error::<T>::BondsMovingAverageMaxReached
+                );
+            }
+
+            pallet_subtensor::Pallet::<T>::do_set_mechanism_hyperparams(
+                netuid,
+                mecid,
+                hyperparams.clone(),
+            )?;
+            log::debug!(
+                "MechanismHyperparamsSet( netuid: {netuid:?}, mecid: {mecid:?}, hyperparams: {hyperparams:?} )"
+            );
+            Self::deposit_event(Event::MechanismHyperparamsSet {
+                netuid,
+                mecid,
+                hyperparams,
+            });
+
+            pallet_subtensor::Pallet::<T>::record_owner_rl(
+                maybe_owner,
+                netuid,
+                &[Hyperparameter::MechanismHyperparams.into()],
+            );
+            Ok(())
+        }
+
+        /// Schedules a hyperparameter change of a subnet, applied at the start of `at_block`.
+        ///
+        /// The change is announced with a `HyperparameterChangeScheduled` event and listed by
+        /// the `subnetInfo_getScheduledHyperparameterChanges` RPC until it is applied. Scheduling
+        /// the same hyperparameter again replaces the pending change. Booleans are passed as 0
+        /// or 1.
+        ///
+        /// `at_block` must be at least one tempo ahead and outside the admin freeze window. The
+        /// change is dropped if, when applied, the subnet is in the freeze window or, for changes
+        /// scheduled by the owner, the owner rate limit of the hyperparameter has not elapsed.
+        /// The rate limit counts from the block the change is applied.
+        ///
+        /// # Parameters
+        /// - `origin`: The origin of the call, which must be the root account or subnet owner.
+        /// - `netuid`: The unique identifier for the subnet.
+        /// - `hyperparameter`: The hyperparameter to change. `Kappa` is root-only.
+        /// - `value`: The new value.
+        /// - `at_block`: The block at which the value is applied.
+        #[pallet::call_index(99)]
+        #[pallet::weight((
+            Weight::from_parts(25_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(5))
+                .saturating_add(T::DbWeight::get().writes(4)),
+            DispatchClass::Operational,
+            Pays::Yes,
+        ))]
+        pub fn schedule_hyperparameter_change(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            hyperparameter: Hyperparameter,
+            value: u64,
+            at_block: u64,
+        ) -> DispatchResult {
+            let maybe_owner = pallet_subtensor::Pallet::<T>::ensure_sn_owner_or_root_with_limits(
+                origin,
+                netuid,
+                &[hyperparameter.into()],
+            )?;
+            pallet_subtensor::Pallet::<T>::ensure_admin_window_open(netuid)?;
+            ensure!(!netuid.is_root(), Error::<T>::NotPermittedOnRootSubnet);
+            if maybe_owner.is_some() {
+                ensure!(
+                    hyperparameter != Hyperparameter::Kappa,
+                    DispatchError::BadOrigin
+                );
+                ensure!(
+                    hyperparameter != Hyperparameter::BondsMovingAverage || value <= 975000,
+                    Error::<T>::BondsMovingAverageMaxReached
+                );
+            }
+
+            pallet_subtensor::Pallet::<T>::do_schedule_hyperparameter_change(
+                netuid,
+                hyperparameter,
+                value,
+                at_block,
+                maybe_owner.is_some(),
+            )?;
+            log::debug!(
+                "HyperparameterChangeScheduled( netuid: {netuid:?}, hyperparameter: {hyperparameter:?}, value: {value:?}, at_block: {at_block:?} )"
+            );
+            Ok(())
+        }
     }
 }
 
@@ -2056,6 +2056,194 @@ fn test_sudo_set_yuma3_enabled() {
     });
 }
 
+#[test]
+fn test_sudo_set_consensus_algorithm() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let sn_owner = U256::from(1);
+        add_network(netuid, 10);
+        assert_eq!(
+            SubtensorModule::get_consensus_algorithm(netuid),
+            ConsensusAlgorithm::Yuma
+        );
+
+        assert_eq!(
+            AdminUtils::sudo_set_consensus_algorithm(
+                <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+                netuid,
+                ConsensusAlgorithm::StakeWeighted
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+
+        assert_ok!(AdminUtils::sudo_set_consensus_algorithm(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            ConsensusAlgorithm::StakeWeighted
+        ));
+        assert_eq!(
+            SubtensorModule::get_consensus_algorithm(netuid),
+            ConsensusAlgorithm::StakeWeighted
+        );
+
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, sn_owner);
+
+        assert_ok!(AdminUtils::sudo_set_consensus_algorithm(
+            <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+            netuid,
+            ConsensusAlgorithm::MedianClipped
+        ));
+        assert_eq!(
+            SubtensorModule::get_consensus_algorithm(netuid),
+            ConsensusAlgorithm::MedianClipped
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_mechanism_hyperparams() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let sn_owner = U256::from(1);
+        let mecid = MechId::from(1);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, sn_owner);
+        MechanismCountCurrent::<Test>::insert(netuid, MechId::from(2));
+        let netuid_index = SubtensorModule::get_mechanism_storage_index(netuid, mecid);
+
+        // Kappa is root-only
+        assert_ok!(AdminUtils::sudo_set_mechanism_hyperparams(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            mecid,
+            pallet_subtensor::MechanismHyperparams {
+                kappa: Some(40_000),
+                ..Default::default()
+            }
+        ));
+        assert_eq!(SubtensorModule::get_mechanism_kappa(netuid_index), 40_000);
+        assert_eq!(
+            AdminUtils::sudo_set_mechanism_hyperparams(
+                <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+                netuid,
+                mecid,
+                pallet_subtensor::MechanismHyperparams {
+                    kappa: Some(10_000),
+                    ..Default::default()
+                }
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+
+        // Owners are held to the subnet bonds moving average limit
+        assert_noop!(
+            AdminUtils::sudo_set_mechanism_hyperparams(
+                <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+                netuid,
+                mecid,
+                pallet_subtensor::MechanismHyperparams {
+                    kappa: Some(40_000),
+                    bonds_moving_average: Some(975_001),
+                    ..Default::default()
+                }
+            ),
+            Error::<Test>::BondsMovingAverageMaxReached
+        );
+
+        assert_ok!(AdminUtils::sudo_set_mechanism_hyperparams(
+            <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+            netuid,
+            mecid,
+            pallet_subtensor::MechanismHyperparams {
+                kappa: Some(40_000),
+                min_allowed_weights: Some(4),
+                liquid_alpha_enabled: Some(true),
+                ..Default::default()
+            }
+        ));
+        assert_eq!(
+            SubtensorModule::get_mechanism_min_allowed_weights(netuid_index),
+            4
+        );
+        assert!(SubtensorModule::get_mechanism_liquid_alpha_enabled(
+            netuid_index
+        ));
+        assert_eq!(
+            SubtensorModule::get_mechanism_min_allowed_weights(
+                SubtensorModule::get_mechanism_storage_index(netuid, MechId::MAIN)
+            ),
+            SubtensorModule::get_min_allowed_weights(netuid)
+        );
+    });
+}
+
+#[test]
+fn test_schedule_hyperparameter_change() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let sn_owner = U256::from(1);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, sn_owner);
+        let at_block = System::block_number() + 10;
+
+        // Kappa is root-only
+        assert_eq!(
+            AdminUtils::schedule_hyperparameter_change(
+                <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+                netuid,
+                Hyperparameter::Kappa,
+                40_000,
+                at_block
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::schedule_hyperparameter_change(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            Hyperparameter::Kappa,
+            40_000,
+            at_block
+        ));
+
+        assert_ok!(AdminUtils::schedule_hyperparameter_change(
+            <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+            netuid,
+            Hyperparameter::ImmunityPeriod,
+            321,
+            at_block
+        ));
+        assert_eq!(
+            SubtensorModule::get_scheduled_hyperparameter_changes(netuid).len(),
+            2
+        );
+
+        // Scheduling again replaces the pending change
+        assert_ok!(AdminUtils::schedule_hyperparameter_change(
+            <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+            netuid,
+            Hyperparameter::ImmunityPeriod,
+            123,
+            at_block
+        ));
+
+        run_to_block(at_block);
+        assert_eq!(SubtensorModule::get_kappa(netuid), 40_000);
+        assert_eq!(SubtensorModule::get_immunity_period(netuid), 123);
+
+        // The owner rate limit counts from the applied change
+        assert_noop!(
+            AdminUtils::schedule_hyperparameter_change(
+                <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+                netuid,
+                Hyperparameter::ImmunityPeriod,
+                321,
+                at_block + 10
+            ),
+            SubtensorError::<Test>::TxRateLimitExceeded
+        );
+    });
+}
+
 #[test]
 fn test_sudo_set_commit_reveal_version() {
     new_test_ext().execute_with(|| {
@@ -0,0 +1,138 @@
+//! RPC interface for the limit orders pallet
+
+use codec::{Decode, Encode};
+use std::sync::Arc;
+
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::{ErrorObjectOwned, error::ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::H256;
+use sp_runtime::{AccountId32, traits::Block as BlockT};
+use subtensor_runtime_common::NetUid;
+
+pub use pallet_limit_orders_runtime_api::{
+    BatchExecutionSummary, LimitOrdersRuntimeApi, OrderFill, OrderStatus, SignedOrder,
+    VersionedOrder,
+};
+
+#[rpc(client, server)]
+pub trait LimitOrdersRpcApi<BlockHash> {
+    #[method(name = "limitOrders_orderStatus")]
+    fn order_status(&self, order_id: H256, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "limitOrders_deriveOrderId")]
+    fn derive_order_id(&self, order: Vec<u8>, at: Option<BlockHash>) -> RpcResult<H256>;
+    #[method(name = "limitOrders_simulateExecution")]
+    fn simulate_execution(
+        &self,
+        netuid: NetUid,
+        orders: Vec<u8>,
+        relayer: Vec<u8>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+    /// The call to runtime failed.
+    RuntimeError(String),
+    /// A SCALE-encoded parameter could not be decoded.
+    DecodeError(String),
+}
+
+impl From<Error> for ErrorObjectOwned {
+    fn from(e: Error) -> Self {
+        match e {
+            Error::RuntimeError(e) => ErrorObject::owned(1, e, None::<()>),
+            Error::DecodeError(e) => ErrorObject::owned(2, e, None::<()>),
+        }
+    }
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError(_) => 1,
+            Error::DecodeError(_) => 2,
+        }
+    }
+}
+
+/// Limit orders RPC implementation.
+pub struct LimitOrders<C, Block> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> LimitOrders<C, Block> {
+    /// Create new `LimitOrders` instance with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block> LimitOrdersRpcApiServer<<Block as BlockT>::Hash> for LimitOrders<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: LimitOrdersRuntimeApi<Block>,
+{
+    fn order_status(
+        &self,
+        order_id: H256,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.order_status(at, order_id) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!("Unable to get order status: {e:?}")).into()),
+        }
+    }
+
+    fn derive_order_id(
+        &self,
+        order: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<H256> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let order = VersionedOrder::<AccountId32>::decode(&mut &order[..])
+            .map_err(|e| Error::DecodeError(format!("Unable to decode order: {e:?}")))?;
+
+        api.derive_order_id(at, order)
+            .map_err(|e| Error::RuntimeError(format!("Unable to derive order id: {e:?}")).into())
+    }
+
+    fn simulate_execution(
+        &self,
+        netuid: NetUid,
+        orders: Vec<u8>,
+        relayer: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let orders = Vec::<SignedOrder<AccountId32>>::decode(&mut &orders[..])
+            .map_err(|e| Error::DecodeError(format!("Unable to decode orders: {e:?}")))?;
+        let relayer = AccountId32::decode(&mut &relayer[..])
+            .map_err(|e| Error::DecodeError(format!("Unable to decode relayer: {e:?}")))?;
+
+        match api.simulate_execution(at, netuid, orders, relayer) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!(
+                "Unable to simulate order execution: {e:?}"
+            ))
+            .into()),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sp_core::H256;
+use sp_runtime::{AccountId32, DispatchError};
+use sp_std::vec::Vec;
+use subtensor_runtime_common::NetUid;
+
+pub use pallet_limit_orders::{
+    BatchExecutionSummary, OrderFill, OrderSide, OrderStatus, SignedOrder, VersionedOrder,
+};
+
+sp_api::decl_runtime_apis! {
+    pub trait LimitOrdersRuntimeApi {
+        /// Status recorded in `Orders` for `order_id`, if any.
+        fn order_status(order_id: H256) -> Option<OrderStatus>;
+        /// The `OrderId` the pallet derives for `order`.
+        fn derive_order_id(order: VersionedOrder<AccountId32>) -> H256;
+        /// Dry-run `execute_batched_orders` as submitted by `relayer`, without
+        /// changing state.
+        fn simulate_execution(
+            netuid: NetUid,
+            orders: Vec<SignedOrder<AccountId32>>,
+            relayer: AccountId32,
+        ) -> Result<BatchExecutionSummary, DispatchError>;
+    }
+}
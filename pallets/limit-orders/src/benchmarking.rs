@@ -8,7 +8,10 @@ use crate::{NetUid, OrderType, Orders};
 use frame_benchmarking::v2::*;
 use frame_system::RawOrigin;
 use sp_core::{Get, H256};
-use sp_runtime::{AccountId32, MultiSignature, Perbill, traits::AccountIdConversion};
+use sp_runtime::{
+    AccountId32, MultiSignature, Perbill,
+    traits::{AccountIdConversion, Convert},
+};
 extern crate alloc;
 use crate::{Call, Config, Pallet};
 use codec::Encode;
@@ -43,6 +46,40 @@ fn benchmark_key(i: u32) -> (sp_core::sr25519::Public, AccountId32) {
     (public, account)
 }
 
+/// Sign a versioned order as EIP-712 typed data using the runtime keystore.
+///
+/// Recovering the signer of an EIP-712 order costs more than verifying an
+/// sr25519 signature, so batch benchmarks sign this way.
+fn sign_order_eip712<T: crate::Config>(
+    public: sp_core::ecdsa::Public,
+    order: &crate::VersionedOrder<T::AccountId>,
+) -> crate::SignedOrder<T::AccountId> {
+    let hash = crate::eip712::signing_hash(order, T::ChainId::get());
+    let sig =
+        sp_io::crypto::ecdsa_sign_prehashed(sp_core::crypto::key_types::ACCOUNT, &public, &hash)
+            .unwrap();
+    crate::SignedOrder {
+        order: order.clone(),
+        signature: MultiSignature::Ecdsa(sig),
+        partial_fill: None,
+    }
+}
+
+/// Generate a deterministic ecdsa key for benchmark index `i` in the runtime
+/// keystore and return it with the account its EIP-712 signatures map to.
+fn benchmark_evm_key<T: crate::Config>(i: u32) -> (sp_core::ecdsa::Public, T::AccountId) {
+    let seed = alloc::format!("//BenchEvmSigner{}", i).into_bytes();
+    let public = sp_io::crypto::ecdsa_generate(sp_core::crypto::key_types::ACCOUNT, Some(seed));
+    // The keystore only hands out the compressed key, so recover the address
+    // from a signature instead of hashing the uncompressed key directly.
+    let probe = [0u8; 32];
+    let sig =
+        sp_io::crypto::ecdsa_sign_prehashed(sp_core::crypto::key_types::ACCOUNT, &public, &probe)
+            .unwrap();
+    let address = crate::eip712::recover_signer(&probe, &sig).unwrap();
+    (public, T::EvmAddressMapping::convert(address))
+}
+
 pub fn order_id<T: crate::Config>(order: &crate::VersionedOrder<T::AccountId>) -> H256 {
     crate::pallet::Pallet::<T>::derive_order_id(order)
 }
@@ -50,12 +87,13 @@ pub fn order_id<T: crate::Config>(order: &crate::VersionedOrder<T::AccountId>) -
 /// Build `n` signed benchmark orders for `netuid`, one per distinct signer.
 ///
 /// For each index `i` in `0..n` the function:
-/// - derives a deterministic sr25519 key via `benchmark_key(i)`,
+/// - derives a deterministic EVM key via `benchmark_evm_key(i)`,
 /// - calls `T::SwapInterface::set_up_acc_for_benchmark` so the account has
 ///   sufficient balance / stake,
 /// - constructs a worst-case `LimitBuy` order (amount = 1 TAO, price = u64::MAX,
 ///   expiry = u64::MAX, fee 1 %, distinct fee recipient), and
-/// - signs it with the generated key.
+/// - signs it with the generated key as EIP-712 typed data, the costlier of
+///   the two signature schemes to check.
 fn make_benchmark_orders<T: crate::Config>(
     n: u32,
     netuid: NetUid,
@@ -65,8 +103,7 @@ fn make_benchmark_orders<T: crate::Config>(
     let mut orders = alloc::vec::Vec::new();
 
     for i in 0..n {
-        let (public, account_id) = benchmark_key(i);
-        let account: T::AccountId = account_id.into();
+        let (public, account) = benchmark_evm_key::<T>(i);
         let fee_recipient: T::AccountId = frame_benchmarking::account("fee_recipient", i, 0);
 
         T::SwapInterface::set_up_acc_for_benchmark(&account, &account);
@@ -86,7 +123,7 @@ fn make_benchmark_orders<T: crate::Config>(
             chain_id: T::ChainId::get(),
             partial_fills_enabled: false,
         });
-        orders.push(sign_order::<T>(public, &order));
+        orders.push(sign_order_eip712::<T>(public, &order));
     }
 
     orders
@@ -178,6 +215,89 @@ mod benchmarks {
         _(RawOrigin::Signed(caller), netuid, bounded_orders);
     }
 
+    /// Worst case: the target book already holds `MaxRestingOrdersPerBook - 1`
+    /// entries, all priced below the new order, so the insert lands at the end.
+    #[benchmark]
+    fn place_order() {
+        let netuid = NetUid::from(1u16);
+        crate::LimitOrdersEnabled::<T>::set(true);
+
+        let (_, account_id) = benchmark_key(0);
+        let account: T::AccountId = account_id.into();
+        T::SwapInterface::set_up_acc_for_benchmark(&account, &account);
+
+        let existing: alloc::vec::Vec<(u64, H256)> = (1..T::MaxRestingOrdersPerBook::get())
+            .map(|i| (i as u64, H256::repeat_byte(i as u8)))
+            .collect();
+        crate::RestingOrderBook::<T>::insert(
+            netuid,
+            OrderType::TakeProfit,
+            frame_support::BoundedVec::try_from(existing).unwrap(),
+        );
+
+        let order = crate::VersionedOrder::V1(crate::Order {
+            signer: account.clone(),
+            hotkey: account.clone(),
+            netuid,
+            order_type: OrderType::TakeProfit,
+            amount: 1_000_000_000u64,
+            limit_price: u64::MAX,
+            expiry: u64::MAX,
+            fee_rate: Perbill::zero(),
+            fee_recipient: account.clone(),
+            relayer: None,
+            max_slippage: None,
+            chain_id: T::ChainId::get(),
+            partial_fills_enabled: false,
+        });
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(account), order.clone());
+
+        assert!(crate::RestingOrders::<T>::contains_key(order_id::<T>(
+            &order
+        )));
+    }
+
+    /// Worst case: the TWAP queue already holds `MaxTwapOrders - 1` entries,
+    /// all due no later than the new order, so the insert lands at the end.
+    #[benchmark]
+    fn place_twap_order() {
+        let netuid = NetUid::from(1u16);
+        crate::LimitOrdersEnabled::<T>::set(true);
+
+        let (_, account_id) = benchmark_key(0);
+        let account: T::AccountId = account_id.into();
+        T::SwapInterface::set_up_acc_for_benchmark(&account, &account);
+
+        let now = frame_system::Pallet::<T>::block_number();
+        let existing: alloc::vec::Vec<_> = (1..T::MaxTwapOrders::get())
+            .map(|i| (now, H256::from_low_u64_be(i as u64)))
+            .collect();
+        crate::TwapQueue::<T>::put(frame_support::BoundedVec::try_from(existing).unwrap());
+
+        let order = crate::VersionedOrder::V1(crate::Order {
+            signer: account.clone(),
+            hotkey: account.clone(),
+            netuid,
+            order_type: OrderType::LimitBuy,
+            amount: 1_000_000_000u64,
+            limit_price: u64::MAX,
+            expiry: u64::MAX,
+            fee_rate: Perbill::zero(),
+            fee_recipient: account.clone(),
+            relayer: None,
+            max_slippage: None,
+            chain_id: T::ChainId::get(),
+            partial_fills_enabled: true,
+        });
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(account), order.clone(), 10, 10u32.into());
+
+        assert!(crate::TwapOrders::<T>::contains_key(order_id::<T>(&order)));
+    }
+
     impl_benchmark_test_suite!(
         Pallet,
         crate::tests::mock::new_test_ext(),
@@ -0,0 +1,132 @@
+//! EIP-712 typed-data hashing for orders signed by EVM wallets.
+//!
+//! An EVM wallet signs an order as `eth_signTypedData_v4` data under the
+//! domain `{ name: DOMAIN_NAME, version: DOMAIN_VERSION, chainId }`. A `V1`
+//! order is presented as an `Order` struct and a `V2` order as an `OrderV2`
+//! struct wrapping it. Account fields are the 32-byte substrate accounts, and
+//! every `Option` field is preceded by a `has*` flag so that no two orders
+//! share a digest.
+
+use sp_core::{H160, U256, ecdsa, hashing::keccak_256};
+use sp_runtime::AccountId32;
+use sp_std::vec::Vec;
+
+use crate::{Order, OrderType, VersionedOrder};
+
+/// `name` field of the EIP-712 domain.
+pub const DOMAIN_NAME: &str = "Bittensor Limit Orders";
+/// `version` field of the EIP-712 domain.
+pub const DOMAIN_VERSION: &str = "1";
+
+macro_rules! order_type {
+    () => {
+        "Order(bytes32 signer,bytes32 hotkey,uint16 netuid,uint8 orderType,uint64 amount,\
+         uint64 limitPrice,uint64 expiry,uint32 feeRate,bytes32 feeRecipient,bool hasRelayers,\
+         bytes32[] relayers,bool hasMaxSlippage,uint32 maxSlippage,uint64 chainId,\
+         bool partialFillsEnabled)"
+    };
+}
+
+const DOMAIN_TYPE: &str = "EIP712Domain(string name,string version,uint256 chainId)";
+const ORDER_TYPE: &str = order_type!();
+// Referenced struct types are appended to the primary type, per EIP-712.
+const ORDER_V2_TYPE: &str = concat!(
+    "OrderV2(Order base,uint32 trailingOffset,bool hasOcoSibling,bytes32 ocoSibling)",
+    order_type!()
+);
+
+/// Digest signed by the EVM wallet for `order` on the chain `chain_id`.
+pub fn signing_hash(order: &VersionedOrder<AccountId32>, chain_id: u64) -> [u8; 32] {
+    let mut data = Vec::with_capacity(66);
+    data.extend_from_slice(b"\x19\x01");
+    data.extend_from_slice(&domain_separator(chain_id));
+    data.extend_from_slice(&struct_hash(order));
+    keccak_256(&data)
+}
+
+/// Address whose key produced `signature` over `hash`, if any. Accepts the
+/// recovery id both as `0`/`1` and as the `27`/`28` returned by EVM wallets.
+pub fn recover_signer(hash: &[u8; 32], signature: &ecdsa::Signature) -> Option<H160> {
+    let bytes: &[u8] = signature.as_ref();
+    let mut signature = <[u8; 65]>::try_from(bytes).ok()?;
+    if let Some(v) = signature.last_mut().filter(|v| **v >= 27) {
+        *v = v.saturating_sub(27);
+    }
+    let public = sp_io::crypto::secp256k1_ecdsa_recover(&signature, hash).ok()?;
+    keccak_256(&public).get(12..).map(H160::from_slice)
+}
+
+fn domain_separator(chain_id: u64) -> [u8; 32] {
+    hash_words(&[
+        keccak_256(DOMAIN_TYPE.as_bytes()),
+        keccak_256(DOMAIN_NAME.as_bytes()),
+        keccak_256(DOMAIN_VERSION.as_bytes()),
+        uint(chain_id),
+    ])
+}
+
+fn struct_hash(order: &VersionedOrder<AccountId32>) -> [u8; 32] {
+    match order {
+        VersionedOrder::V1(order) => order_hash(order),
+        VersionedOrder::V2(order) => hash_words(&[
+            keccak_256(ORDER_V2_TYPE.as_bytes()),
+            order_hash(&order.base),
+            uint(order.trailing_offset.deconstruct().into()),
+            boolean(order.oco_sibling.is_some()),
+            order.oco_sibling.unwrap_or_default().0,
+        ]),
+    }
+}
+
+fn order_hash(order: &Order<AccountId32>) -> [u8; 32] {
+    let relayers: Vec<u8> = order
+        .relayer
+        .iter()
+        .flat_map(|relayers| relayers.iter())
+        .flat_map(account)
+        .collect();
+    hash_words(&[
+        keccak_256(ORDER_TYPE.as_bytes()),
+        account(&order.signer),
+        account(&order.hotkey),
+        uint(u16::from(order.netuid).into()),
+        uint(order_type_index(&order.order_type)),
+        uint(order.amount),
+        uint(order.limit_price),
+        uint(order.expiry),
+        uint(order.fee_rate.deconstruct().into()),
+        account(&order.fee_recipient),
+        boolean(order.relayer.is_some()),
+        keccak_256(&relayers),
+        boolean(order.max_slippage.is_some()),
+        uint(order.max_slippage.unwrap_or_default().deconstruct().into()),
+        uint(order.chain_id),
+        boolean(order.partial_fills_enabled),
+    ])
+}
+
+/// `uint8` value of each order type in the typed data.
+fn order_type_index(order_type: &OrderType) -> u64 {
+    match order_type {
+        OrderType::LimitBuy => 0,
+        OrderType::TakeProfit => 1,
+        OrderType::StopLoss => 2,
+        OrderType::TrailingStop => 3,
+    }
+}
+
+fn account(who: &AccountId32) -> [u8; 32] {
+    who.clone().into()
+}
+
+fn hash_words(words: &[[u8; 32]]) -> [u8; 32] {
+    keccak_256(&words.concat())
+}
+
+fn uint(value: u64) -> [u8; 32] {
+    U256::from(value).to_big_endian()
+}
+
+fn boolean(value: bool) -> [u8; 32] {
+    uint(value.into())
+}
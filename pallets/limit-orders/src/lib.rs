@@ -6,6 +6,7 @@ pub use pallet::*;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod eip712;
 pub(crate) mod migrations;
 #[cfg(test)]
 mod tests;
@@ -16,11 +17,12 @@ type MigrationKeyMaxLen = frame_support::traits::ConstU32<128>;
 use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use frame_support::{BoundedVec, traits::ConstU32};
 use scale_info::TypeInfo;
-use sp_core::H256;
+use sp_core::{H160, H256};
 use sp_runtime::{
     AccountId32, MultiSignature, Perbill,
-    traits::{ConstBool, Verify},
+    traits::{ConstBool, Convert, Verify},
 };
+use sp_std::vec::Vec;
 use substrate_fixed::types::U64F64;
 use subtensor_macros::freeze_struct;
 use subtensor_runtime_common::{AlphaBalance, NetUid, TaoBalance, Token};
@@ -46,6 +48,11 @@ pub enum OrderSide {
 /// | `LimitBuy`   | Buy    | price ≤ limit_price |
 /// | `TakeProfit` | Sell   | price ≥ limit_price |
 /// | `StopLoss`   | Sell   | price ≤ limit_price |
+/// | `TrailingStop` | Sell | price ≤ stop level  |
+///
+/// A `TrailingStop`'s stop level is `peak - peak * trailing_offset`, where
+/// `peak` is the highest price seen since the order was placed, and never
+/// drops below `limit_price`. It is only available as a resting `V2` order.
 #[derive(
     Encode, Decode, DecodeWithMemTracking, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, Debug,
 )]
@@ -53,6 +60,7 @@ pub enum OrderType {
     LimitBuy,
     TakeProfit,
     StopLoss,
+    TrailingStop,
 }
 
 impl OrderType {
@@ -60,6 +68,17 @@ impl OrderType {
     pub fn is_buy(&self) -> bool {
         matches!(self, OrderType::LimitBuy)
     }
+
+    /// `true` if `price` (×10⁹ scale) satisfies the trigger condition for
+    /// `limit_price` (see the table on [`OrderType`]).
+    pub fn is_triggered(&self, price: u64, limit_price: u64) -> bool {
+        match self {
+            OrderType::TakeProfit => price >= limit_price,
+            OrderType::StopLoss | OrderType::TrailingStop | OrderType::LimitBuy => {
+                price <= limit_price
+            }
+        }
+    }
 }
 
 /// The canonical order payload that users sign off-chain.
@@ -109,16 +128,37 @@ pub struct Order<AccountId: Encode + Decode + TypeInfo + MaxEncodedLen + Clone>
     pub partial_fills_enabled: bool,
 }
 
+/// Second version of the order schema: the V1 payload extended with trailing
+/// stops and one-cancels-other links.
+#[allow(clippy::multiple_bound_locations)] // bounds on AccountId required by FRAME derives
+#[freeze_struct("c36efa28e16c24e3")]
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, Debug,
+)]
+pub struct OrderV2<AccountId: Encode + Decode + TypeInfo + MaxEncodedLen + Clone> {
+    /// All V1 fields, with unchanged meaning.
+    pub base: Order<AccountId>,
+    /// `TrailingStop` only: distance of the stop level below the highest price
+    /// seen since placement, as a fraction of that price. Ignored otherwise.
+    pub trailing_offset: Perbill,
+    /// One-cancels-other link: the `OrderId` of another order by the same
+    /// signer. Filling this order cancels the sibling if it is open on-chain
+    /// (resting or TWAP), and this order can no longer execute once the
+    /// sibling has been (partially) filled.
+    pub oco_sibling: Option<H256>,
+}
+
 /// Versioned wrapper around an order payload.
 ///
-/// Adding a new variant in the future (e.g. `V2`) lets the pallet accept orders
-/// signed against either schema simultaneously, preventing old signed orders from
-/// being invalidated by a schema upgrade.
+/// Each variant is a separate schema, so the pallet accepts orders signed
+/// against any of them simultaneously and old signed orders are not
+/// invalidated by a schema upgrade.
 #[derive(
     Encode, Decode, DecodeWithMemTracking, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, Debug,
 )]
 pub enum VersionedOrder<AccountId: Encode + Decode + TypeInfo + MaxEncodedLen + Clone> {
     V1(Order<AccountId>),
+    V2(OrderV2<AccountId>),
 }
 
 impl<AccountId: Encode + Decode + TypeInfo + MaxEncodedLen + Clone> VersionedOrder<AccountId> {
@@ -126,23 +166,44 @@ impl<AccountId: Encode + Decode + TypeInfo + MaxEncodedLen + Clone> VersionedOrd
     pub fn inner(&self) -> &Order<AccountId> {
         match self {
             VersionedOrder::V1(order) => order,
+            VersionedOrder::V2(order) => &order.base,
+        }
+    }
+
+    /// The trailing offset of a V2 `TrailingStop` order, `None` otherwise.
+    pub fn trailing_offset(&self) -> Option<Perbill> {
+        match self {
+            VersionedOrder::V2(order) if order.base.order_type == OrderType::TrailingStop => {
+                Some(order.trailing_offset)
+            }
+            _ => None,
+        }
+    }
+
+    /// The one-cancels-other sibling of a V2 order, if any.
+    pub fn oco_sibling(&self) -> Option<H256> {
+        match self {
+            VersionedOrder::V1(_) => None,
+            VersionedOrder::V2(order) => order.oco_sibling,
         }
     }
 }
 
 /// The envelope the admin submits on-chain: the versioned order payload plus
-/// the user's signature over the SCALE-encoded `VersionedOrder`.
+/// the user's signature.
 ///
-/// Signature verification is performed against `order.inner().signer` (the AccountId)
-/// directly. Only sr25519 signatures are accepted; ed25519 and ecdsa variants
-/// of `MultiSignature` are rejected at validation time.
-#[freeze_struct("9dd5a8ac812dc504")]
+/// Signature verification is performed against `order.inner().signer` (the AccountId).
+/// sr25519 and ecdsa (EIP-712) signatures are accepted; the ed25519 variant of
+/// `MultiSignature` is rejected at validation time.
+#[freeze_struct("6c13b68882665cc0")]
 #[derive(
     Encode, Decode, DecodeWithMemTracking, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, Debug,
 )]
 pub struct SignedOrder<AccountId: Encode + Decode + TypeInfo + MaxEncodedLen + Clone> {
     pub order: VersionedOrder<AccountId>,
-    /// Sr25519 signature over `SCALE_ENCODE(VersionedOrder)`.
+    /// Either an sr25519 signature over `SCALE_ENCODE(VersionedOrder)` by the
+    /// signer, or an ecdsa signature over the order's EIP-712 digest (see
+    /// [`eip712`]) by the EVM address that maps to the signer.
     pub signature: MultiSignature,
     /// Whether we want a partial fill for this order
     pub partial_fill: Option<u64>,
@@ -160,6 +221,65 @@ pub enum OrderStatus {
     Cancelled,
 }
 
+/// An order placed directly on-chain via `place_order`, waiting in the resting
+/// book for its trigger price to be crossed.
+///
+/// Unlike relayed orders, the full payload is stored so that the matcher can
+/// execute it without an off-chain party resubmitting it.
+#[freeze_struct("8f34fd58ebcdb3e3")]
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, Debug)]
+pub struct RestingOrder<AccountId: Encode + Decode + TypeInfo + MaxEncodedLen + Clone> {
+    /// The order payload as placed by the signer.
+    pub order: VersionedOrder<AccountId>,
+    /// TAO held by the pallet account while the order rests. Refunded to the
+    /// signer when the order leaves the book (executed, skipped or cancelled).
+    pub deposit: TaoBalance,
+}
+
+/// A time-weighted order placed via `place_twap_order`: the order's `amount`
+/// is filled in slices of `slice_amount`, one every `interval` blocks, until it
+/// is fully filled or expires.
+#[freeze_struct("b71f9df56d53cc4b")]
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, Debug)]
+pub struct TwapOrder<AccountId: Encode + Decode + TypeInfo + MaxEncodedLen + Clone, BlockNumber> {
+    /// The order payload as placed by the signer.
+    pub order: VersionedOrder<AccountId>,
+    /// Amount filled per slice; the last slice fills whatever remains.
+    pub slice_amount: u64,
+    /// Number of blocks between two slices.
+    pub interval: BlockNumber,
+    /// TAO held by the pallet account until the schedule ends.
+    pub deposit: TaoBalance,
+}
+
+/// Outcome of a single order within a netted batch.
+#[freeze_struct("1e4c796de388ec8d")]
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+pub struct OrderFill {
+    pub order_id: H256,
+    pub order_type: OrderType,
+    /// Input amount: TAO (raw) for buy orders, alpha (raw) for sell orders.
+    pub amount_in: u64,
+    /// Output amount after fee: alpha (raw) for buy orders, TAO (raw) for sell orders.
+    pub amount_out: u64,
+    /// TAO (raw) forwarded to the order's `fee_recipient`.
+    pub fee: u64,
+}
+
+/// Result of a netted batch: the fields of the `GroupExecutionSummary` event
+/// together with the fill of every order. Returned by `simulate_execution`.
+#[freeze_struct("10cc8e6165748afa")]
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+pub struct BatchExecutionSummary {
+    pub netuid: NetUid,
+    pub net_side: OrderSide,
+    pub net_amount: u64,
+    pub actual_out: u64,
+    pub executed_count: u32,
+    /// Buy fills first, then sell fills, each in batch order.
+    pub fills: Vec<OrderFill>,
+}
+
 /// Classified, fee-adjusted entry produced by `validate_and_classify`.
 /// Used in every in-memory batch pipeline step; never stored on-chain.
 #[derive(Debug, PartialEq)]
@@ -186,6 +306,8 @@ pub(crate) struct OrderEntry<AccountId> {
     pub(crate) effective_swap_limit: u64,
     /// Present when this execution covers only part of the order.
     pub(crate) partial_fill: Option<u64>,
+    /// One-cancels-other sibling, cancelled once this order fills.
+    pub(crate) oco_sibling: Option<H256>,
 }
 
 // ── Pallet ───────────────────────────────────────────────────────────────────
@@ -198,11 +320,12 @@ pub mod pallet {
     use frame_support::{
         PalletId,
         pallet_prelude::*,
+        storage::{TransactionOutcome, with_transaction},
         traits::{Get, UnixTime},
         transactional,
     };
     use frame_system::pallet_prelude::*;
-    use sp_runtime::traits::AccountIdConversion;
+    use sp_runtime::traits::{AccountIdConversion, Saturating};
     use sp_std::collections::btree_set::BTreeSet;
     use sp_std::vec::Vec;
 
@@ -244,6 +367,27 @@ pub mod pallet {
         /// EVM-compatible chain ID used to bind orders to a specific chain.
         /// Wire to `pallet_evm_chain_id` in the runtime via `ConfigurableChainId`.
         type ChainId: Get<u64>;
+
+        /// Maximum number of resting orders held in a single (netuid, order type)
+        /// book. Bounds the storage read by the `on_idle` matcher per book.
+        #[pallet::constant]
+        type MaxRestingOrdersPerBook: Get<u32>;
+
+        /// Base TAO deposit for an order placed in the resting book or scheduled
+        /// as a TWAP order. An order whose signer already has `n` orders resting,
+        /// or `n` TWAP orders scheduled, reserves `n + 1` times this amount.
+        /// Refunded in full when the order leaves the book or its schedule ends.
+        #[pallet::constant]
+        type RestingOrderDeposit: Get<TaoBalance>;
+
+        /// Maximum number of TWAP orders scheduled at any one time.
+        #[pallet::constant]
+        type MaxTwapOrders: Get<u32>;
+
+        /// Maps the EVM address that signed an EIP-712 order to the account
+        /// the order must name as its signer. Wire to the `AddressMapping` of
+        /// `pallet_evm` in the runtime.
+        type EvmAddressMapping: Convert<H160, Self::AccountId>;
     }
 
     // ── Storage ───────────────────────────────────────────────────────────────
@@ -254,6 +398,60 @@ pub mod pallet {
     #[pallet::storage]
     pub type Orders<T: Config> = StorageMap<_, Blake2_128Concat, H256, OrderStatus, OptionQuery>;
 
+    /// Full payload of every order placed on-chain via `place_order`, keyed by
+    /// `OrderId`. Removed when the order is executed, skipped or cancelled.
+    #[pallet::storage]
+    pub type RestingOrders<T: Config> =
+        StorageMap<_, Blake2_128Concat, H256, RestingOrder<T::AccountId>, OptionQuery>;
+
+    /// Trigger-price index over `RestingOrders`, one book per (netuid, order type).
+    /// Each book holds `(limit_price, order_id)` pairs sorted ascending by
+    /// `limit_price`, so the matcher only ever inspects one end of the book.
+    #[pallet::storage]
+    pub type RestingOrderBook<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        NetUid,
+        Twox64Concat,
+        OrderType,
+        BoundedVec<(u64, H256), T::MaxRestingOrdersPerBook>,
+        ValueQuery,
+    >;
+
+    /// Number of orders each signer has resting across all books. Scales the
+    /// deposit of the signer's next resting order.
+    #[pallet::storage]
+    pub type RestingOrderCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Every order placed via `place_twap_order` that still has slices left,
+    /// keyed by `OrderId`. Filled amounts are tracked in `Orders`.
+    #[pallet::storage]
+    pub type TwapOrders<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        H256,
+        TwapOrder<T::AccountId, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// `(next_slice_block, order_id)` for every entry of `TwapOrders`, sorted
+    /// ascending by block so the matcher only ever inspects the front.
+    #[pallet::storage]
+    pub type TwapQueue<T: Config> =
+        StorageValue<_, BoundedVec<(BlockNumberFor<T>, H256), T::MaxTwapOrders>, ValueQuery>;
+
+    /// Number of TWAP orders each signer has scheduled. Scales the deposit of
+    /// the signer's next TWAP order.
+    #[pallet::storage]
+    pub type TwapOrderCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Highest price (×10⁹ scale) observed for each resting `TrailingStop`
+    /// order since it was placed. Drives the order's stop level.
+    #[pallet::storage]
+    pub type TrailingStopPeaks<T: Config> = StorageMap<_, Blake2_128Concat, H256, u64, OptionQuery>;
+
     /// Switch to enable/disable the pallet.
     /// Defaults to `false` so bare node deployments are safe; genesis sets it to `true`.
     #[pallet::storage]
@@ -286,7 +484,8 @@ pub mod pallet {
             order_id: H256,
             reason: sp_runtime::DispatchError,
         },
-        /// A user registered a cancellation intent for their order.
+        /// A user registered a cancellation intent for their order, or the order
+        /// was cancelled by the fill of its one-cancels-other sibling.
         OrderCancelled {
             order_id: H256,
             signer: T::AccountId,
@@ -308,6 +507,23 @@ pub mod pallet {
         },
         /// Root has either enabled(true) or disabled(false) the pallet
         LimitOrdersPalletStatusChanged { enabled: bool },
+        /// An order was placed in the on-chain resting book.
+        OrderPlaced {
+            order_id: H256,
+            signer: T::AccountId,
+            netuid: NetUid,
+            order_type: OrderType,
+            limit_price: u64,
+        },
+        /// A time-weighted order was scheduled via `place_twap_order`.
+        TwapOrderPlaced {
+            order_id: H256,
+            signer: T::AccountId,
+            netuid: NetUid,
+            order_type: OrderType,
+            slice_amount: u64,
+            interval: BlockNumberFor<T>,
+        },
     }
 
     // ── Errors ────────────────────────────────────────────────────────────────
@@ -357,6 +573,28 @@ pub mod pallet {
         /// delivering any output (conservation), and the order stays retryable in a
         /// differently-composed batch.
         ZeroShareInBatch,
+        /// The resting book for this (netuid, order type) is full.
+        RestingOrderBookFull,
+        /// The order is already resting on-chain, in the book or as a TWAP order.
+        OrderAlreadyResting,
+        /// Resting orders are filled in full by the chain itself, so they may not
+        /// restrict relayers or enable partial fills.
+        UnsupportedRestingOrder,
+        /// `TrailingStop` orders must be V2 orders placed via `place_order`, since
+        /// only the resting book tracks their peak price. TWAP orders must be
+        /// `LimitBuy` or `TakeProfit`.
+        UnsupportedOrderType,
+        /// The order's one-cancels-other sibling has already been filled.
+        OcoSiblingFilled,
+        /// An order and its one-cancels-other sibling appear in the same batch.
+        OcoSiblingInBatch,
+        /// A TWAP schedule needs at least one slice, no more slices than the
+        /// order amount, and a non-zero interval.
+        InvalidTwapSchedule,
+        /// The maximum number of TWAP orders is already scheduled.
+        TwapQueueFull,
+        /// A simulated batch holds more than `MaxOrdersPerBatch` orders.
+        BatchTooLarge,
     }
 
     // ── Hooks ─────────────────────────────────────────────────────────────────
@@ -395,6 +633,14 @@ pub mod pallet {
 
             weight
         }
+
+        fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let weight_used = Self::match_resting_orders(remaining_weight);
+            weight_used.saturating_add(Self::execute_due_twap_slices(
+                n,
+                remaining_weight.saturating_sub(weight_used),
+            ))
+        }
     }
 
     // ── Extrinsics ────────────────────────────────────────────────────────────
@@ -477,14 +723,17 @@ pub mod pallet {
                 Error::<T>::LimitOrdersDisabled
             );
 
-            Self::do_execute_batched_orders(netuid, orders, relayer)
+            Self::do_execute_batched_orders(netuid, orders, relayer).map(|_| ())
         }
 
         /// Register a cancellation intent for an order.
         ///
         /// Must be called by the order's signer. The full `Order` payload is
         /// provided so the pallet can derive the `OrderId`. Once marked
-        /// Cancelled, the order can never be executed.
+        /// Cancelled, the order can never be executed. If the order is resting in
+        /// the on-chain book or scheduled as a TWAP order it is removed and its
+        /// deposit refunded; a TWAP order can be cancelled after its first slice,
+        /// which cancels the unfilled remainder.
         #[pallet::call_index(2)]
         #[pallet::weight(T::WeightInfo::cancel_order())]
         pub fn cancel_order(
@@ -496,12 +745,20 @@ pub mod pallet {
 
             let order_id = Self::derive_order_id(&order);
 
+            // A running TWAP schedule is `PartiallyFilled` once its first slice
+            // has filled, and stopping it is the only way to cancel its remainder.
+            // Other partially filled orders stay uncancellable, as before.
+            let status = Orders::<T>::get(order_id);
             ensure!(
-                Orders::<T>::get(order_id).is_none(),
+                status.is_none()
+                    || (matches!(status, Some(OrderStatus::PartiallyFilled(_)))
+                        && TwapOrders::<T>::contains_key(order_id)),
                 Error::<T>::OrderAlreadyProcessed
             );
 
             Orders::<T>::insert(order_id, OrderStatus::Cancelled);
+            Self::remove_resting_order(order_id);
+            Self::remove_twap_order(order_id);
             Self::deposit_event(Event::OrderCancelled {
                 order_id,
                 signer: who,
@@ -536,6 +793,216 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Place an order in the on-chain resting book.
+        ///
+        /// Must be called by the order's signer; the signed origin authorises the
+        /// order, so no off-chain signature is required. The order rests until the
+        /// `on_idle` matcher observes the pool price crossing `limit_price`, at
+        /// which point it is executed in full exactly as `execute_orders` would
+        /// execute it. Orders that have expired or fail to execute by then are
+        /// dropped from the book with `OrderSkipped`.
+        ///
+        /// `TrailingStop` orders can only be placed this way: their peak starts at
+        /// the current pool price and is raised by the matcher as the price rises.
+        ///
+        /// A deposit is moved to the pallet account for as long as the order rests
+        /// and is refunded when it leaves the book. It is `RestingOrderDeposit`
+        /// times the number of orders the signer has resting once this one is
+        /// added, so each further order of the same signer gets more expensive.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::place_order())]
+        pub fn place_order(
+            origin: OriginFor<T>,
+            order: VersionedOrder<T::AccountId>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                LimitOrdersEnabled::<T>::get(),
+                Error::<T>::LimitOrdersDisabled
+            );
+
+            let inner = order.inner();
+            ensure!(inner.signer == who, Error::<T>::Unauthorized);
+            ensure!(!inner.netuid.is_root(), Error::<T>::RootNetUidNotAllowed);
+            ensure!(
+                inner.chain_id == T::ChainId::get(),
+                Error::<T>::ChainIdMismatch
+            );
+            ensure!(
+                inner.relayer.is_none() && !inner.partial_fills_enabled,
+                Error::<T>::UnsupportedRestingOrder
+            );
+            let now_ms = T::TimeProvider::now().as_millis() as u64;
+            ensure!(now_ms <= inner.expiry, Error::<T>::OrderExpired);
+
+            let order_id = Self::derive_order_id(&order);
+            ensure!(
+                Orders::<T>::get(order_id).is_none(),
+                Error::<T>::OrderAlreadyProcessed
+            );
+            ensure!(
+                !RestingOrders::<T>::contains_key(order_id),
+                Error::<T>::OrderAlreadyResting
+            );
+            ensure!(
+                !Self::oco_sibling_filled(&order),
+                Error::<T>::OcoSiblingFilled
+            );
+
+            // Trailing stops are indexed by their current stop level rather than
+            // the signed `limit_price`, which only acts as its floor.
+            let book_price = match order.trailing_offset() {
+                Some(offset) => {
+                    let peak =
+                        Self::scale_price(T::SwapInterface::current_alpha_price(inner.netuid));
+                    TrailingStopPeaks::<T>::insert(order_id, peak);
+                    Self::trailing_stop_level(inner.limit_price, offset, peak)
+                }
+                None => {
+                    ensure!(
+                        inner.order_type != OrderType::TrailingStop,
+                        Error::<T>::UnsupportedOrderType
+                    );
+                    inner.limit_price
+                }
+            };
+
+            RestingOrderBook::<T>::try_mutate(inner.netuid, &inner.order_type, |book| {
+                let pos = book.partition_point(|(price, _)| *price <= book_price);
+                book.try_insert(pos, (book_price, order_id))
+                    .map_err(|_| Error::<T>::RestingOrderBookFull)
+            })?;
+
+            let open_orders = RestingOrderCount::<T>::get(&who);
+            let deposit = Self::open_orders_deposit(open_orders);
+            if !deposit.is_zero() {
+                T::SwapInterface::transfer_tao(&who, &Self::pallet_account(), deposit)?;
+            }
+            RestingOrderCount::<T>::insert(&who, open_orders.saturating_add(1));
+
+            RestingOrders::<T>::insert(
+                order_id,
+                RestingOrder {
+                    order: order.clone(),
+                    deposit,
+                },
+            );
+            Self::deposit_event(Event::OrderPlaced {
+                order_id,
+                signer: who,
+                netuid: inner.netuid,
+                order_type: inner.order_type.clone(),
+                limit_price: inner.limit_price,
+            });
+
+            Ok(())
+        }
+
+        /// Schedule a time-weighted (TWAP) order.
+        ///
+        /// Must be called by the order's signer, which authorises the order in
+        /// place of an off-chain signature. The order's `amount` is split into
+        /// `slices` slices, filled one at a time by the `on_idle` matcher: the
+        /// first in the current block, then one every `interval` blocks until the
+        /// order is fully filled or expires.
+        ///
+        /// Each slice is a partial fill, so the order must have
+        /// `partial_fills_enabled` and its progress is recorded in `Orders` as
+        /// `PartiallyFilled`. A slice only fills while the price condition holds
+        /// (`LimitBuy`: price ≤ `limit_price`, `TakeProfit`: price ≥ `limit_price`)
+        /// and its swap is bounded by `max_slippage`; otherwise it is skipped
+        /// with `OrderSkipped` and retried at the next interval.
+        ///
+        /// A deposit is held by the pallet account until the schedule ends. It is
+        /// `RestingOrderDeposit` times the number of TWAP orders the signer has
+        /// scheduled once this one is added, so each further order of the same
+        /// signer gets more expensive.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::place_twap_order())]
+        pub fn place_twap_order(
+            origin: OriginFor<T>,
+            order: VersionedOrder<T::AccountId>,
+            slices: u32,
+            interval: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                LimitOrdersEnabled::<T>::get(),
+                Error::<T>::LimitOrdersDisabled
+            );
+
+            let inner = order.inner();
+            ensure!(inner.signer == who, Error::<T>::Unauthorized);
+            ensure!(!inner.netuid.is_root(), Error::<T>::RootNetUidNotAllowed);
+            ensure!(
+                inner.chain_id == T::ChainId::get(),
+                Error::<T>::ChainIdMismatch
+            );
+            ensure!(
+                matches!(
+                    inner.order_type,
+                    OrderType::LimitBuy | OrderType::TakeProfit
+                ),
+                Error::<T>::UnsupportedOrderType
+            );
+            ensure!(inner.relayer.is_none(), Error::<T>::UnsupportedRestingOrder);
+            ensure!(
+                inner.partial_fills_enabled,
+                Error::<T>::PartialFillsNotEnabled
+            );
+            ensure!(
+                slices > 0 && u64::from(slices) <= inner.amount && !interval.is_zero(),
+                Error::<T>::InvalidTwapSchedule
+            );
+            let now_ms = T::TimeProvider::now().as_millis() as u64;
+            ensure!(now_ms <= inner.expiry, Error::<T>::OrderExpired);
+
+            let order_id = Self::derive_order_id(&order);
+            ensure!(
+                Orders::<T>::get(order_id).is_none(),
+                Error::<T>::OrderAlreadyProcessed
+            );
+            ensure!(
+                !TwapOrders::<T>::contains_key(order_id),
+                Error::<T>::OrderAlreadyResting
+            );
+            ensure!(
+                !Self::oco_sibling_filled(&order),
+                Error::<T>::OcoSiblingFilled
+            );
+
+            let now = frame_system::Pallet::<T>::block_number();
+            Self::enqueue_twap_slice(now, order_id).map_err(|_| Error::<T>::TwapQueueFull)?;
+
+            let open_orders = TwapOrderCount::<T>::get(&who);
+            let deposit = Self::open_orders_deposit(open_orders);
+            if !deposit.is_zero() {
+                T::SwapInterface::transfer_tao(&who, &Self::pallet_account(), deposit)?;
+            }
+            TwapOrderCount::<T>::insert(&who, open_orders.saturating_add(1));
+
+            let slice_amount = inner.amount.div_ceil(u64::from(slices));
+            TwapOrders::<T>::insert(
+                order_id,
+                TwapOrder {
+                    order: order.clone(),
+                    slice_amount,
+                    interval,
+                    deposit,
+                },
+            );
+            Self::deposit_event(Event::TwapOrderPlaced {
+                order_id,
+                signer: who,
+                netuid: inner.netuid,
+                order_type: inner.order_type.clone(),
+                slice_amount,
+                interval,
+            });
+
+            Ok(())
+        }
     }
 
     // ── Internal helpers ──────────────────────────────────────────────────────
@@ -572,6 +1039,105 @@ pub mod pallet {
             }
         }
 
+        /// Scale a raw TAO/alpha price to the ×10⁹ representation used by
+        /// `limit_price` (same scale as the `current_alpha_price` RPC endpoint).
+        pub(crate) fn scale_price(price: U64F64) -> u64 {
+            price
+                .saturating_mul(U64F64::from_num(1_000_000_000u64))
+                .saturating_to_num::<u64>()
+        }
+
+        /// Stop level of a trailing stop whose highest observed price is `peak`:
+        /// `peak - peak * offset`, never below `floor`.
+        pub(crate) fn trailing_stop_level(floor: u64, offset: Perbill, peak: u64) -> u64 {
+            peak.saturating_sub(offset.mul_floor(peak)).max(floor)
+        }
+
+        /// `true` once the one-cancels-other sibling of `order` has been filled,
+        /// in full or in part.
+        pub(crate) fn oco_sibling_filled(order: &VersionedOrder<T::AccountId>) -> bool {
+            order.oco_sibling().is_some_and(|sibling| {
+                matches!(
+                    Orders::<T>::get(sibling),
+                    Some(OrderStatus::Fulfilled | OrderStatus::PartiallyFilled(_))
+                )
+            })
+        }
+
+        /// Cancel the one-cancels-other sibling of an order `signer` has just filled.
+        ///
+        /// Only a sibling that is open on-chain (resting or TWAP) and belongs to
+        /// `signer` is cancelled. Any other sibling id is ignored, so an order
+        /// cannot be used to cancel an order it does not own.
+        fn cancel_oco_sibling(signer: &T::AccountId, sibling: Option<H256>) {
+            let Some(sibling) = sibling else {
+                return;
+            };
+            if Orders::<T>::contains_key(sibling) {
+                return;
+            }
+            if let Some(resting) = RestingOrders::<T>::get(sibling)
+                && resting.order.inner().signer == *signer
+            {
+                Self::remove_resting_order(sibling);
+            } else if let Some(twap) = TwapOrders::<T>::get(sibling)
+                && twap.order.inner().signer == *signer
+            {
+                Self::remove_twap_order(sibling);
+            } else {
+                return;
+            }
+            Orders::<T>::insert(sibling, OrderStatus::Cancelled);
+            Self::deposit_event(Event::OrderCancelled {
+                order_id: sibling,
+                signer: signer.clone(),
+            });
+        }
+
+        /// Dry-run `execute_batched_orders` for `orders` as if submitted by
+        /// `relayer`, returning the batch summary or the error the extrinsic
+        /// would fail with. All storage changes are rolled back.
+        ///
+        /// Used by the runtime API so relayers can validate a batch before
+        /// submitting it.
+        pub fn simulate_execution(
+            netuid: NetUid,
+            orders: Vec<SignedOrder<T::AccountId>>,
+            relayer: T::AccountId,
+        ) -> Result<BatchExecutionSummary, DispatchError> {
+            ensure!(
+                LimitOrdersEnabled::<T>::get(),
+                Error::<T>::LimitOrdersDisabled
+            );
+            let orders = BoundedVec::<_, T::MaxOrdersPerBatch>::try_from(orders)
+                .map_err(|_| Error::<T>::BatchTooLarge)?;
+
+            with_transaction(|| {
+                TransactionOutcome::Rollback(Self::do_execute_batched_orders(
+                    netuid, orders, relayer,
+                ))
+            })
+        }
+
+        /// Check the signature of `signed_order` against its signer. Sr25519
+        /// signatures cover the SCALE-encoded order; ecdsa signatures cover its
+        /// EIP-712 digest and must recover to an EVM address mapped to the
+        /// signer. Ed25519 signatures are rejected.
+        pub(crate) fn verify_signature(signed_order: &SignedOrder<T::AccountId>) -> bool {
+            let signer = &signed_order.order.inner().signer;
+            match &signed_order.signature {
+                MultiSignature::Sr25519(_) => signed_order
+                    .signature
+                    .verify(signed_order.order.encode().as_slice(), signer),
+                MultiSignature::Ecdsa(signature) => {
+                    let hash = eip712::signing_hash(&signed_order.order, T::ChainId::get());
+                    eip712::recover_signer(&hash, signature)
+                        .is_some_and(|address| T::EvmAddressMapping::convert(address) == *signer)
+                }
+                MultiSignature::Ed25519(_) => false,
+            }
+        }
+
         /// Derive the on-chain `OrderId` as blake2_256 over the SCALE-encoded order.
         pub fn derive_order_id(order: &VersionedOrder<T::AccountId>) -> H256 {
             H256(sp_core::hashing::blake2_256(&order.encode()))
@@ -582,6 +1148,19 @@ pub mod pallet {
             T::PalletId::get().into_account_truncating()
         }
 
+        /// Deposit owed for a new order of a signer that already has
+        /// `open_orders` orders of the same kind: one `RestingOrderDeposit` per
+        /// order, the new one included, so each further order costs more than
+        /// the last.
+        pub(crate) fn open_orders_deposit(open_orders: u32) -> TaoBalance {
+            let entries = u64::from(open_orders).saturating_add(1);
+            TaoBalance::from(
+                T::RestingOrderDeposit::get()
+                    .to_u64()
+                    .saturating_mul(entries),
+            )
+        }
+
         /// Transfer `fee_tao` from `signer` to `recipient`.
         /// Returns an error if the transfer fails, causing the surrounding operation to revert.
         /// Does nothing when `fee_tao` is zero.
@@ -613,11 +1192,14 @@ pub mod pallet {
                 order.chain_id == T::ChainId::get(),
                 Error::<T>::ChainIdMismatch
             );
+            // Trailing stops need their peak tracked since placement, which only
+            // the resting book does.
+            ensure!(
+                order.order_type != OrderType::TrailingStop,
+                Error::<T>::UnsupportedOrderType
+            );
             ensure!(
-                matches!(signed_order.signature, MultiSignature::Sr25519(_))
-                    && signed_order
-                        .signature
-                        .verify(signed_order.order.encode().as_slice(), &order.signer),
+                Self::verify_signature(signed_order),
                 Error::<T>::InvalidSignature
             );
             let order_status = Orders::<T>::get(order_id);
@@ -630,18 +1212,19 @@ pub mod pallet {
                 Error::<T>::OrderCancelled
             );
             ensure!(now_ms <= order.expiry, Error::<T>::OrderExpired);
+            ensure!(
+                !Self::oco_sibling_filled(&signed_order.order),
+                Error::<T>::OcoSiblingFilled
+            );
             // Scale current_price to ×10⁹ to match the limit_price field, which is
             // expressed in the same ×10⁹ scale as the `current_alpha_price` RPC endpoint.
             // This allows sub-unity prices (e.g. 0.5 TAO/alpha = 500_000_000) to be
             // represented and compared correctly.
-            let scaled_price = current_price
-                .saturating_mul(U64F64::from_num(1_000_000_000u64))
-                .saturating_to_num::<u64>();
+            let scaled_price = Self::scale_price(current_price);
             ensure!(
-                match order.order_type {
-                    OrderType::TakeProfit => scaled_price >= order.limit_price,
-                    OrderType::StopLoss | OrderType::LimitBuy => scaled_price <= order.limit_price,
-                },
+                order
+                    .order_type
+                    .is_triggered(scaled_price, order.limit_price),
                 Error::<T>::PriceConditionNotMet
             );
             if let Some(forced_relayers) = order.relayer.as_ref() {
@@ -715,11 +1298,6 @@ pub mod pallet {
 
         /// Attempt to execute one signed order. Returns an error on any
         /// validation or execution failure without panicking.
-        ///
-        /// `#[transactional]` makes the whole body a single storage layer: the
-        /// swap (`buy_alpha`/`sell_alpha`, themselves transactional), the fee
-        /// transfer, and the `Orders::insert` either all commit together or all
-        /// roll back together.
         #[transactional]
         fn try_execute_order(
             signed_order: SignedOrder<T::AccountId>,
@@ -732,6 +1310,23 @@ pub mod pallet {
 
             Self::is_order_valid(&signed_order, order_id, now_ms, current_price, relayer)?;
 
+            Self::execute_order(&signed_order.order, order_id, signed_order.partial_fill)
+        }
+
+        /// Execute one order whose preconditions have already been checked,
+        /// filling `partial_fill` (or the full `order.amount` when `None`).
+        ///
+        /// `#[transactional]` makes the whole body a single storage layer: the
+        /// swap (`buy_alpha`/`sell_alpha`, themselves transactional), the fee
+        /// transfer, the `Orders::insert` and any one-cancels-other cancellation
+        /// either all commit together or all roll back together.
+        #[transactional]
+        fn execute_order(
+            versioned: &VersionedOrder<T::AccountId>,
+            order_id: H256,
+            partial_fill: Option<u64>,
+        ) -> DispatchResult {
+            let order = versioned.inner();
             let effective_swap_limit = Self::compute_effective_swap_limit(
                 order.order_type.is_buy(),
                 order.limit_price,
@@ -744,7 +1339,7 @@ pub mod pallet {
             // limit is u64::MAX (buys) or 0 (sells), matching previous market-order behaviour.
             let (amount_in, amount_out) = if order.order_type.is_buy() {
                 // partial fill validations have passed, it is safe here to do this
-                let tao_in = TaoBalance::from(signed_order.partial_fill.unwrap_or(order.amount));
+                let tao_in = TaoBalance::from(partial_fill.unwrap_or(order.amount));
                 // Deduct fee from TAO input before swapping.
                 let fee_tao = TaoBalance::from(order.fee_rate.mul_floor(tao_in.to_u64()));
                 let tao_after_fee = tao_in.saturating_sub(fee_tao);
@@ -763,8 +1358,7 @@ pub mod pallet {
                 (tao_after_fee.to_u64(), alpha_out.to_u64())
             } else {
                 // partial fill validations have passed, it is safe here to do this
-                let alpha_in =
-                    AlphaBalance::from(signed_order.partial_fill.unwrap_or(order.amount));
+                let alpha_in = AlphaBalance::from(partial_fill.unwrap_or(order.amount));
 
                 // Sell the full alpha amount; fee is taken from the TAO output.
                 let tao_out = T::SwapInterface::sell_alpha(
@@ -783,8 +1377,7 @@ pub mod pallet {
             };
 
             // Mark as fulfilled or partially filled and emit event.
-            let status =
-                Self::compute_order_status(order_id, signed_order.partial_fill, order.amount);
+            let status = Self::compute_order_status(order_id, partial_fill, order.amount);
             Orders::<T>::insert(order_id, status);
             Self::deposit_event(Event::OrderExecuted {
                 order_id,
@@ -794,21 +1387,329 @@ pub mod pallet {
                 amount_in,
                 amount_out,
             });
+            Self::cancel_oco_sibling(&order.signer, versioned.oco_sibling());
 
             Ok(())
         }
 
+        /// Fire resting orders whose trigger price has been crossed, spending at
+        /// most `remaining_weight`.
+        ///
+        /// Within a book the order closest to the current price fires first, and
+        /// the price is re-read after every execution so that one fill moving the
+        /// pool can trigger the next order in line.
+        pub(crate) fn match_resting_orders(remaining_weight: Weight) -> Weight {
+            let db_weight = T::DbWeight::get();
+            let mut weight_used = db_weight.reads(1);
+            if !LimitOrdersEnabled::<T>::get() {
+                return weight_used;
+            }
+
+            // Pop from the book, take the payload, refund the deposit, then execute.
+            let per_order =
+                T::WeightInfo::execute_orders(1).saturating_add(db_weight.reads_writes(3, 3));
+            let now_ms = T::TimeProvider::now().as_millis() as u64;
+            let books: Vec<(NetUid, OrderType)> = RestingOrderBook::<T>::iter_keys().collect();
+            weight_used = weight_used.saturating_add(db_weight.reads(books.len() as u64));
+
+            let max_book_len = u64::from(T::MaxRestingOrdersPerBook::get());
+            // Per entry: read the peak, then read the payload and write the new peak.
+            let max_ratchet = db_weight.reads_writes(
+                max_book_len.saturating_mul(2).saturating_add(1),
+                max_book_len.saturating_add(1),
+            );
+
+            for (netuid, order_type) in books {
+                if order_type == OrderType::TrailingStop {
+                    if weight_used
+                        .saturating_add(max_ratchet)
+                        .any_gt(remaining_weight)
+                    {
+                        return weight_used;
+                    }
+                    let price = Self::scale_price(T::SwapInterface::current_alpha_price(netuid));
+                    weight_used =
+                        weight_used.saturating_add(Self::ratchet_trailing_stops(netuid, price));
+                }
+                loop {
+                    if weight_used
+                        .saturating_add(per_order)
+                        .any_gt(remaining_weight)
+                    {
+                        return weight_used;
+                    }
+                    let price = Self::scale_price(T::SwapInterface::current_alpha_price(netuid));
+                    let Some(order_id) = Self::pop_triggered(netuid, &order_type, price) else {
+                        weight_used = weight_used.saturating_add(db_weight.reads(2));
+                        break;
+                    };
+                    weight_used = weight_used.saturating_add(per_order);
+                    Self::fire_resting_order(order_id, now_ms);
+                }
+            }
+
+            weight_used
+        }
+
+        /// Raise the stop level of every trailing stop resting on `netuid` whose
+        /// peak `price` now exceeds, keeping the book sorted.
+        fn ratchet_trailing_stops(netuid: NetUid, price: u64) -> Weight {
+            let db_weight = T::DbWeight::get();
+            let mut weight = db_weight.reads_writes(1, 1);
+            RestingOrderBook::<T>::mutate_exists(netuid, OrderType::TrailingStop, |maybe_book| {
+                let Some(book) = maybe_book.as_mut() else {
+                    return;
+                };
+                let mut entries = core::mem::take(book).into_inner();
+                let mut raised = false;
+                for (stop_level, order_id) in entries.iter_mut() {
+                    weight = weight.saturating_add(db_weight.reads(1));
+                    if TrailingStopPeaks::<T>::get(*order_id).is_none_or(|peak| price <= peak) {
+                        continue;
+                    }
+                    weight = weight.saturating_add(db_weight.reads_writes(1, 1));
+                    let Some(offset) = RestingOrders::<T>::get(*order_id)
+                        .and_then(|resting| resting.order.trailing_offset())
+                    else {
+                        continue;
+                    };
+                    TrailingStopPeaks::<T>::insert(*order_id, price);
+                    *stop_level = Self::trailing_stop_level(*stop_level, offset, price);
+                    raised = true;
+                }
+                if raised {
+                    // Stable, so orders at the same level keep their placement order.
+                    entries.sort_by_key(|(stop_level, _)| *stop_level);
+                }
+                *book = BoundedVec::truncate_from(entries);
+            });
+            weight
+        }
+
+        /// Pop the next triggered entry from the `(netuid, order_type)` book.
+        ///
+        /// Books are sorted ascending by `limit_price` (the stop level for
+        /// trailing stops): `TakeProfit` orders (price ≥ limit) fire from the
+        /// low end, all other orders (price ≤ limit) from the high end.
+        fn pop_triggered(netuid: NetUid, order_type: &OrderType, price: u64) -> Option<H256> {
+            RestingOrderBook::<T>::mutate_exists(netuid, order_type, |maybe_book| {
+                let book = maybe_book.as_mut()?;
+                let fires_from_low_end = matches!(order_type, OrderType::TakeProfit);
+                let (limit_price, order_id) = if fires_from_low_end {
+                    *book.first()?
+                } else {
+                    *book.last()?
+                };
+                if !order_type.is_triggered(price, limit_price) {
+                    return None;
+                }
+                if fires_from_low_end {
+                    book.remove(0);
+                } else {
+                    book.pop();
+                }
+                if book.is_empty() {
+                    *maybe_book = None;
+                }
+                Some(order_id)
+            })
+        }
+
+        /// Execute a resting order that has just been popped from its book.
+        ///
+        /// The order leaves the book whatever the outcome and its deposit is
+        /// refunded first; failures are reported via `OrderSkipped`.
+        fn fire_resting_order(order_id: H256, now_ms: u64) {
+            let Some(resting) = RestingOrders::<T>::take(order_id) else {
+                return;
+            };
+            TrailingStopPeaks::<T>::remove(order_id);
+            let order = resting.order.inner();
+            RestingOrderCount::<T>::mutate_exists(&order.signer, Self::decrement_order_count);
+            Self::refund_resting_deposit(&order.signer, resting.deposit);
+
+            let result = match Orders::<T>::get(order_id) {
+                Some(OrderStatus::Cancelled) => Err(Error::<T>::OrderCancelled.into()),
+                Some(_) => Err(Error::<T>::OrderAlreadyProcessed.into()),
+                None if now_ms > order.expiry => Err(Error::<T>::OrderExpired.into()),
+                None if Self::oco_sibling_filled(&resting.order) => {
+                    Err(Error::<T>::OcoSiblingFilled.into())
+                }
+                None => Self::execute_order(&resting.order, order_id, None),
+            };
+            if let Err(reason) = result {
+                Self::deposit_event(Event::OrderSkipped { order_id, reason });
+            }
+        }
+
+        /// Remove `order_id` from the resting book, if it is resting there, and
+        /// refund its deposit.
+        fn remove_resting_order(order_id: H256) {
+            let Some(resting) = RestingOrders::<T>::take(order_id) else {
+                return;
+            };
+            TrailingStopPeaks::<T>::remove(order_id);
+            let order = resting.order.inner();
+            RestingOrderBook::<T>::mutate_exists(order.netuid, &order.order_type, |maybe_book| {
+                if let Some(book) = maybe_book.as_mut() {
+                    book.retain(|(_, id)| *id != order_id);
+                }
+                if maybe_book.as_ref().is_some_and(|book| book.is_empty()) {
+                    *maybe_book = None;
+                }
+            });
+            RestingOrderCount::<T>::mutate_exists(&order.signer, Self::decrement_order_count);
+            Self::refund_resting_deposit(&order.signer, resting.deposit);
+        }
+
+        /// Count one order out of a signer's open order count, removing the
+        /// entry once it reaches zero.
+        fn decrement_order_count(count: &mut Option<u32>) {
+            *count = count.and_then(|n| n.checked_sub(1)).filter(|n| *n > 0);
+        }
+
+        /// Return a resting order's deposit from the pallet account to `signer`.
+        fn refund_resting_deposit(signer: &T::AccountId, deposit: TaoBalance) {
+            if deposit.is_zero() {
+                return;
+            }
+            if let Err(e) = T::SwapInterface::transfer_tao(&Self::pallet_account(), signer, deposit)
+            {
+                log::error!("Failed to refund resting order deposit to {signer:?}: {e:?}");
+            }
+        }
+
+        /// Fill the TWAP slices that are due at block `now`, spending at most
+        /// `remaining_weight`.
+        pub(crate) fn execute_due_twap_slices(
+            now: BlockNumberFor<T>,
+            remaining_weight: Weight,
+        ) -> Weight {
+            let db_weight = T::DbWeight::get();
+            let mut weight_used = db_weight.reads(1);
+            if !LimitOrdersEnabled::<T>::get() {
+                return weight_used;
+            }
+
+            // Pop from the queue, read the schedule and status, execute, re-queue.
+            let per_slice =
+                T::WeightInfo::execute_orders(1).saturating_add(db_weight.reads_writes(3, 3));
+            let now_ms = T::TimeProvider::now().as_millis() as u64;
+
+            loop {
+                if weight_used
+                    .saturating_add(per_slice)
+                    .any_gt(remaining_weight)
+                {
+                    return weight_used;
+                }
+                let Some(order_id) = Self::pop_due_twap_slice(now) else {
+                    return weight_used.saturating_add(db_weight.reads(1));
+                };
+                weight_used = weight_used.saturating_add(per_slice);
+                Self::fire_twap_slice(order_id, now, now_ms);
+            }
+        }
+
+        /// Insert `order_id` into `TwapQueue` with its next slice due at `block`,
+        /// behind any slices already due at the same block.
+        fn enqueue_twap_slice(block: BlockNumberFor<T>, order_id: H256) -> Result<(), ()> {
+            TwapQueue::<T>::try_mutate(|queue| {
+                let pos = queue.partition_point(|(due, _)| *due <= block);
+                queue.try_insert(pos, (block, order_id)).map_err(|_| ())
+            })
+        }
+
+        /// Pop the front of `TwapQueue` if its slice is due at or before `now`.
+        fn pop_due_twap_slice(now: BlockNumberFor<T>) -> Option<H256> {
+            TwapQueue::<T>::mutate(|queue| {
+                let (due, order_id) = *queue.first()?;
+                if due > now {
+                    return None;
+                }
+                queue.remove(0);
+                Some(order_id)
+            })
+        }
+
+        /// Fill the next slice of a TWAP order that has just been popped from
+        /// the queue, then either re-queue it `interval` blocks later or, once
+        /// the order is filled, cancelled, expired or blocked by its
+        /// one-cancels-other sibling, end the schedule and refund its deposit.
+        fn fire_twap_slice(order_id: H256, now: BlockNumberFor<T>, now_ms: u64) {
+            let Some(twap) = TwapOrders::<T>::get(order_id) else {
+                return;
+            };
+            let order = twap.order.inner();
+
+            // `done` ends the schedule; only a missed price condition or a failed
+            // swap leaves an unfilled order open for a retry at the next interval.
+            let (result, done): (DispatchResult, bool) = match Orders::<T>::get(order_id) {
+                Some(OrderStatus::Cancelled) => (Err(Error::<T>::OrderCancelled.into()), true),
+                Some(OrderStatus::Fulfilled) => {
+                    (Err(Error::<T>::OrderAlreadyProcessed.into()), true)
+                }
+                _ if now_ms > order.expiry => (Err(Error::<T>::OrderExpired.into()), true),
+                _ if Self::oco_sibling_filled(&twap.order) => {
+                    (Err(Error::<T>::OcoSiblingFilled.into()), true)
+                }
+                status => {
+                    let filled = match status {
+                        Some(OrderStatus::PartiallyFilled(n)) => n,
+                        _ => 0,
+                    };
+                    let slice = twap.slice_amount.min(order.amount.saturating_sub(filled));
+                    let price =
+                        Self::scale_price(T::SwapInterface::current_alpha_price(order.netuid));
+                    let result = if order.order_type.is_triggered(price, order.limit_price) {
+                        Self::execute_order(&twap.order, order_id, Some(slice))
+                    } else {
+                        Err(Error::<T>::PriceConditionNotMet.into())
+                    };
+                    let done = Orders::<T>::get(order_id) == Some(OrderStatus::Fulfilled);
+                    (result, done)
+                }
+            };
+            if let Err(reason) = result {
+                Self::deposit_event(Event::OrderSkipped { order_id, reason });
+            }
+
+            // The slice was just popped from the queue, so re-queuing cannot overflow it.
+            if !done
+                && Self::enqueue_twap_slice(now.saturating_add(twap.interval), order_id).is_ok()
+            {
+                return;
+            }
+            TwapOrders::<T>::remove(order_id);
+            TwapOrderCount::<T>::mutate_exists(&order.signer, Self::decrement_order_count);
+            Self::refund_resting_deposit(&order.signer, twap.deposit);
+        }
+
+        /// Remove `order_id` from the TWAP schedule, if it is scheduled there,
+        /// and refund its deposit.
+        fn remove_twap_order(order_id: H256) {
+            let Some(twap) = TwapOrders::<T>::take(order_id) else {
+                return;
+            };
+            TwapQueue::<T>::mutate(|queue| queue.retain(|(_, id)| *id != order_id));
+            let signer = &twap.order.inner().signer;
+            TwapOrderCount::<T>::mutate_exists(signer, Self::decrement_order_count);
+            Self::refund_resting_deposit(signer, twap.deposit);
+        }
+
         /// Thin orchestrator for `execute_batched_orders`.
         ///
         /// All-or-nothing: any `Err` returned here (e.g. a `ZeroShareInBatch` rejection
         /// during distribution) rolls back the whole batch — including the up-front
         /// `collect_assets` debits and the pool swap — via FRAME's default per-dispatch
         /// storage layer, so no signer is left debited without receiving output.
+        ///
+        /// Returns the batch summary, including every order's fill.
         fn do_execute_batched_orders(
             netuid: NetUid,
             orders: BoundedVec<SignedOrder<T::AccountId>, T::MaxOrdersPerBatch>,
             relayer: T::AccountId,
-        ) -> DispatchResult {
+        ) -> Result<BatchExecutionSummary, DispatchError> {
             ensure!(!netuid.is_root(), Error::<T>::RootNetUidNotAllowed);
 
             let now_ms = T::TimeProvider::now().as_millis() as u64;
@@ -820,7 +1721,14 @@ pub mod pallet {
 
             let executed_count = valid_buys.len().saturating_add(valid_sells.len()) as u32;
             if executed_count == 0 {
-                return Ok(());
+                return Ok(BatchExecutionSummary {
+                    netuid,
+                    net_side: OrderSide::Buy,
+                    net_amount: 0,
+                    actual_out: 0,
+                    executed_count,
+                    fills: Vec::new(),
+                });
             }
 
             let total_buy_net: u128 = valid_buys.iter().map(|e| e.net as u128).sum();
@@ -867,6 +1775,8 @@ pub mod pallet {
                 pool_price_limit,
             )?;
 
+            let mut fills = Vec::with_capacity(executed_count as usize);
+
             // Give every buyer their pro-rata share of (pool alpha output + offset sell alpha).
             Self::distribute_alpha_pro_rata(
                 &valid_buys,
@@ -878,6 +1788,7 @@ pub mod pallet {
                 &pallet_acct,
                 &pallet_hotkey,
                 netuid,
+                &mut fills,
             )?;
 
             // Give every seller their pro-rata share of (pool TAO output + offset buy TAO),
@@ -891,6 +1802,7 @@ pub mod pallet {
                 current_price,
                 &pallet_acct,
                 netuid,
+                &mut fills,
             )?;
 
             // Merge buy and sell fees by recipient and transfer once per unique recipient.
@@ -905,13 +1817,20 @@ pub mod pallet {
             )?;
             Self::deposit_event(Event::GroupExecutionSummary {
                 netuid,
-                net_side,
+                net_side: net_side.clone(),
                 net_amount,
                 actual_out: actual_out as u64,
                 executed_count,
             });
 
-            Ok(())
+            Ok(BatchExecutionSummary {
+                netuid,
+                net_side,
+                net_amount,
+                actual_out: actual_out as u64,
+                executed_count,
+                fills,
+            })
         }
 
         /// Validate every order against `netuid`, signature, expiry, and price.
@@ -986,6 +1905,7 @@ pub mod pallet {
                     fee_recipient: order.fee_recipient.clone(),
                     effective_swap_limit,
                     partial_fill: signed_order.partial_fill,
+                    oco_sibling: signed_order.order.oco_sibling(),
                 };
 
                 // try_push cannot fail: both vecs share the same bound as `orders`.
@@ -996,6 +1916,16 @@ pub mod pallet {
                 }
             }
 
+            // Both halves of a one-cancels-other pair filling together would defeat
+            // the link, so such a batch is rejected outright.
+            ensure!(
+                buys.iter()
+                    .chain(sells.iter())
+                    .filter_map(|e| e.oco_sibling)
+                    .all(|sibling| !seen_order_ids.contains(&sibling)),
+                Error::<T>::OcoSiblingInBatch
+            );
+
             Ok((buys, sells))
         }
 
@@ -1084,6 +2014,7 @@ pub mod pallet {
         }
 
         /// Distribute alpha pro-rata to ALL buyers and mark their orders fulfilled.
+        /// Each buyer's fill is appended to `fills`.
         ///
         /// - Buy-dominant: total alpha = pool output + sell-side alpha (passed through).
         /// - Sell-dominant: total alpha = buy-side TAO converted at `current_price`.
@@ -1098,6 +2029,7 @@ pub mod pallet {
             pallet_acct: &T::AccountId,
             pallet_hotkey: &T::AccountId,
             netuid: NetUid,
+            fills: &mut Vec<OrderFill>,
         ) -> DispatchResult {
             let total_alpha: u128 = match net_side {
                 OrderSide::Buy => actual_out.saturating_add(total_sell_net),
@@ -1140,6 +2072,14 @@ pub mod pallet {
                     amount_in: e.gross,
                     amount_out: share,
                 });
+                fills.push(OrderFill {
+                    order_id: e.order_id,
+                    order_type: e.side.clone(),
+                    amount_in: e.gross,
+                    amount_out: share,
+                    fee: e.gross.saturating_sub(e.net),
+                });
+                Self::cancel_oco_sibling(&e.signer, e.oco_sibling);
             }
             Ok(())
         }
@@ -1151,6 +2091,7 @@ pub mod pallet {
         ///
         /// Fee on TAO output: `ppb(share)` is withheld from each seller's payout and
         /// left in the pallet account. Returns the total sell-side fee TAO accumulated.
+        /// Each seller's fill is appended to `fills`.
         #[allow(clippy::too_many_arguments)]
         pub(crate) fn distribute_tao_pro_rata(
             sells: &BoundedVec<OrderEntry<T::AccountId>, T::MaxOrdersPerBatch>,
@@ -1161,6 +2102,7 @@ pub mod pallet {
             current_price: U64F64,
             pallet_acct: &T::AccountId,
             netuid: NetUid,
+            fills: &mut Vec<OrderFill>,
         ) -> Result<Vec<(T::AccountId, u64)>, DispatchError> {
             let total_tao: u128 = match net_side {
                 OrderSide::Sell => actual_out.saturating_add(total_buy_net),
@@ -1213,6 +2155,14 @@ pub mod pallet {
                     amount_in: e.gross,
                     amount_out: net_share,
                 });
+                fills.push(OrderFill {
+                    order_id: e.order_id,
+                    order_type: e.side.clone(),
+                    amount_in: e.gross,
+                    amount_out: net_share,
+                    fee,
+                });
+                Self::cancel_oco_sibling(&e.signer, e.oco_sibling);
             }
             Ok(sell_fees)
         }
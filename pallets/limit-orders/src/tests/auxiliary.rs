@@ -659,6 +659,7 @@ fn make_buy_entry(
         fee_recipient,
         effective_swap_limit: u64::MAX, // no slippage constraint
         partial_fill: None,
+        oco_sibling: None,
     }
 }
 
@@ -724,6 +725,7 @@ fn distribute_alpha_pro_rata_buy_dominant_scenario_a() {
             &pallet_acct,
             &pallet_hk,
             netuid(),
+            &mut Vec::new(),
         )
         .unwrap();
 
@@ -795,6 +797,7 @@ fn distribute_alpha_pro_rata_sell_dominant_scenario_b() {
             &pallet_acct,
             &pallet_hk,
             netuid(),
+            &mut Vec::new(),
         )
         .unwrap();
 
@@ -875,6 +878,7 @@ fn distribute_alpha_pro_rata_buy_dominant_scenario_c() {
             &pallet_acct,
             &pallet_hk,
             netuid(),
+            &mut Vec::new(),
         )
         .unwrap();
 
@@ -963,6 +967,7 @@ fn distribute_alpha_pro_rata_dust_remains_in_pallet_scenario_d() {
             &pallet_acct,
             &pallet_hk,
             netuid(),
+            &mut Vec::new(),
         )
         .unwrap();
 
@@ -1099,6 +1104,7 @@ fn distribute_tao_pro_rata_sell_dominant_no_fee_scenario_a() {
             U64F64::from_num(2u32),
             &pallet_acct,
             netuid(),
+            &mut Vec::new(),
         )
         .unwrap();
 
@@ -1160,6 +1166,7 @@ fn distribute_tao_pro_rata_sell_dominant_with_fee_scenario_b() {
             U64F64::from_num(2u32),
             &pallet_acct,
             netuid(),
+            &mut Vec::new(),
         )
         .unwrap();
 
@@ -1221,6 +1228,7 @@ fn distribute_tao_pro_rata_buy_dominant_scenario_c() {
             U64F64::from_num(2u32),
             &pallet_acct,
             netuid(),
+            &mut Vec::new(),
         )
         .unwrap();
 
@@ -1291,6 +1299,7 @@ fn distribute_tao_pro_rata_dust_remains_in_pallet_scenario_d() {
             U64F64::from_num(1u32),
             &pallet_acct,
             netuid(),
+            &mut Vec::new(),
         )
         .unwrap();
 
@@ -148,6 +148,35 @@ fn cancel_order_already_fulfilled_rejected() {
     });
 }
 
+#[test]
+fn cancel_order_partially_filled_rejected() {
+    new_test_ext().execute_with(|| {
+        let order = VersionedOrder::V1(Order {
+            signer: alice(),
+            hotkey: bob(),
+            netuid: netuid(),
+            order_type: OrderType::LimitBuy,
+            amount: 1_000,
+            limit_price: u64::MAX,
+            expiry: FAR_FUTURE,
+            fee_rate: Perbill::zero(),
+            fee_recipient: fee_recipient(),
+            relayer: Some(charlie()),
+            max_slippage: None,
+            chain_id: 945,
+            partial_fills_enabled: true,
+        });
+        let id = order_id(&order);
+        Orders::<Test>::insert(id, OrderStatus::PartiallyFilled(400));
+
+        // Only a running TWAP schedule can be cancelled once partially filled.
+        assert_noop!(
+            LimitOrders::cancel_order(RuntimeOrigin::signed(alice()), order),
+            Error::<Test>::OrderAlreadyProcessed
+        );
+    });
+}
+
 #[test]
 fn cancel_order_unsigned_rejected() {
     new_test_ext().execute_with(|| {
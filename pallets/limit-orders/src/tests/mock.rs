@@ -13,11 +13,11 @@ use frame_support::{
     traits::{ConstU32, ConstU64, Everything},
 };
 use frame_system as system;
-use sp_core::{H256, Pair};
+use sp_core::{H160, H256, Pair, ecdsa};
 use sp_keyring::Sr25519Keyring as AccountKeyring;
 use sp_runtime::{
     AccountId32, BuildStorage, MultiSignature,
-    traits::{AccountIdConversion, BlakeTwo256, IdentityLookup},
+    traits::{AccountIdConversion, BlakeTwo256, Convert, IdentityLookup},
 };
 use substrate_fixed::types::U64F64;
 use subtensor_runtime_common::{AlphaBalance, NetUid, TaoBalance, Token};
@@ -521,8 +521,12 @@ impl frame_support::traits::UnixTime for MockTime {
 parameter_types! {
     pub const LimitOrdersPalletId: PalletId = PalletId(*b"lmt/ordr");
     pub const PalletHotkeyAccount: AccountId = AccountId::new([0xaa; 32]);
+    pub const RestingOrderDeposit: TaoBalance = TaoBalance::new(RESTING_ORDER_DEPOSIT);
 }
 
+/// Deposit (raw TAO) reserved for every order placed in the resting book.
+pub const RESTING_ORDER_DEPOSIT: u64 = 100;
+
 /// A fixed account used in tests as the fee recipient when a concrete
 /// recipient is needed but the test isn't specifically about fees.
 pub fn fee_recipient() -> AccountId {
@@ -537,6 +541,21 @@ impl pallet_limit_orders::Config for Test {
     type PalletHotkey = PalletHotkeyAccount;
     type WeightInfo = ();
     type ChainId = ConstU64<945>;
+    type MaxRestingOrdersPerBook = ConstU32<4>;
+    type RestingOrderDeposit = RestingOrderDeposit;
+    type MaxTwapOrders = ConstU32<4>;
+    type EvmAddressMapping = MockEvmAddressMapping;
+}
+
+/// Maps an EVM address the way `pallet_evm::HashedAddressMapping<BlakeTwo256>`
+/// does in the runtime.
+pub struct MockEvmAddressMapping;
+
+impl Convert<H160, AccountId> for MockEvmAddressMapping {
+    fn convert(address: H160) -> AccountId {
+        let data = [b"evm:".as_slice(), address.as_bytes()].concat();
+        AccountId::new(sp_core::hashing::blake2_256(&data))
+    }
 }
 
 // ── Shared test helpers ───────────────────────────────────────────────────────
@@ -634,6 +653,40 @@ pub fn make_partial_fill_order(
     }
 }
 
+/// Sign an arbitrary versioned order with `keyring` for a one-shot full fill.
+pub fn sign_order(
+    keyring: AccountKeyring,
+    order: crate::VersionedOrder<AccountId>,
+) -> crate::SignedOrder<AccountId> {
+    let sig = keyring.pair().sign(&order.encode());
+    crate::SignedOrder {
+        order,
+        signature: MultiSignature::Sr25519(sig),
+        partial_fill: None,
+    }
+}
+
+/// The substrate account of the EVM wallet holding `pair`.
+pub fn evm_account(pair: &ecdsa::Pair) -> AccountId {
+    let hash = [0u8; 32];
+    let address = crate::eip712::recover_signer(&hash, &pair.sign_prehashed(&hash)).unwrap();
+    MockEvmAddressMapping::convert(address)
+}
+
+/// Sign an arbitrary versioned order as EIP-712 typed data with the EVM key
+/// `pair`, for a one-shot full fill.
+pub fn sign_order_eip712(
+    pair: &ecdsa::Pair,
+    order: crate::VersionedOrder<AccountId>,
+) -> crate::SignedOrder<AccountId> {
+    let hash = crate::eip712::signing_hash(&order, 945);
+    crate::SignedOrder {
+        order,
+        signature: MultiSignature::Ecdsa(pair.sign_prehashed(&hash)),
+        partial_fill: None,
+    }
+}
+
 pub fn bounded(
     v: Vec<crate::SignedOrder<AccountId>>,
 ) -> BoundedVec<crate::SignedOrder<AccountId>, ConstU32<64>> {
@@ -1,4 +1,9 @@
 pub mod auxiliary;
+pub mod eip712;
 pub mod extrinsics;
 pub mod migration;
 pub mod mock;
+pub mod order_v2;
+pub mod resting;
+pub mod simulation;
+pub mod twap;
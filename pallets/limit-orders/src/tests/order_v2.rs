@@ -0,0 +1,434 @@
+#![allow(clippy::unwrap_used)]
+//! Tests for `VersionedOrder::V2` features: trailing stops and
+//! one-cancels-other links, through both the relayed and the resting paths.
+
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok, traits::Hooks, weights::Weight};
+use sp_core::H256;
+use sp_keyring::Sr25519Keyring as AccountKeyring;
+use sp_runtime::Perbill;
+
+use crate::{
+    Error, Order, OrderStatus, OrderType, OrderV2, Orders, RestingOrderBook, RestingOrders,
+    TrailingStopPeaks, VersionedOrder, pallet::Event,
+};
+
+type LimitOrders = crate::pallet::Pallet<Test>;
+
+use super::mock::*;
+
+fn assert_event(event: Event<Test>) {
+    assert!(
+        System::events()
+            .iter()
+            .any(|r| r.event == RuntimeEvent::LimitOrders(event.clone())),
+        "expected event not found: {event:?}",
+    );
+}
+
+fn base_order(signer: AccountId, order_type: OrderType, limit_price: u64) -> Order<AccountId> {
+    Order {
+        signer,
+        hotkey: bob(),
+        netuid: netuid(),
+        order_type,
+        amount: 1_000,
+        limit_price,
+        expiry: FAR_FUTURE,
+        fee_rate: Perbill::zero(),
+        fee_recipient: fee_recipient(),
+        relayer: None,
+        max_slippage: None,
+        chain_id: 945,
+        partial_fills_enabled: false,
+    }
+}
+
+fn v2_order(
+    base: Order<AccountId>,
+    trailing_offset: Perbill,
+    oco_sibling: Option<H256>,
+) -> VersionedOrder<AccountId> {
+    VersionedOrder::V2(OrderV2 {
+        base,
+        trailing_offset,
+        oco_sibling,
+    })
+}
+
+fn trailing_stop(signer: AccountId, floor: u64, offset: Perbill) -> VersionedOrder<AccountId> {
+    v2_order(
+        base_order(signer, OrderType::TrailingStop, floor),
+        offset,
+        None,
+    )
+}
+
+fn book(order_type: OrderType) -> Vec<(u64, H256)> {
+    RestingOrderBook::<Test>::get(netuid(), order_type).into_inner()
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Encoding
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn v1_encoding_unchanged_by_v2() {
+    let base = base_order(alice(), OrderType::TakeProfit, 1);
+    let v1 = VersionedOrder::V1(base.clone());
+    let v2 = v2_order(base.clone(), Perbill::zero(), None);
+
+    // V1 keeps its variant index and layout, so existing signatures stay valid.
+    let mut expected = vec![0u8];
+    expected.extend(base.encode());
+    assert_eq!(v1.encode(), expected);
+    assert_eq!(v2.encode().first(), Some(&1u8));
+    assert_ne!(order_id(&v1), order_id(&v2));
+    assert_eq!(v2.inner(), &base);
+}
+
+#[test]
+fn trailing_stop_level_follows_peak_above_floor() {
+    let offset = Perbill::from_percent(10);
+    assert_eq!(
+        LimitOrders::trailing_stop_level(0, offset, 3_000_000_000),
+        2_700_000_000
+    );
+    assert_eq!(
+        LimitOrders::trailing_stop_level(2_900_000_000, offset, 3_000_000_000),
+        2_900_000_000
+    );
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Relayed execution
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn execute_orders_rejects_trailing_stop() {
+    new_test_ext().execute_with(|| {
+        MockSwap::set_price(1.0);
+        let signed = sign_order(
+            AccountKeyring::Alice,
+            trailing_stop(alice(), u64::MAX, Perbill::from_percent(5)),
+        );
+
+        assert_noop!(
+            LimitOrders::execute_orders(
+                RuntimeOrigin::signed(charlie()),
+                bounded(vec![signed]),
+                true,
+            ),
+            Error::<Test>::UnsupportedOrderType
+        );
+    });
+}
+
+#[test]
+fn take_profit_fill_cancels_stop_loss_sibling() {
+    new_test_ext().execute_with(|| {
+        MockSwap::set_tao_balance(alice(), 1_000);
+        let stop_loss = sign_order(
+            AccountKeyring::Alice,
+            VersionedOrder::V1(base_order(alice(), OrderType::StopLoss, 500_000_000)),
+        );
+        let sl_id = order_id(&stop_loss.order);
+        assert_ok!(LimitOrders::place_order(
+            RuntimeOrigin::signed(alice()),
+            stop_loss.order.clone()
+        ));
+        let take_profit = sign_order(
+            AccountKeyring::Alice,
+            v2_order(
+                base_order(alice(), OrderType::TakeProfit, 1_500_000_000),
+                Perbill::zero(),
+                Some(sl_id),
+            ),
+        );
+        let tp_id = order_id(&take_profit.order);
+
+        MockSwap::set_price(2.0);
+        assert_ok!(LimitOrders::execute_orders(
+            RuntimeOrigin::signed(charlie()),
+            bounded(vec![take_profit]),
+            true,
+        ));
+
+        assert_eq!(Orders::<Test>::get(tp_id), Some(OrderStatus::Fulfilled));
+        assert_eq!(Orders::<Test>::get(sl_id), Some(OrderStatus::Cancelled));
+        assert!(!RestingOrders::<Test>::contains_key(sl_id));
+        assert_event(Event::OrderCancelled {
+            order_id: sl_id,
+            signer: alice(),
+        });
+
+        // The cancelled stop loss can no longer execute.
+        MockSwap::set_price(0.4);
+        assert_noop!(
+            LimitOrders::execute_orders(
+                RuntimeOrigin::signed(charlie()),
+                bounded(vec![stop_loss]),
+                true,
+            ),
+            Error::<Test>::OrderCancelled
+        );
+    });
+}
+
+#[test]
+fn filled_sibling_blocks_take_profit() {
+    new_test_ext().execute_with(|| {
+        let stop_loss = sign_order(
+            AccountKeyring::Alice,
+            VersionedOrder::V1(base_order(alice(), OrderType::StopLoss, 500_000_000)),
+        );
+        let sl_id = order_id(&stop_loss.order);
+        let take_profit = sign_order(
+            AccountKeyring::Alice,
+            v2_order(
+                base_order(alice(), OrderType::TakeProfit, 1_500_000_000),
+                Perbill::zero(),
+                Some(sl_id),
+            ),
+        );
+
+        MockSwap::set_price(0.4);
+        assert_ok!(LimitOrders::execute_orders(
+            RuntimeOrigin::signed(charlie()),
+            bounded(vec![stop_loss]),
+            true,
+        ));
+        assert_eq!(Orders::<Test>::get(sl_id), Some(OrderStatus::Fulfilled));
+
+        MockSwap::set_price(2.0);
+        assert_noop!(
+            LimitOrders::execute_orders(
+                RuntimeOrigin::signed(charlie()),
+                bounded(vec![take_profit]),
+                true,
+            ),
+            Error::<Test>::OcoSiblingFilled
+        );
+    });
+}
+
+#[test]
+fn batch_with_both_oco_siblings_rejected() {
+    new_test_ext().execute_with(|| {
+        // At exactly 1.0 both a TakeProfit and a StopLoss at 1.0 are triggered.
+        MockSwap::set_price(1.0);
+        let stop_loss = sign_order(
+            AccountKeyring::Alice,
+            VersionedOrder::V1(base_order(alice(), OrderType::StopLoss, 1_000_000_000)),
+        );
+        let take_profit = sign_order(
+            AccountKeyring::Alice,
+            v2_order(
+                base_order(alice(), OrderType::TakeProfit, 1_000_000_000),
+                Perbill::zero(),
+                Some(order_id(&stop_loss.order)),
+            ),
+        );
+
+        assert_noop!(
+            LimitOrders::execute_batched_orders(
+                RuntimeOrigin::signed(charlie()),
+                netuid(),
+                bounded(vec![take_profit, stop_loss]),
+            ),
+            Error::<Test>::OcoSiblingInBatch
+        );
+    });
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Resting book
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn place_order_rejects_v1_trailing_stop() {
+    new_test_ext().execute_with(|| {
+        let order = VersionedOrder::V1(base_order(alice(), OrderType::TrailingStop, 0));
+        assert_noop!(
+            LimitOrders::place_order(RuntimeOrigin::signed(alice()), order),
+            Error::<Test>::UnsupportedOrderType
+        );
+    });
+}
+
+#[test]
+fn place_order_indexes_trailing_stop_by_stop_level() {
+    new_test_ext().execute_with(|| {
+        MockSwap::set_price(2.0);
+        let order = trailing_stop(alice(), 0, Perbill::from_percent(10));
+        let id = order_id(&order);
+
+        assert_ok!(LimitOrders::place_order(
+            RuntimeOrigin::signed(alice()),
+            order
+        ));
+
+        assert_eq!(TrailingStopPeaks::<Test>::get(id), Some(2_000_000_000));
+        assert_eq!(book(OrderType::TrailingStop), vec![(1_800_000_000, id)]);
+    });
+}
+
+#[test]
+fn on_idle_ratchets_trailing_stop_then_fires() {
+    new_test_ext().execute_with(|| {
+        MockSwap::set_price(2.0);
+        let order = trailing_stop(alice(), 0, Perbill::from_percent(10));
+        let id = order_id(&order);
+        assert_ok!(LimitOrders::place_order(
+            RuntimeOrigin::signed(alice()),
+            order
+        ));
+
+        // New peak at 3.0 lifts the stop to 2.7 without firing.
+        MockSwap::set_price(3.0);
+        LimitOrders::on_idle(1, Weight::MAX);
+        assert_eq!(TrailingStopPeaks::<Test>::get(id), Some(3_000_000_000));
+        assert_eq!(book(OrderType::TrailingStop), vec![(2_700_000_000, id)]);
+
+        // A dip that stays above the stop changes nothing.
+        MockSwap::set_price(2.8);
+        LimitOrders::on_idle(1, Weight::MAX);
+        assert_eq!(Orders::<Test>::get(id), None);
+        assert_eq!(book(OrderType::TrailingStop), vec![(2_700_000_000, id)]);
+
+        // Falling through the stop fires the order.
+        MockSwap::set_price(2.6);
+        LimitOrders::on_idle(1, Weight::MAX);
+        assert_eq!(Orders::<Test>::get(id), Some(OrderStatus::Fulfilled));
+        assert!(book(OrderType::TrailingStop).is_empty());
+        assert_eq!(TrailingStopPeaks::<Test>::get(id), None);
+    });
+}
+
+#[test]
+fn ratchet_reorders_trailing_stops_by_new_level() {
+    new_test_ext().execute_with(|| {
+        MockSwap::set_price(2.0);
+        // `floored` starts above `tight`, but `tight` trails closer to the new
+        // peak and overtakes it.
+        let floored = trailing_stop(alice(), 1_990_000_000, Perbill::from_percent(50));
+        let tight = trailing_stop(alice(), 0, Perbill::from_percent(1));
+        assert_ok!(LimitOrders::place_order(
+            RuntimeOrigin::signed(alice()),
+            floored.clone()
+        ));
+        assert_ok!(LimitOrders::place_order(
+            RuntimeOrigin::signed(alice()),
+            tight.clone()
+        ));
+        assert_eq!(
+            book(OrderType::TrailingStop),
+            vec![
+                (1_980_000_000, order_id(&tight)),
+                (1_990_000_000, order_id(&floored)),
+            ]
+        );
+
+        MockSwap::set_price(4.0);
+        LimitOrders::on_idle(1, Weight::MAX);
+
+        assert_eq!(
+            book(OrderType::TrailingStop),
+            vec![
+                (2_000_000_000, order_id(&floored)),
+                (3_960_000_000, order_id(&tight)),
+            ]
+        );
+    });
+}
+
+#[test]
+fn resting_take_profit_cancels_resting_sibling() {
+    new_test_ext().execute_with(|| {
+        MockSwap::set_tao_balance(alice(), 1_000);
+        let stop_loss = VersionedOrder::V1(base_order(alice(), OrderType::StopLoss, 500_000_000));
+        let sl_id = order_id(&stop_loss);
+        let take_profit = v2_order(
+            base_order(alice(), OrderType::TakeProfit, 1_500_000_000),
+            Perbill::zero(),
+            Some(sl_id),
+        );
+        assert_ok!(LimitOrders::place_order(
+            RuntimeOrigin::signed(alice()),
+            stop_loss
+        ));
+        assert_ok!(LimitOrders::place_order(
+            RuntimeOrigin::signed(alice()),
+            take_profit.clone()
+        ));
+
+        MockSwap::set_price(2.0);
+        LimitOrders::on_idle(1, Weight::MAX);
+
+        assert_eq!(
+            Orders::<Test>::get(order_id(&take_profit)),
+            Some(OrderStatus::Fulfilled)
+        );
+        assert_eq!(Orders::<Test>::get(sl_id), Some(OrderStatus::Cancelled));
+        assert!(!RestingOrders::<Test>::contains_key(sl_id));
+        assert!(book(OrderType::StopLoss).is_empty());
+        // Both deposits are back with the signer.
+        assert_eq!(MockSwap::tao_balance(&alice()), 1_000);
+    });
+}
+
+#[test]
+fn oco_link_ignores_resting_order_of_another_signer() {
+    new_test_ext().execute_with(|| {
+        let victim = VersionedOrder::V1(base_order(dave(), OrderType::StopLoss, 500_000_000));
+        let victim_id = order_id(&victim);
+        assert_ok!(LimitOrders::place_order(
+            RuntimeOrigin::signed(dave()),
+            victim
+        ));
+        let take_profit = sign_order(
+            AccountKeyring::Alice,
+            v2_order(
+                base_order(alice(), OrderType::TakeProfit, 1_500_000_000),
+                Perbill::zero(),
+                Some(victim_id),
+            ),
+        );
+
+        MockSwap::set_price(2.0);
+        assert_ok!(LimitOrders::execute_orders(
+            RuntimeOrigin::signed(charlie()),
+            bounded(vec![take_profit]),
+            true,
+        ));
+
+        assert_eq!(Orders::<Test>::get(victim_id), None);
+        assert!(RestingOrders::<Test>::contains_key(victim_id));
+    });
+}
+
+#[test]
+fn oco_link_ignores_sibling_not_on_chain() {
+    new_test_ext().execute_with(|| {
+        // A relayed order of another signer, never placed on-chain.
+        let victim = VersionedOrder::V1(base_order(dave(), OrderType::StopLoss, 500_000_000));
+        let victim_id = order_id(&victim);
+        let take_profit = sign_order(
+            AccountKeyring::Alice,
+            v2_order(
+                base_order(alice(), OrderType::TakeProfit, 1_500_000_000),
+                Perbill::zero(),
+                Some(victim_id),
+            ),
+        );
+
+        MockSwap::set_price(2.0);
+        assert_ok!(LimitOrders::execute_orders(
+            RuntimeOrigin::signed(charlie()),
+            bounded(vec![take_profit]),
+            true,
+        ));
+
+        assert_eq!(Orders::<Test>::get(victim_id), None);
+    });
+}
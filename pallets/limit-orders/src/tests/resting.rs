@@ -0,0 +1,495 @@
+#![allow(clippy::unwrap_used)]
+//! Tests for the on-chain resting order book: `place_order`, cancellation of
+//! resting orders, and the `on_idle` matcher.
+
+use frame_support::{BoundedVec, assert_noop, assert_ok, traits::Hooks, weights::Weight};
+use sp_core::H256;
+use sp_runtime::{DispatchError, Perbill};
+use subtensor_runtime_common::TaoBalance;
+
+use crate::{
+    Error, Order, OrderStatus, OrderType, Orders, RestingOrderBook, RestingOrderCount,
+    RestingOrders, VersionedOrder, pallet::Event,
+};
+
+type LimitOrders = crate::pallet::Pallet<Test>;
+
+use super::mock::*;
+
+fn assert_event(event: Event<Test>) {
+    assert!(
+        System::events()
+            .iter()
+            .any(|r| r.event == RuntimeEvent::LimitOrders(event.clone())),
+        "expected event not found: {event:?}",
+    );
+}
+
+/// Build an unsigned order for `place_order`; the origin authorises it.
+fn resting_order(
+    signer: AccountId,
+    order_type: OrderType,
+    limit_price: u64,
+    expiry: u64,
+) -> VersionedOrder<AccountId> {
+    VersionedOrder::V1(Order {
+        signer,
+        hotkey: bob(),
+        netuid: netuid(),
+        order_type,
+        amount: 1_000,
+        limit_price,
+        expiry,
+        fee_rate: Perbill::zero(),
+        fee_recipient: fee_recipient(),
+        relayer: None,
+        max_slippage: None,
+        chain_id: 945,
+        partial_fills_enabled: false,
+    })
+}
+
+fn book(order_type: OrderType) -> Vec<(u64, H256)> {
+    RestingOrderBook::<Test>::get(netuid(), order_type).into_inner()
+}
+
+fn pallet_account() -> AccountId {
+    LimitOrders::pallet_account()
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// place_order
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn place_order_indexes_by_price_and_takes_deposit() {
+    new_test_ext().execute_with(|| {
+        MockSwap::set_tao_balance(alice(), 1_000);
+        let high = resting_order(alice(), OrderType::TakeProfit, 3_000_000_000, FAR_FUTURE);
+        let low = resting_order(alice(), OrderType::TakeProfit, 1_000_000_000, FAR_FUTURE);
+
+        assert_ok!(LimitOrders::place_order(
+            RuntimeOrigin::signed(alice()),
+            high.clone()
+        ));
+        assert_ok!(LimitOrders::place_order(
+            RuntimeOrigin::signed(alice()),
+            low.clone()
+        ));
+
+        // Sorted ascending by limit price regardless of insertion order.
+        assert_eq!(
+            book(OrderType::TakeProfit),
+            vec![
+                (1_000_000_000, order_id(&low)),
+                (3_000_000_000, order_id(&high)),
+            ]
+        );
+        let resting = RestingOrders::<Test>::get(order_id(&high)).unwrap();
+        assert_eq!(resting.order, high);
+        // The second order of the signer owes twice the base deposit.
+        assert_eq!(resting.deposit, TaoBalance::new(RESTING_ORDER_DEPOSIT));
+        assert_eq!(
+            RestingOrders::<Test>::get(order_id(&low)).unwrap().deposit,
+            TaoBalance::new(2 * RESTING_ORDER_DEPOSIT)
+        );
+        assert_eq!(
+            MockSwap::tao_balance(&alice()),
+            1_000 - 3 * RESTING_ORDER_DEPOSIT
+        );
+        assert_eq!(
+            MockSwap::tao_balance(&pallet_account()),
+            3 * RESTING_ORDER_DEPOSIT
+        );
+        assert_event(Event::OrderPlaced {
+            order_id: order_id(&low),
+            signer: alice(),
+            netuid: netuid(),
+            order_type: OrderType::TakeProfit,
+            limit_price: 1_000_000_000,
+        });
+    });
+}
+
+#[test]
+fn place_order_non_signer_rejected() {
+    new_test_ext().execute_with(|| {
+        let order = resting_order(alice(), OrderType::LimitBuy, 1_000_000_000, FAR_FUTURE);
+        assert_noop!(
+            LimitOrders::place_order(RuntimeOrigin::signed(bob()), order),
+            Error::<Test>::Unauthorized
+        );
+    });
+}
+
+#[test]
+fn place_order_unsigned_rejected() {
+    new_test_ext().execute_with(|| {
+        let order = resting_order(alice(), OrderType::LimitBuy, 1_000_000_000, FAR_FUTURE);
+        assert_noop!(
+            LimitOrders::place_order(RuntimeOrigin::none(), order),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn place_order_relayer_or_partial_fills_rejected() {
+    new_test_ext().execute_with(|| {
+        let order = resting_order(alice(), OrderType::LimitBuy, 1, FAR_FUTURE);
+
+        let mut with_relayer = order.inner().clone();
+        with_relayer.relayer = Some(BoundedVec::try_from(vec![charlie()]).unwrap());
+        assert_noop!(
+            LimitOrders::place_order(
+                RuntimeOrigin::signed(alice()),
+                VersionedOrder::V1(with_relayer)
+            ),
+            Error::<Test>::UnsupportedRestingOrder
+        );
+
+        let mut partial = order.inner().clone();
+        partial.partial_fills_enabled = true;
+        assert_noop!(
+            LimitOrders::place_order(RuntimeOrigin::signed(alice()), VersionedOrder::V1(partial)),
+            Error::<Test>::UnsupportedRestingOrder
+        );
+    });
+}
+
+#[test]
+fn place_order_expired_rejected() {
+    new_test_ext().execute_with(|| {
+        MockTime::set(2_000_000);
+        let order = resting_order(alice(), OrderType::LimitBuy, 1, 1_999_999);
+        assert_noop!(
+            LimitOrders::place_order(RuntimeOrigin::signed(alice()), order),
+            Error::<Test>::OrderExpired
+        );
+    });
+}
+
+#[test]
+fn place_order_twice_rejected() {
+    new_test_ext().execute_with(|| {
+        let order = resting_order(alice(), OrderType::LimitBuy, 1, FAR_FUTURE);
+        assert_ok!(LimitOrders::place_order(
+            RuntimeOrigin::signed(alice()),
+            order.clone()
+        ));
+        assert_noop!(
+            LimitOrders::place_order(RuntimeOrigin::signed(alice()), order),
+            Error::<Test>::OrderAlreadyResting
+        );
+    });
+}
+
+#[test]
+fn place_order_full_book_rejected() {
+    new_test_ext().execute_with(|| {
+        // MaxRestingOrdersPerBook = 4 in the mock.
+        for price in 1..=4u64 {
+            assert_ok!(LimitOrders::place_order(
+                RuntimeOrigin::signed(alice()),
+                resting_order(alice(), OrderType::StopLoss, price, FAR_FUTURE)
+            ));
+        }
+        assert_noop!(
+            LimitOrders::place_order(
+                RuntimeOrigin::signed(alice()),
+                resting_order(alice(), OrderType::StopLoss, 5, FAR_FUTURE)
+            ),
+            Error::<Test>::RestingOrderBookFull
+        );
+        // Other books on the same subnet are unaffected.
+        assert_ok!(LimitOrders::place_order(
+            RuntimeOrigin::signed(alice()),
+            resting_order(alice(), OrderType::TakeProfit, 5, FAR_FUTURE)
+        ));
+    });
+}
+
+#[test]
+fn place_order_deposit_scales_with_signer_open_orders() {
+    new_test_ext().execute_with(|| {
+        MockSwap::set_tao_balance(alice(), 1_000 * RESTING_ORDER_DEPOSIT);
+        MockSwap::set_tao_balance(bob(), 1_000 * RESTING_ORDER_DEPOSIT);
+        // Alice's orders cost 1 + 2 + 3 base deposits.
+        let orders: Vec<_> = (1..=3u64)
+            .map(|price| resting_order(alice(), OrderType::StopLoss, price, FAR_FUTURE))
+            .collect();
+        for (i, order) in orders.iter().enumerate() {
+            assert_ok!(LimitOrders::place_order(
+                RuntimeOrigin::signed(alice()),
+                order.clone()
+            ));
+            assert_eq!(
+                RestingOrders::<Test>::get(order_id(order)).unwrap().deposit,
+                TaoBalance::new((i as u64 + 1) * RESTING_ORDER_DEPOSIT)
+            );
+        }
+
+        // Bob joins the same book for a single base deposit.
+        let bob_order = resting_order(bob(), OrderType::StopLoss, 4, FAR_FUTURE);
+        assert_ok!(LimitOrders::place_order(
+            RuntimeOrigin::signed(bob()),
+            bob_order.clone()
+        ));
+        assert_eq!(
+            RestingOrders::<Test>::get(order_id(&bob_order))
+                .unwrap()
+                .deposit,
+            TaoBalance::new(RESTING_ORDER_DEPOSIT)
+        );
+
+        // Orders in other books count towards the same signer.
+        let take_profit = resting_order(alice(), OrderType::TakeProfit, 5, FAR_FUTURE);
+        assert_ok!(LimitOrders::place_order(
+            RuntimeOrigin::signed(alice()),
+            take_profit.clone()
+        ));
+        assert_eq!(
+            RestingOrders::<Test>::get(order_id(&take_profit))
+                .unwrap()
+                .deposit,
+            TaoBalance::new(4 * RESTING_ORDER_DEPOSIT)
+        );
+        assert_eq!(RestingOrderCount::<Test>::get(alice()), 4);
+        assert_eq!(RestingOrderCount::<Test>::get(bob()), 1);
+        assert_eq!(
+            MockSwap::tao_balance(&pallet_account()),
+            11 * RESTING_ORDER_DEPOSIT
+        );
+
+        // Cancelling refunds the deposit taken when the order was placed.
+        assert_ok!(LimitOrders::cancel_order(
+            RuntimeOrigin::signed(alice()),
+            orders[2].clone()
+        ));
+        assert_eq!(RestingOrderCount::<Test>::get(alice()), 3);
+        assert_eq!(
+            MockSwap::tao_balance(&pallet_account()),
+            8 * RESTING_ORDER_DEPOSIT
+        );
+        assert_eq!(MockSwap::tao_balance(&alice()), 993 * RESTING_ORDER_DEPOSIT);
+
+        assert_ok!(LimitOrders::cancel_order(
+            RuntimeOrigin::signed(bob()),
+            bob_order
+        ));
+        assert!(!RestingOrderCount::<Test>::contains_key(bob()));
+    });
+}
+
+#[test]
+fn cancel_order_removes_resting_order_and_refunds_deposit() {
+    new_test_ext().execute_with(|| {
+        MockSwap::set_tao_balance(alice(), 1_000);
+        let order = resting_order(alice(), OrderType::LimitBuy, 1_000_000_000, FAR_FUTURE);
+        let id = order_id(&order);
+        assert_ok!(LimitOrders::place_order(
+            RuntimeOrigin::signed(alice()),
+            order.clone()
+        ));
+
+        assert_ok!(LimitOrders::cancel_order(
+            RuntimeOrigin::signed(alice()),
+            order
+        ));
+
+        assert_eq!(Orders::<Test>::get(id), Some(OrderStatus::Cancelled));
+        assert!(!RestingOrders::<Test>::contains_key(id));
+        assert!(!RestingOrderBook::<Test>::contains_key(
+            netuid(),
+            OrderType::LimitBuy
+        ));
+        assert_eq!(MockSwap::tao_balance(&alice()), 1_000);
+    });
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// on_idle matcher
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn on_idle_fires_only_crossed_take_profit_orders() {
+    new_test_ext().execute_with(|| {
+        MockSwap::set_tao_balance(alice(), 1_000);
+        let crossed = resting_order(alice(), OrderType::TakeProfit, 1_500_000_000, FAR_FUTURE);
+        let waiting = resting_order(alice(), OrderType::TakeProfit, 3_000_000_000, FAR_FUTURE);
+        assert_ok!(LimitOrders::place_order(
+            RuntimeOrigin::signed(alice()),
+            crossed.clone()
+        ));
+        assert_ok!(LimitOrders::place_order(
+            RuntimeOrigin::signed(alice()),
+            waiting.clone()
+        ));
+
+        MockSwap::set_price(2.0);
+        LimitOrders::on_idle(1, Weight::MAX);
+
+        assert_eq!(
+            Orders::<Test>::get(order_id(&crossed)),
+            Some(OrderStatus::Fulfilled)
+        );
+        assert_eq!(Orders::<Test>::get(order_id(&waiting)), None);
+        assert_eq!(
+            book(OrderType::TakeProfit),
+            vec![(3_000_000_000, order_id(&waiting))]
+        );
+        assert!(!RestingOrders::<Test>::contains_key(order_id(&crossed)));
+        // The first order's deposit is refunded; the second order was the
+        // signer's second and its double deposit is still held.
+        assert_eq!(
+            MockSwap::tao_balance(&alice()),
+            1_000 - 2 * RESTING_ORDER_DEPOSIT
+        );
+        assert_event(Event::OrderExecuted {
+            order_id: order_id(&crossed),
+            signer: alice(),
+            netuid: netuid(),
+            order_type: OrderType::TakeProfit,
+            amount_in: 1_000,
+            amount_out: 0,
+        });
+    });
+}
+
+#[test]
+fn on_idle_fires_stop_losses_from_the_high_end() {
+    new_test_ext().execute_with(|| {
+        let near = resting_order(alice(), OrderType::StopLoss, 900_000_000, FAR_FUTURE);
+        let far = resting_order(alice(), OrderType::StopLoss, 400_000_000, FAR_FUTURE);
+        assert_ok!(LimitOrders::place_order(
+            RuntimeOrigin::signed(alice()),
+            near.clone()
+        ));
+        assert_ok!(LimitOrders::place_order(
+            RuntimeOrigin::signed(alice()),
+            far.clone()
+        ));
+
+        // 0.5 ≤ 0.9 triggers `near`; 0.5 > 0.4 leaves `far` resting.
+        MockSwap::set_price(0.5);
+        LimitOrders::on_idle(1, Weight::MAX);
+
+        assert_eq!(
+            Orders::<Test>::get(order_id(&near)),
+            Some(OrderStatus::Fulfilled)
+        );
+        assert_eq!(
+            book(OrderType::StopLoss),
+            vec![(400_000_000, order_id(&far))]
+        );
+        assert_eq!(
+            MockSwap::sell_alpha_limit_prices(),
+            vec![0] // no max_slippage → no floor
+        );
+    });
+}
+
+#[test]
+fn on_idle_fires_limit_buy_with_signer_funds() {
+    new_test_ext().execute_with(|| {
+        MockSwap::set_tao_balance(alice(), 1_000 + RESTING_ORDER_DEPOSIT);
+        MockSwap::set_buy_alpha_return(700);
+        let order = resting_order(alice(), OrderType::LimitBuy, 1_000_000_000, FAR_FUTURE);
+        assert_ok!(LimitOrders::place_order(
+            RuntimeOrigin::signed(alice()),
+            order.clone()
+        ));
+
+        MockSwap::set_price(0.8);
+        LimitOrders::on_idle(1, Weight::MAX);
+
+        assert_eq!(
+            Orders::<Test>::get(order_id(&order)),
+            Some(OrderStatus::Fulfilled)
+        );
+        // Deposit refunded, then the full amount spent on the buy.
+        assert_eq!(MockSwap::tao_balance(&alice()), 0);
+        assert_eq!(MockSwap::alpha_balance(&alice(), &bob(), netuid()), 700);
+    });
+}
+
+#[test]
+fn on_idle_expired_order_is_skipped_and_refunded() {
+    new_test_ext().execute_with(|| {
+        MockTime::set(1_000_000);
+        MockSwap::set_tao_balance(alice(), 1_000);
+        let order = resting_order(alice(), OrderType::TakeProfit, 1, 1_500_000);
+        let id = order_id(&order);
+        assert_ok!(LimitOrders::place_order(
+            RuntimeOrigin::signed(alice()),
+            order
+        ));
+
+        MockTime::set(2_000_000);
+        LimitOrders::on_idle(1, Weight::MAX);
+
+        assert_eq!(Orders::<Test>::get(id), None);
+        assert!(!RestingOrders::<Test>::contains_key(id));
+        assert_eq!(MockSwap::tao_balance(&alice()), 1_000);
+        assert_event(Event::OrderSkipped {
+            order_id: id,
+            reason: Error::<Test>::OrderExpired.into(),
+        });
+    });
+}
+
+#[test]
+fn on_idle_failed_swap_drops_order_from_book() {
+    new_test_ext().execute_with(|| {
+        let order = resting_order(alice(), OrderType::TakeProfit, 1, FAR_FUTURE);
+        let id = order_id(&order);
+        assert_ok!(LimitOrders::place_order(
+            RuntimeOrigin::signed(alice()),
+            order
+        ));
+
+        MockSwap::set_swap_fail(true);
+        LimitOrders::on_idle(1, Weight::MAX);
+        MockSwap::set_swap_fail(false);
+
+        assert_eq!(Orders::<Test>::get(id), None);
+        assert!(book(OrderType::TakeProfit).is_empty());
+        assert_event(Event::OrderSkipped {
+            order_id: id,
+            reason: DispatchError::Other("pool error"),
+        });
+    });
+}
+
+#[test]
+fn on_idle_without_spare_weight_fires_nothing() {
+    new_test_ext().execute_with(|| {
+        let order = resting_order(alice(), OrderType::TakeProfit, 1, FAR_FUTURE);
+        assert_ok!(LimitOrders::place_order(
+            RuntimeOrigin::signed(alice()),
+            order.clone()
+        ));
+
+        LimitOrders::on_idle(1, Weight::zero());
+
+        assert_eq!(Orders::<Test>::get(order_id(&order)), None);
+        assert_eq!(book(OrderType::TakeProfit).len(), 1);
+    });
+}
+
+#[test]
+fn on_idle_does_nothing_while_pallet_disabled() {
+    new_test_ext().execute_with(|| {
+        let order = resting_order(alice(), OrderType::TakeProfit, 1, FAR_FUTURE);
+        assert_ok!(LimitOrders::place_order(
+            RuntimeOrigin::signed(alice()),
+            order.clone()
+        ));
+        crate::LimitOrdersEnabled::<Test>::set(false);
+
+        LimitOrders::on_idle(1, Weight::MAX);
+
+        assert_eq!(Orders::<Test>::get(order_id(&order)), None);
+        assert!(RestingOrders::<Test>::contains_key(order_id(&order)));
+    });
+}
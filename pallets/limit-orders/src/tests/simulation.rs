@@ -0,0 +1,146 @@
+#![allow(clippy::unwrap_used)]
+//! Tests for `simulate_execution`, the dry run of `execute_batched_orders`
+//! exposed through the runtime API.
+
+use frame_support::assert_noop;
+use sp_keyring::Sr25519Keyring as AccountKeyring;
+use sp_runtime::Perbill;
+
+use crate::{
+    BatchExecutionSummary, Error, LimitOrdersEnabled, OrderFill, OrderSide, OrderStatus, OrderType,
+    Orders, SignedOrder,
+};
+
+type LimitOrders = crate::pallet::Pallet<Test>;
+
+use super::mock::*;
+
+fn buy(amount: u64, fee_rate: Perbill) -> SignedOrder<AccountId> {
+    make_signed_order(
+        AccountKeyring::Alice,
+        dave(),
+        netuid(),
+        OrderType::LimitBuy,
+        amount,
+        u64::MAX,
+        FAR_FUTURE,
+        fee_rate,
+        fee_recipient(),
+        None,
+    )
+}
+
+fn sell(amount: u64, fee_rate: Perbill) -> SignedOrder<AccountId> {
+    make_signed_order(
+        AccountKeyring::Bob,
+        dave(),
+        netuid(),
+        OrderType::TakeProfit,
+        amount,
+        0,
+        FAR_FUTURE,
+        fee_rate,
+        fee_recipient(),
+        None,
+    )
+}
+
+#[test]
+fn simulate_execution_reports_fills_and_fees() {
+    new_test_ext().execute_with(|| {
+        // Alice buys with 1_000 TAO (fee 10, net 990); Bob sells 200 alpha worth
+        // 200 TAO (fee 2 on the payout). The residual 790 TAO buys 500 alpha.
+        MockSwap::set_price(1.0);
+        MockSwap::set_buy_alpha_return(500);
+        MockSwap::set_alpha_balance(bob(), dave(), netuid(), 200);
+        let alice_buy = buy(1_000, Perbill::from_percent(1));
+        let bob_sell = sell(200, Perbill::from_percent(1));
+        let alice_id = order_id(&alice_buy.order);
+        let bob_id = order_id(&bob_sell.order);
+
+        let summary =
+            LimitOrders::simulate_execution(netuid(), vec![alice_buy, bob_sell], charlie())
+                .unwrap();
+
+        assert_eq!(
+            summary,
+            BatchExecutionSummary {
+                netuid: netuid(),
+                net_side: OrderSide::Buy,
+                net_amount: 790,
+                actual_out: 500,
+                executed_count: 2,
+                fills: vec![
+                    OrderFill {
+                        order_id: alice_id,
+                        order_type: OrderType::LimitBuy,
+                        amount_in: 1_000,
+                        amount_out: 700,
+                        fee: 10,
+                    },
+                    OrderFill {
+                        order_id: bob_id,
+                        order_type: OrderType::TakeProfit,
+                        amount_in: 200,
+                        amount_out: 198,
+                        fee: 2,
+                    },
+                ],
+            }
+        );
+    });
+}
+
+#[test]
+fn simulate_execution_leaves_no_trace() {
+    new_test_ext().execute_with(|| {
+        MockSwap::set_buy_alpha_return(500);
+        let alice_buy = buy(1_000, Perbill::zero());
+        let id = order_id(&alice_buy.order);
+
+        LimitOrders::simulate_execution(netuid(), vec![alice_buy], charlie()).unwrap();
+
+        assert_eq!(Orders::<Test>::get(id), None);
+        assert!(System::events().is_empty());
+    });
+}
+
+#[test]
+fn simulate_execution_returns_batch_error() {
+    new_test_ext().execute_with(|| {
+        let alice_buy = buy(1_000, Perbill::zero());
+        Orders::<Test>::insert(order_id(&alice_buy.order), OrderStatus::Fulfilled);
+
+        assert_eq!(
+            LimitOrders::simulate_execution(netuid(), vec![alice_buy], charlie()),
+            Err(Error::<Test>::OrderAlreadyProcessed.into())
+        );
+    });
+}
+
+#[test]
+fn simulate_execution_rejects_oversized_batch() {
+    new_test_ext().execute_with(|| {
+        // MaxOrdersPerBatch = 64 in the mock.
+        let orders = (1_000..1_065)
+            .map(|amount| buy(amount, Perbill::zero()))
+            .collect();
+
+        assert_noop!(
+            LimitOrders::simulate_execution(netuid(), orders, charlie()),
+            Error::<Test>::BatchTooLarge
+        );
+    });
+}
+
+#[test]
+fn simulate_execution_fails_when_disabled() {
+    new_test_ext().execute_with(|| {
+        LimitOrdersEnabled::<Test>::set(false);
+
+        assert_noop!(
+            LimitOrders::simulate_execution(netuid(), vec![buy(1_000, Perbill::zero())], charlie()),
+            Error::<Test>::LimitOrdersDisabled
+        );
+    });
+}
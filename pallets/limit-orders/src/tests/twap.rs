@@ -0,0 +1,388 @@
+#![allow(clippy::unwrap_used)]
+//! Tests for time-weighted (TWAP) orders: `place_twap_order`, slice execution
+//! by the `on_idle` matcher, and cancellation of a running schedule.
+
+use frame_support::{BoundedVec, assert_noop, assert_ok, traits::Hooks, weights::Weight};
+use sp_core::H256;
+use sp_runtime::Perbill;
+use subtensor_runtime_common::TaoBalance;
+
+use crate::{
+    Error, Order, OrderStatus, OrderType, Orders, TwapOrderCount, TwapOrders, TwapQueue,
+    VersionedOrder, pallet::Event,
+};
+
+type LimitOrders = crate::pallet::Pallet<Test>;
+
+use super::mock::*;
+
+fn assert_event(event: Event<Test>) {
+    assert!(
+        System::events()
+            .iter()
+            .any(|r| r.event == RuntimeEvent::LimitOrders(event.clone())),
+        "expected event not found: {event:?}",
+    );
+}
+
+fn twap_order(order_type: OrderType, amount: u64, limit_price: u64) -> Order<AccountId> {
+    Order {
+        signer: alice(),
+        hotkey: bob(),
+        netuid: netuid(),
+        order_type,
+        amount,
+        limit_price,
+        expiry: FAR_FUTURE,
+        fee_rate: Perbill::zero(),
+        fee_recipient: fee_recipient(),
+        relayer: None,
+        max_slippage: None,
+        chain_id: 945,
+        partial_fills_enabled: true,
+    }
+}
+
+fn place(order: Order<AccountId>, slices: u32, interval: u64) -> H256 {
+    let order = VersionedOrder::V1(order);
+    assert_ok!(LimitOrders::place_twap_order(
+        RuntimeOrigin::signed(alice()),
+        order.clone(),
+        slices,
+        interval
+    ));
+    order_id(&order)
+}
+
+fn run_idle_at(block: u64) {
+    System::set_block_number(block);
+    LimitOrders::on_idle(block, Weight::MAX);
+}
+
+fn queue() -> Vec<(u64, H256)> {
+    TwapQueue::<Test>::get().into_inner()
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// place_twap_order
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn place_twap_order_schedules_first_slice_now() {
+    new_test_ext().execute_with(|| {
+        MockSwap::set_tao_balance(alice(), 1_000);
+        let id = place(twap_order(OrderType::LimitBuy, 1_000, u64::MAX), 3, 10);
+
+        let twap = TwapOrders::<Test>::get(id).unwrap();
+        // 1_000 / 3 rounded up: slices of 334, 334 and 332.
+        assert_eq!(twap.slice_amount, 334);
+        assert_eq!(twap.interval, 10);
+        assert_eq!(queue(), vec![(1, id)]);
+        assert_eq!(
+            MockSwap::tao_balance(&alice()),
+            1_000 - RESTING_ORDER_DEPOSIT
+        );
+        assert_event(Event::TwapOrderPlaced {
+            order_id: id,
+            signer: alice(),
+            netuid: netuid(),
+            order_type: OrderType::LimitBuy,
+            slice_amount: 334,
+            interval: 10,
+        });
+    });
+}
+
+#[test]
+fn place_twap_order_invalid_orders_rejected() {
+    new_test_ext().execute_with(|| {
+        let try_place = |order: Order<AccountId>, slices: u32, interval: u64| {
+            LimitOrders::place_twap_order(
+                RuntimeOrigin::signed(alice()),
+                VersionedOrder::V1(order),
+                slices,
+                interval,
+            )
+        };
+        let order = twap_order(OrderType::LimitBuy, 1_000, u64::MAX);
+
+        assert_noop!(
+            LimitOrders::place_twap_order(
+                RuntimeOrigin::signed(bob()),
+                VersionedOrder::V1(order.clone()),
+                3,
+                10
+            ),
+            Error::<Test>::Unauthorized
+        );
+        assert_noop!(
+            try_place(twap_order(OrderType::StopLoss, 1_000, 0), 3, 10),
+            Error::<Test>::UnsupportedOrderType
+        );
+
+        let mut relayed = order.clone();
+        relayed.relayer = Some(BoundedVec::try_from(vec![charlie()]).unwrap());
+        assert_noop!(
+            try_place(relayed, 3, 10),
+            Error::<Test>::UnsupportedRestingOrder
+        );
+
+        let mut one_shot = order.clone();
+        one_shot.partial_fills_enabled = false;
+        assert_noop!(
+            try_place(one_shot, 3, 10),
+            Error::<Test>::PartialFillsNotEnabled
+        );
+
+        assert_noop!(
+            try_place(order.clone(), 0, 10),
+            Error::<Test>::InvalidTwapSchedule
+        );
+        assert_noop!(
+            try_place(order.clone(), 1_001, 10),
+            Error::<Test>::InvalidTwapSchedule
+        );
+        assert_noop!(try_place(order, 3, 0), Error::<Test>::InvalidTwapSchedule);
+    });
+}
+
+#[test]
+fn place_twap_order_twice_rejected() {
+    new_test_ext().execute_with(|| {
+        let order = twap_order(OrderType::LimitBuy, 1_000, u64::MAX);
+        place(order.clone(), 3, 10);
+        assert_noop!(
+            LimitOrders::place_twap_order(
+                RuntimeOrigin::signed(alice()),
+                VersionedOrder::V1(order),
+                3,
+                10
+            ),
+            Error::<Test>::OrderAlreadyResting
+        );
+    });
+}
+
+#[test]
+fn place_twap_order_full_queue_rejected() {
+    new_test_ext().execute_with(|| {
+        // MaxTwapOrders = 4 in the mock.
+        for amount in 1_000..1_004 {
+            place(twap_order(OrderType::LimitBuy, amount, u64::MAX), 2, 10);
+        }
+        assert_noop!(
+            LimitOrders::place_twap_order(
+                RuntimeOrigin::signed(alice()),
+                VersionedOrder::V1(twap_order(OrderType::LimitBuy, 1_004, u64::MAX)),
+                2,
+                10
+            ),
+            Error::<Test>::TwapQueueFull
+        );
+    });
+}
+
+#[test]
+fn place_twap_order_deposit_scales_with_signer_open_orders() {
+    new_test_ext().execute_with(|| {
+        MockSwap::set_tao_balance(alice(), 1_000 * RESTING_ORDER_DEPOSIT);
+        MockSwap::set_tao_balance(bob(), 1_000 * RESTING_ORDER_DEPOSIT);
+        // Alice's orders cost 1 + 2 + 3 base deposits.
+        let orders: Vec<_> = (1_000..1_003)
+            .map(|amount| twap_order(OrderType::LimitBuy, amount, u64::MAX))
+            .collect();
+        for (i, order) in orders.iter().enumerate() {
+            let id = place(order.clone(), 2, 10);
+            assert_eq!(
+                TwapOrders::<Test>::get(id).unwrap().deposit,
+                TaoBalance::new((i as u64 + 1) * RESTING_ORDER_DEPOSIT)
+            );
+        }
+        assert_eq!(MockSwap::tao_balance(&alice()), 994 * RESTING_ORDER_DEPOSIT);
+
+        // Bob joins the same queue for a single base deposit.
+        let bob_order = VersionedOrder::V1(Order {
+            signer: bob(),
+            ..twap_order(OrderType::LimitBuy, 1_003, u64::MAX)
+        });
+        assert_ok!(LimitOrders::place_twap_order(
+            RuntimeOrigin::signed(bob()),
+            bob_order.clone(),
+            2,
+            10
+        ));
+        assert_eq!(
+            TwapOrders::<Test>::get(order_id(&bob_order))
+                .unwrap()
+                .deposit,
+            TaoBalance::new(RESTING_ORDER_DEPOSIT)
+        );
+        assert_eq!(TwapOrderCount::<Test>::get(alice()), 3);
+        assert_eq!(TwapOrderCount::<Test>::get(bob()), 1);
+
+        // Cancelling refunds the deposit taken when the order was scheduled.
+        assert_ok!(LimitOrders::cancel_order(
+            RuntimeOrigin::signed(alice()),
+            VersionedOrder::V1(orders[2].clone())
+        ));
+        assert_eq!(MockSwap::tao_balance(&alice()), 997 * RESTING_ORDER_DEPOSIT);
+        assert_eq!(TwapOrderCount::<Test>::get(alice()), 2);
+    });
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Slice execution
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn slices_fill_every_interval_until_fulfilled() {
+    new_test_ext().execute_with(|| {
+        MockSwap::set_tao_balance(alice(), 1_000 + RESTING_ORDER_DEPOSIT);
+        MockSwap::set_buy_alpha_return(10);
+        let id = place(twap_order(OrderType::LimitBuy, 1_000, u64::MAX), 3, 10);
+
+        run_idle_at(1);
+        assert_eq!(
+            Orders::<Test>::get(id),
+            Some(OrderStatus::PartiallyFilled(334))
+        );
+        assert_eq!(queue(), vec![(11, id)]);
+
+        // Nothing is due between slices.
+        run_idle_at(5);
+        assert_eq!(
+            Orders::<Test>::get(id),
+            Some(OrderStatus::PartiallyFilled(334))
+        );
+
+        run_idle_at(11);
+        assert_eq!(
+            Orders::<Test>::get(id),
+            Some(OrderStatus::PartiallyFilled(668))
+        );
+
+        // The last slice only fills the remainder and ends the schedule.
+        run_idle_at(21);
+        assert_eq!(Orders::<Test>::get(id), Some(OrderStatus::Fulfilled));
+        assert!(!TwapOrders::<Test>::contains_key(id));
+        assert!(!TwapOrderCount::<Test>::contains_key(alice()));
+        assert!(queue().is_empty());
+        assert_eq!(MockSwap::tao_balance(&alice()), RESTING_ORDER_DEPOSIT);
+        assert_eq!(MockSwap::alpha_balance(&alice(), &bob(), netuid()), 30);
+        assert_event(Event::OrderExecuted {
+            order_id: id,
+            signer: alice(),
+            netuid: netuid(),
+            order_type: OrderType::LimitBuy,
+            amount_in: 332,
+            amount_out: 10,
+        });
+    });
+}
+
+#[test]
+fn slice_waits_for_price_condition() {
+    new_test_ext().execute_with(|| {
+        MockSwap::set_price(1.0);
+        let id = place(
+            twap_order(OrderType::TakeProfit, 1_000, 2_000_000_000),
+            2,
+            10,
+        );
+
+        run_idle_at(1);
+        assert_eq!(Orders::<Test>::get(id), None);
+        assert_eq!(queue(), vec![(11, id)]);
+        assert_event(Event::OrderSkipped {
+            order_id: id,
+            reason: Error::<Test>::PriceConditionNotMet.into(),
+        });
+
+        MockSwap::set_price(3.0);
+        run_idle_at(11);
+        assert_eq!(
+            Orders::<Test>::get(id),
+            Some(OrderStatus::PartiallyFilled(500))
+        );
+    });
+}
+
+#[test]
+fn each_slice_respects_max_slippage() {
+    new_test_ext().execute_with(|| {
+        MockSwap::set_price(1.0);
+        let mut order = twap_order(OrderType::LimitBuy, 1_000, 1_000_000_000);
+        order.max_slippage = Some(Perbill::from_percent(10));
+        place(order, 2, 10);
+
+        run_idle_at(1);
+        run_idle_at(11);
+
+        assert_eq!(
+            MockSwap::buy_alpha_limit_prices(),
+            vec![1_100_000_000, 1_100_000_000]
+        );
+    });
+}
+
+#[test]
+fn expired_schedule_ends_and_refunds_deposit() {
+    new_test_ext().execute_with(|| {
+        MockTime::set(1_000_000);
+        MockSwap::set_tao_balance(alice(), 1_000);
+        let mut order = twap_order(OrderType::TakeProfit, 1_000, 0);
+        order.expiry = 1_500_000;
+        let id = place(order, 2, 10);
+
+        run_idle_at(1);
+        MockTime::set(2_000_000);
+        run_idle_at(11);
+
+        assert_eq!(
+            Orders::<Test>::get(id),
+            Some(OrderStatus::PartiallyFilled(500))
+        );
+        assert!(!TwapOrders::<Test>::contains_key(id));
+        assert!(queue().is_empty());
+        assert_eq!(MockSwap::tao_balance(&alice()), 1_000);
+        assert_event(Event::OrderSkipped {
+            order_id: id,
+            reason: Error::<Test>::OrderExpired.into(),
+        });
+    });
+}
+
+#[test]
+fn cancel_order_stops_running_schedule() {
+    new_test_ext().execute_with(|| {
+        MockSwap::set_tao_balance(alice(), 1_000);
+        let order = twap_order(OrderType::TakeProfit, 1_000, 0);
+        let id = place(order.clone(), 2, 10);
+        run_idle_at(1);
+
+        assert_ok!(LimitOrders::cancel_order(
+            RuntimeOrigin::signed(alice()),
+            VersionedOrder::V1(order)
+        ));
+
+        assert_eq!(Orders::<Test>::get(id), Some(OrderStatus::Cancelled));
+        assert!(!TwapOrders::<Test>::contains_key(id));
+        assert!(queue().is_empty());
+        assert_eq!(MockSwap::tao_balance(&alice()), 1_000);
+
+        run_idle_at(11);
+        assert_eq!(Orders::<Test>::get(id), Some(OrderStatus::Cancelled));
+    });
+}
+
+#[test]
+fn on_idle_without_spare_weight_fills_no_slice() {
+    new_test_ext().execute_with(|| {
+        let id = place(twap_order(OrderType::TakeProfit, 1_000, 0), 2, 10);
+
+        LimitOrders::on_idle(1, Weight::zero());
+
+        assert_eq!(Orders::<Test>::get(id), None);
+        assert_eq!(queue(), vec![(1, id)]);
+    });
+}
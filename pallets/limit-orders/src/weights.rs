@@ -40,6 +40,8 @@ pub trait WeightInfo {
 	fn set_pallet_status() -> Weight;
 	fn execute_orders(n: u32, ) -> Weight;
 	fn execute_batched_orders(n: u32, ) -> Weight;
+	fn place_order() -> Weight;
+	fn place_twap_order() -> Weight;
 }
 
 /// Weights for `pallet_limit_orders` using the Substrate node and recommended hardware.
@@ -120,6 +122,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `SubtensorModule::LastColdkeyHotkeyStakeBlock` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `n` is `[1, 100]`.
 	fn execute_orders(n: u32, ) -> Weight {
+		// Not re-measured yet: the benchmark now signs its orders as EIP-712 typed data.
 		// Proof Size summary in bytes:
 		//  Measured:  `1134 + n * (283 ±0)`
 		//  Estimated: `6148 + n * (5158 ±0)`
@@ -187,6 +190,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `SubtensorModule::LastColdkeyHotkeyStakeBlock` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `n` is `[1, 100]`.
 	fn execute_batched_orders(n: u32, ) -> Weight {
+		// Not re-measured yet: the benchmark now signs its orders as EIP-712 typed data.
 		// Proof Size summary in bytes:
 		//  Measured:  `1263 + n * (283 ±0)`
 		//  Estimated: `8727 + n * (5158 ±0)`
@@ -200,6 +204,42 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 5158).saturating_mul(n.into()))
 	}
+	/// Storage: `LimitOrders::LimitOrdersEnabled` (r:1 w:0)
+	/// Proof: `LimitOrders::LimitOrdersEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `LimitOrders::Orders` (r:1 w:0)
+	/// Proof: `LimitOrders::Orders` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `LimitOrders::RestingOrders` (r:1 w:1)
+	/// Proof: `LimitOrders::RestingOrders` (`max_values`: None, `max_size`: Some(551), added: 3026, mode: `MaxEncodedLen`)
+	/// Storage: `LimitOrders::RestingOrderBook` (r:1 w:1)
+	/// Proof: `LimitOrders::RestingOrderBook` (`max_values`: None, `max_size`: Some(10035), added: 12510, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn place_order() -> Weight {
+		// Not measured yet: a full book and a deposit scaled by its occupancy.
+		Weight::from_parts(63_275_000, 13500)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `LimitOrders::LimitOrdersEnabled` (r:1 w:0)
+	/// Proof: `LimitOrders::LimitOrdersEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `LimitOrders::Orders` (r:1 w:0)
+	/// Proof: `LimitOrders::Orders` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `LimitOrders::TwapOrders` (r:1 w:1)
+	/// Proof: `LimitOrders::TwapOrders` (`max_values`: None, `max_size`: Some(615), added: 3090, mode: `MaxEncodedLen`)
+	/// Storage: `LimitOrders::TwapQueue` (r:1 w:1)
+	/// Proof: `LimitOrders::TwapQueue` (`max_values`: Some(1), `max_size`: Some(9218), added: 9713, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn place_twap_order() -> Weight {
+		// Not measured yet: a full queue and a deposit scaled by its occupancy.
+		Weight::from_parts(60_914_000, 10703)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -279,6 +319,7 @@ impl WeightInfo for () {
 	/// Proof: `SubtensorModule::LastColdkeyHotkeyStakeBlock` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `n` is `[1, 100]`.
 	fn execute_orders(n: u32, ) -> Weight {
+		// Not re-measured yet: the benchmark now signs its orders as EIP-712 typed data.
 		// Proof Size summary in bytes:
 		//  Measured:  `1134 + n * (283 ±0)`
 		//  Estimated: `6148 + n * (5158 ±0)`
@@ -346,6 +387,7 @@ impl WeightInfo for () {
 	/// Proof: `SubtensorModule::LastColdkeyHotkeyStakeBlock` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `n` is `[1, 100]`.
 	fn execute_batched_orders(n: u32, ) -> Weight {
+		// Not re-measured yet: the benchmark now signs its orders as EIP-712 typed data.
 		// Proof Size summary in bytes:
 		//  Measured:  `1263 + n * (283 ±0)`
 		//  Estimated: `8727 + n * (5158 ±0)`
@@ -359,4 +401,16 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes((7_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 5158).saturating_mul(n.into()))
 	}
+	fn place_order() -> Weight {
+		// Not measured yet: a full book and a deposit scaled by its occupancy.
+		Weight::from_parts(63_275_000, 13500)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn place_twap_order() -> Weight {
+		// Not measured yet: a full queue and a deposit scaled by its occupancy.
+		Weight::from_parts(60_914_000, 10703)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
 }
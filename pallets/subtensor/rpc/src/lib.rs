@@ -14,8 +14,8 @@ use subtensor_runtime_common::{MechId, NetUid, TaoBalance};
 use sp_api::ProvideRuntimeApi;
 
 pub use subtensor_custom_rpc_runtime_api::{
-    DelegateInfoRuntimeApi, NeuronInfoRuntimeApi, StakeInfoRuntimeApi, SubnetInfoRuntimeApi,
-    SubnetRegistrationRuntimeApi,
+    DelegateInfoRuntimeApi, LockAction, NeuronInfoRuntimeApi, StakeInfoRuntimeApi,
+    SubnetInfoRuntimeApi, SubnetRegistrationRuntimeApi,
 };
 
 #[rpc(client, server)]
@@ -111,6 +111,44 @@ pub trait SubtensorCustomApi<BlockHash> {
     fn get_subnet_to_prune(&self, at: Option<BlockHash>) -> RpcResult<Option<NetUid>>;
     #[method(name = "subnetInfo_getSubnetAccountId")]
     fn get_subnet_account_id(&self, netuid: NetUid, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_simulateEpoch")]
+    fn simulate_epoch(
+        &self,
+        netuid: NetUid,
+        mecid: MechId,
+        override_weights: Option<Vec<(u16, Vec<(u16, u16)>)>>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getEpochHistory")]
+    fn get_epoch_history(
+        &self,
+        netuid: NetUid,
+        mecid: MechId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getWeightCommitStatus")]
+    fn get_weight_commit_status(
+        &self,
+        hotkey: AccountId32,
+        netuid: NetUid,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getValidatorAgreement")]
+    fn get_validator_agreement(
+        &self,
+        netuid: NetUid,
+        mecid: MechId,
+        similarity_threshold: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getScheduledHyperparameterChanges")]
+    fn get_scheduled_hyperparameter_changes(
+        &self,
+        netuid: NetUid,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getChildkeyGraph")]
+    fn get_childkey_graph(&self, netuid: NetUid, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
     #[method(name = "stakeInfo_getColdkeyLock")]
     fn get_coldkey_lock(
         &self,
@@ -118,6 +156,27 @@ pub trait SubtensorCustomApi<BlockHash> {
         netuid: NetUid,
         at: Option<BlockHash>,
     ) -> RpcResult<Vec<u8>>;
+    #[method(name = "stakeInfo_getColdkeyLockProjection")]
+    fn get_coldkey_lock_projection(
+        &self,
+        coldkey: AccountId32,
+        netuid: NetUid,
+        blocks: Vec<u64>,
+        action: Option<Vec<u8>>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "stakeInfo_getRecurringStakes")]
+    fn get_recurring_stakes(
+        &self,
+        coldkey: AccountId32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "stakeInfo_getStakeInfoWithCostBasis")]
+    fn get_stake_info_with_cost_basis(
+        &self,
+        coldkey: AccountId32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
 }
 
 pub struct SubtensorCustom<C, P> {
@@ -558,6 +617,108 @@ where
         }
     }
 
+    fn simulate_epoch(
+        &self,
+        netuid: NetUid,
+        mecid: MechId,
+        override_weights: Option<Vec<(u16, Vec<(u16, u16)>)>>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.simulate_epoch(at, netuid, mecid, override_weights) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!("Unable to simulate epoch: {e:?}")).into()),
+        }
+    }
+
+    fn get_epoch_history(
+        &self,
+        netuid: NetUid,
+        mecid: MechId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_epoch_history(at, netuid, mecid) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get epoch history: {e:?}")).into())
+            }
+        }
+    }
+
+    fn get_weight_commit_status(
+        &self,
+        hotkey: AccountId32,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_weight_commit_status(at, hotkey, netuid) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!(
+                "Unable to get weight commit status: {e:?}"
+            ))
+            .into()),
+        }
+    }
+
+    fn get_validator_agreement(
+        &self,
+        netuid: NetUid,
+        mecid: MechId,
+        similarity_threshold: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_validator_agreement(at, netuid, mecid, similarity_threshold) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get validator agreement: {e:?}")).into())
+            }
+        }
+    }
+
+    fn get_scheduled_hyperparameter_changes(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_scheduled_hyperparameter_changes(at, netuid) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!(
+                "Unable to get scheduled hyperparameter changes: {e:?}"
+            ))
+            .into()),
+        }
+    }
+
+    fn get_childkey_graph(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_childkey_graph(at, netuid) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get childkey graph: {e:?}")).into())
+            }
+        }
+    }
+
     fn get_coldkey_lock(
         &self,
         coldkey: AccountId32,
@@ -572,4 +733,68 @@ where
             Err(e) => Err(Error::RuntimeError(format!("Unable to get coldkey lock: {e:?}")).into()),
         }
     }
+
+    fn get_coldkey_lock_projection(
+        &self,
+        coldkey: AccountId32,
+        netuid: NetUid,
+        blocks: Vec<u64>,
+        action: Option<Vec<u8>>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let action = match action
+            .map(|action| LockAction::<AccountId32>::decode(&mut &action[..]))
+            .transpose()
+        {
+            Ok(action) => action,
+            Err(e) => {
+                return Err(
+                    Error::RuntimeError(format!("Unable to decode lock action: {e:?}")).into(),
+                );
+            }
+        };
+        match api.get_coldkey_lock_projection(at, coldkey, netuid, blocks, action) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!(
+                "Unable to get coldkey lock projection: {e:?}"
+            ))
+            .into()),
+        }
+    }
+
+    fn get_recurring_stakes(
+        &self,
+        coldkey: AccountId32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_recurring_stakes(at, coldkey) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get recurring stakes: {e:?}")).into())
+            }
+        }
+    }
+
+    fn get_stake_info_with_cost_basis(
+        &self,
+        coldkey: AccountId32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_stake_info_with_cost_basis(at, coldkey) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!(
+                "Unable to get stake info with cost basis: {e:?}"
+            ))
+            .into()),
+        }
+    }
 }
@@ -3,19 +3,28 @@ extern crate alloc;
 use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
 use codec::Compact;
+pub use pallet_subtensor::epoch::history::EpochRecord;
 use pallet_subtensor::rpc_info::{
+    childkey_graph::ChildkeyGraph,
     delegate_info::DelegateInfo,
     dynamic_info::DynamicInfo,
+    epoch_simulation::EpochSimulation,
     metagraph::{Metagraph, SelectiveMetagraph},
     neuron_info::{NeuronInfo, NeuronInfoLite},
     show_subnet::SubnetState,
-    stake_info::{StakeAvailability, StakeInfo},
+    stake_info::{StakeAvailability, StakeInfo, StakeInfoWithCostBasis},
     subnet_info::{
         SubnetHyperparams, SubnetHyperparamsV2, SubnetHyperparamsV3, SubnetInfo, SubnetInfov2,
     },
+    validator_agreement::ValidatorAgreementReport,
+    weight_commits::WeightCommitStatus,
 };
 use pallet_subtensor::staking::lock::LockState;
-use sp_runtime::AccountId32;
+pub use pallet_subtensor::staking::lock::{LockAction, LockProjection};
+use pallet_subtensor::{
+    RecurringStake, ScheduledHyperparameterChange, utils::rate_limiting::Hyperparameter,
+};
+use sp_runtime::{AccountId32, DispatchError};
 use substrate_fixed::types::U64F64;
 use subtensor_runtime_common::{
     AlphaBalance, MechId, NetUid, ProxyFilterInfo, ProxyTypeInfo, TaoBalance,
@@ -61,6 +70,12 @@ sp_api::decl_runtime_apis! {
         fn get_subnet_to_prune() -> Option<NetUid>;
         fn get_subnet_account_id(netuid: NetUid) -> Option<AccountId32>;
         fn get_next_epoch_start_block(netuid: NetUid) -> Option<u64>;
+        fn simulate_epoch(netuid: NetUid, mecid: MechId, override_weights: Option<Vec<(u16, Vec<(u16, u16)>)>>) -> Option<EpochSimulation<AccountId32>>;
+        fn get_epoch_history(netuid: NetUid, mecid: MechId) -> Vec<EpochRecord<AccountId32>>;
+        fn get_weight_commit_status(hotkey: AccountId32, netuid: NetUid) -> Vec<WeightCommitStatus>;
+        fn get_validator_agreement(netuid: NetUid, mecid: MechId, similarity_threshold: u16) -> Option<ValidatorAgreementReport<AccountId32>>;
+        fn get_scheduled_hyperparameter_changes(netuid: NetUid) -> Vec<(Hyperparameter, ScheduledHyperparameterChange)>;
+        fn get_childkey_graph(netuid: NetUid) -> Option<ChildkeyGraph<AccountId32>>;
     }
 
     pub trait StakeInfoRuntimeApi {
@@ -70,8 +85,11 @@ sp_api::decl_runtime_apis! {
         fn get_stake_availability_for_coldkeys( coldkey_accounts: Vec<AccountId32>, netuids: Option<Vec<NetUid>> ) -> BTreeMap<AccountId32, BTreeMap<NetUid, StakeAvailability>>;
         fn get_stake_fee( origin: Option<(AccountId32, NetUid)>, origin_coldkey_account: AccountId32, destination: Option<(AccountId32, NetUid)>, destination_coldkey_account: AccountId32, amount: u64 ) -> u64;
         fn get_coldkey_lock(coldkey: AccountId32, netuid: NetUid) -> Option<LockState>;
+        fn get_coldkey_lock_projection(coldkey: AccountId32, netuid: NetUid, blocks: Vec<u64>, action: Option<LockAction<AccountId32>>) -> Result<Vec<LockProjection>, DispatchError>;
         fn get_hotkey_conviction(hotkey: AccountId32, netuid: NetUid) -> U64F64;
         fn get_most_convicted_hotkey_on_subnet(netuid: NetUid) -> Option<AccountId32>;
+        fn get_recurring_stakes(coldkey: AccountId32) -> Vec<(u64, RecurringStake<AccountId32>)>;
+        fn get_stake_info_with_cost_basis(coldkey: AccountId32) -> Vec<StakeInfoWithCostBasis>;
     }
 
     pub trait SubnetRegistrationRuntimeApi {
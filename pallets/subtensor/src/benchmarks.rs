@@ -17,13 +17,13 @@ use frame_system::{RawOrigin, pallet_prelude::BlockNumberFor};
 pub use pallet::*;
 use sp_core::{H160, H256, ecdsa};
 use sp_runtime::{
-    BoundedVec, Percent,
+    BoundedVec, Perbill, Percent,
     traits::{BlakeTwo256, Dispatchable, Hash},
 };
 use sp_std::collections::{btree_set::BTreeSet, vec_deque::VecDeque};
 use sp_std::vec;
 use substrate_fixed::types::U64F64;
-use subtensor_runtime_common::{AlphaBalance, NetUid, NetUidStorageIndex, TaoBalance};
+use subtensor_runtime_common::{AlphaBalance, MechId, NetUid, NetUidStorageIndex, TaoBalance};
 use subtensor_swap_interface::SwapHandler;
 
 #[benchmarks(
@@ -104,6 +104,23 @@ mod pallet_benchmarks {
         frame_system::Pallet::<T>::set_block_number(block_number);
     }
 
+    /// Turns commit-reveal on with every mechanism overriding the reveal period, so the weights
+    /// follow lag is checked against each of them.
+    fn override_mechanism_reveal_periods<T: Config>(netuid: NetUid, reveal_period: u64) {
+        Subtensor::<T>::set_commit_reveal_weights_enabled(netuid, true);
+        let mechanism_count = MaxMechanismCount::<T>::get();
+        MechanismCountCurrent::<T>::insert(netuid, mechanism_count);
+        for mecid in 0..u8::from(mechanism_count) {
+            MechanismHyperparamOverrides::<T>::insert(
+                Subtensor::<T>::get_mechanism_storage_index(netuid, mecid.into()),
+                MechanismHyperparams {
+                    reveal_period: Some(reveal_period),
+                    ..Default::default()
+                },
+            );
+        }
+    }
+
     fn runtime_call<T: Config>(call: Call<T>) -> <T as frame_system::Config>::RuntimeCall {
         <T as Config>::RuntimeCall::from(call).into()
     }
@@ -1208,6 +1225,67 @@ mod pallet_benchmarks {
         );
     }
 
+    #[benchmark]
+    fn swap_stake_min_out() {
+        let coldkey: T::AccountId = whitelisted_caller::<AccountIdOf<T>>();
+        let hot: T::AccountId = account("A", 0, 1);
+        let netuid1 = NetUid::from(1);
+        let netuid2 = NetUid::from(2);
+
+        SubtokenEnabled::<T>::insert(netuid1, true);
+        Subtensor::<T>::init_new_network(netuid1, 1);
+        Subtensor::<T>::set_network_registration_allowed(netuid1, true);
+
+        SubtokenEnabled::<T>::insert(netuid2, true);
+        Subtensor::<T>::init_new_network(netuid2, 1);
+        Subtensor::<T>::set_network_registration_allowed(netuid2, true);
+
+        let tao_reserve = TaoBalance::from(150_000_000_000_u64);
+        let alpha_in = AlphaBalance::from(100_000_000_000_u64);
+        set_reserves::<T>(netuid1, tao_reserve, alpha_in);
+        set_reserves::<T>(netuid2, tao_reserve, alpha_in);
+
+        Subtensor::<T>::increase_total_stake(1_000_000_000_000_u64.into());
+
+        let amount = TaoBalance::from(900_000_000_000_u64);
+        add_balance_to_coldkey_account::<T>(&coldkey.clone(), amount);
+        add_lock::<T>(&coldkey, netuid1);
+        add_lock::<T>(&coldkey, netuid2);
+
+        assert_ok!(Subtensor::<T>::burned_register(
+            RawOrigin::Signed(coldkey.clone()).into(),
+            netuid1,
+            hot.clone()
+        ));
+        assert_ok!(Subtensor::<T>::burned_register(
+            RawOrigin::Signed(coldkey.clone()).into(),
+            netuid2,
+            hot.clone()
+        ));
+
+        // Worst case: the largest amount only fills in part at the requested rate, so the
+        // bisection runs over the whole range of the amount
+        let amount_swapped = AlphaBalance::from(u64::MAX / 2);
+        Subtensor::<T>::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &hot,
+            &coldkey,
+            netuid1,
+            amount_swapped,
+        );
+        let min_alpha_out = AlphaBalance::from(u64::MAX / 4);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(coldkey.clone()),
+            hot.clone(),
+            netuid1,
+            netuid2,
+            amount_swapped,
+            min_alpha_out,
+            true,
+        );
+    }
+
     #[benchmark]
     fn transfer_stake() {
         let coldkey: T::AccountId = whitelisted_caller();
@@ -2327,6 +2405,275 @@ mod pallet_benchmarks {
         _(RawOrigin::Signed(coldkey.clone()), netuid);
     }
 
+    #[benchmark]
+    fn transfer_locked_stake() {
+        let netuid = NetUid::from(1);
+        let tempo: u16 = 1;
+
+        Subtensor::<T>::init_new_network(netuid, tempo);
+        SubtokenEnabled::<T>::insert(netuid, true);
+        Subtensor::<T>::set_burn(netuid, benchmark_registration_burn());
+        Subtensor::<T>::set_network_registration_allowed(netuid, true);
+        Subtensor::<T>::set_max_allowed_uids(netuid, 4096);
+
+        let seed: u32 = 1;
+        let coldkey: T::AccountId = account("Test", 0, seed);
+        let coldkey_dest: T::AccountId = account("Dest", 0, seed);
+        let hotkey: T::AccountId = account("Alice", 0, seed);
+        let total_stake = TaoBalance::from(1_000_000_000);
+        let amount = AlphaBalance::from(60_000_000);
+
+        seed_swap_reserves::<T>(netuid);
+        let burn = Subtensor::<T>::get_burn(netuid);
+        add_balance_to_coldkey_account::<T>(
+            &coldkey,
+            total_stake
+                .saturating_mul(2.into())
+                .saturating_add(burn)
+                .into(),
+        );
+
+        assert_ok!(Subtensor::<T>::burned_register(
+            RawOrigin::Signed(coldkey.clone()).into(),
+            netuid,
+            hotkey.clone()
+        ));
+
+        assert_ok!(Subtensor::<T>::add_stake(
+            RawOrigin::Signed(coldkey.clone()).into(),
+            hotkey.clone(),
+            netuid,
+            total_stake
+        ));
+
+        assert_ok!(Subtensor::<T>::do_lock_stake(
+            &coldkey, netuid, &hotkey, amount,
+        ));
+        Subtensor::<T>::set_accept_locked_alpha(&coldkey_dest, true);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(coldkey.clone()),
+            coldkey_dest.clone(),
+            netuid,
+            amount,
+        );
+
+        assert_eq!(
+            Lock::<T>::get((coldkey_dest, netuid, hotkey)).map(|lock| lock.locked_mass),
+            Some(amount)
+        );
+    }
+
+    #[benchmark]
+    fn follow_weights() {
+        let netuid = NetUid::from(1);
+        let hotkey: T::AccountId = account("Follower", 0, 1);
+        let leader: T::AccountId = account("Leader", 0, 2);
+
+        Subtensor::<T>::init_new_network(netuid, 1);
+        Subtensor::<T>::set_stake_threshold(0);
+        Subtensor::<T>::append_neuron(netuid, &leader, 0);
+        Subtensor::<T>::append_neuron(netuid, &hotkey, 0);
+
+        // Worst case: the subnet is one follower below the limit and the lag is checked against
+        // the reveal period of every mechanism.
+        override_mechanism_reveal_periods::<T>(netuid, 2);
+        for i in 1..MAX_WEIGHTS_FOLLOWERS_PER_SUBNET {
+            let follower: T::AccountId = account("Follower", i, 1);
+            WeightsLeader::<T>::insert(netuid, &follower, (leader.clone(), 2));
+        }
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(hotkey.clone()), netuid, leader.clone(), 2);
+
+        assert_eq!(WeightsLeader::<T>::get(netuid, &hotkey), Some((leader, 2)));
+    }
+
+    #[benchmark]
+    fn apply_followed_weights() {
+        let netuid = NetUid::from(1);
+        let netuid_index = NetUidStorageIndex::from(netuid);
+        let leader: T::AccountId = account("Leader", 0, 2);
+
+        Subtensor::<T>::init_new_network(netuid, 1);
+        Subtensor::<T>::set_max_allowed_uids(netuid, 4096);
+        Subtensor::<T>::append_neuron(netuid, &leader, 0);
+        let row: Vec<(u16, u16)> = (0..4096).map(|uid| (uid, u16::MAX)).collect();
+        Weights::<T>::insert(netuid_index, 0, row.clone());
+
+        // Worst case: every follower has a full queue of full rows to rotate and copy, and its
+        // lag is checked against the reveal period of every mechanism.
+        override_mechanism_reveal_periods::<T>(netuid, u64::from(MAX_WEIGHTS_FOLLOW_LAG_EPOCHS));
+        let rows = BoundedVec::truncate_from(vec![(1, row); MAX_FOLLOWED_WEIGHT_ROWS as usize]);
+        for i in 0..MAX_WEIGHTS_FOLLOWERS_PER_SUBNET {
+            let follower: T::AccountId = account("Follower", i, 1);
+            Subtensor::<T>::append_neuron(netuid, &follower, 0);
+            WeightsLeader::<T>::insert(
+                netuid,
+                &follower,
+                (leader.clone(), MAX_WEIGHTS_FOLLOW_LAG_EPOCHS),
+            );
+            FollowedWeightRows::<T>::insert(netuid_index, &follower, rows.clone());
+        }
+
+        #[block]
+        {
+            Subtensor::<T>::apply_followed_weights(netuid, MechId::MAIN);
+        }
+
+        assert_eq!(
+            Weights::<T>::get(netuid_index, 1).len(),
+            4096,
+            "followers should get the lagged row"
+        );
+    }
+
+    #[benchmark]
+    fn schedule_recurring_stake() {
+        let netuid = NetUid::from(1);
+        let coldkey: T::AccountId = account("Test", 0, 1);
+        let hotkey: T::AccountId = account("Alice", 0, 1);
+
+        Subtensor::<T>::init_new_network(netuid, 1);
+        let _ = Subtensor::<T>::create_account_if_non_existent(&coldkey, &hotkey);
+
+        // Worst case: the coldkey is one schedule below the limit.
+        for _ in 1..MAX_RECURRING_STAKES_PER_COLDKEY {
+            assert_ok!(Subtensor::<T>::do_schedule_recurring_stake(
+                coldkey.clone(),
+                hotkey.clone(),
+                netuid,
+                DefaultMinStake::<T>::get(),
+                100,
+                None,
+                1_000,
+            ));
+        }
+        // The next block is full, so the first execution moves to the block after.
+        RecurringStakesDue::<T>::insert(
+            Subtensor::<T>::get_current_block_as_u64().saturating_add(1),
+            BoundedVec::truncate_from(vec![
+                (coldkey.clone(), u64::MAX);
+                MAX_RECURRING_STAKES_DUE_PER_BLOCK as usize
+            ]),
+        );
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(coldkey.clone()),
+            hotkey.clone(),
+            netuid,
+            DefaultMinStake::<T>::get(),
+            100,
+            Some(TaoBalance::from(1_000_000_000_u64)),
+            1_000,
+        );
+
+        assert_eq!(
+            RecurringStakes::<T>::iter_prefix(&coldkey).count(),
+            MAX_RECURRING_STAKES_PER_COLDKEY as usize
+        );
+    }
+
+    #[benchmark]
+    fn cancel_recurring_stake() {
+        let netuid = NetUid::from(1);
+        let coldkey: T::AccountId = account("Test", 0, 1);
+        let hotkey: T::AccountId = account("Alice", 0, 1);
+
+        Subtensor::<T>::init_new_network(netuid, 1);
+        let _ = Subtensor::<T>::create_account_if_non_existent(&coldkey, &hotkey);
+        assert_ok!(Subtensor::<T>::do_schedule_recurring_stake(
+            coldkey.clone(),
+            hotkey,
+            netuid,
+            DefaultMinStake::<T>::get(),
+            100,
+            None,
+            1_000,
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(coldkey.clone()), 0);
+
+        assert!(RecurringStakes::<T>::get(&coldkey, 0).is_none());
+    }
+
+    #[benchmark]
+    fn rebalance_portfolio(k: Linear<1, 32>) {
+        let coldkey: T::AccountId = account("Test", 0, 1);
+        let hotkey: T::AccountId = account("Alice", 0, 1);
+        let _ = Subtensor::<T>::create_account_if_non_existent(&coldkey, &hotkey);
+        add_balance_to_coldkey_account::<T>(&coldkey, TaoBalance::from(900_000_000_000_u64));
+
+        // The whole stake sits on the first subnet and is spread evenly over `k` subnets.
+        let mut targets = Vec::new();
+        for i in 0..k {
+            let netuid = NetUid::from((i as u16).saturating_add(1));
+            Subtensor::<T>::init_new_network(netuid, 1);
+            SubtokenEnabled::<T>::insert(netuid, true);
+            set_reserves::<T>(
+                netuid,
+                TaoBalance::from(1_000_000_000_000_u64),
+                AlphaBalance::from(1_000_000_000_000_u64),
+            );
+            add_lock::<T>(&coldkey, netuid);
+            targets.push((netuid, hotkey.clone(), Perbill::from_rational(1, k)));
+        }
+        assert_ok!(Subtensor::<T>::stake_into_subnet(
+            &hotkey,
+            &coldkey,
+            NetUid::from(1),
+            TaoBalance::from(100_000_000_000_u64),
+            T::SwapInterface::max_price(),
+            false,
+        ));
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(coldkey.clone()),
+            targets,
+            Perbill::from_percent(50),
+        );
+    }
+
+    #[benchmark]
+    fn set_cost_basis_tracking(s: Linear<1, 128>, h: Linear<1, MAX_COST_BASIS_STAKING_HOTKEYS>) {
+        let coldkey: T::AccountId = account("Test", 0, 1);
+
+        // The alpha of each staking hotkey on each subnet enters the ledger at its market value.
+        for i in 1..=s {
+            let netuid = NetUid::from(i as u16);
+            Subtensor::<T>::init_new_network(netuid, 1);
+            set_reserves::<T>(
+                netuid,
+                TaoBalance::from(1_000_000_000_000_u64),
+                AlphaBalance::from(1_000_000_000_000_u64),
+            );
+        }
+        for j in 0..h {
+            let hotkey: T::AccountId = account("Hotkey", j, 1);
+            let _ = Subtensor::<T>::create_account_if_non_existent(&coldkey, &hotkey);
+            for i in 1..=s {
+                Subtensor::<T>::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                    &hotkey,
+                    &coldkey,
+                    NetUid::from(i as u16),
+                    AlphaBalance::from(1_000_000_000_u64),
+                );
+            }
+        }
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(coldkey.clone()), true);
+
+        assert_eq!(
+            StakeCostBasis::<T>::iter_key_prefix(NetUid::from(1)).count(),
+            1
+        );
+    }
+
     #[benchmark]
     fn check_coldkey_swap_extension() {
         let coldkey: T::AccountId = account("coldkey", 0, 1);
@@ -11,23 +11,26 @@ impl<T: Config + pallet_drand::Config> Pallet<T> {
         // --- 1. Update registration burn prices.
         Self::update_registration_prices_for_networks();
 
-        // --- 2. Get the current coinbase emission.
+        // --- 2. Apply hyperparameter changes scheduled for this block, before any epoch runs.
+        Self::apply_scheduled_hyperparameter_changes(block_number);
+
+        // --- 3. Get the current coinbase emission.
         let block_emission = Self::get_block_emission();
         log::debug!("Block emission: {block_emission:?}");
 
-        // --- 3. Reveal matured weights.
+        // --- 4. Reveal matured weights.
         Self::reveal_crv3_commits();
-        // --- 4. Run emission through network.
+        // --- 5. Run emission through network.
         Self::run_coinbase(block_emission);
-        // --- 5. Update moving prices AFTER using them for emissions.
+        // --- 6. Update moving prices AFTER using them for emissions.
         Self::update_moving_prices();
-        // --- 6. Update roop prop AFTER using them for emissions.
+        // --- 7. Update roop prop AFTER using them for emissions.
         Self::update_root_prop();
-        // --- 7. Set pending children on the epoch; but only after the coinbase has been run.
+        // --- 8. Set pending children on the epoch; but only after the coinbase has been run.
         Self::try_set_pending_children(block_number);
-        // --- 8. Run auto-claim root divs.
+        // --- 9. Run auto-claim root divs.
         Self::run_auto_claim_root_divs(last_block_hash);
-        // --- 9. Populate root coldkey maps.
+        // --- 10. Populate root coldkey maps.
         Self::populate_root_coldkey_staking_maps();
         Self::populate_root_coldkey_staking_maps_v2();
 
@@ -37,18 +37,18 @@ pub struct LegacyWeightsTlockPayload {
 impl<T: Config> Pallet<T> {
     /// The `reveal_crv3_commits` function is run at the very beginning of epoch `n`,
     pub fn reveal_crv3_commits_for_subnet(netuid: NetUid) -> dispatch::DispatchResult {
-        let reveal_period = Self::get_reveal_period(netuid);
         // If the subnet is deferred past this block the
         // commits are taken once here and the later block(s) become no-ops.
         let cur_epoch = Self::current_epoch_with_lookahead(netuid);
 
-        // Weights revealed must have been committed during epoch `cur_epoch - reveal_period`.
-        let reveal_epoch = cur_epoch.saturating_sub(reveal_period);
-
-        // All mechanisms share the same epoch, so the reveal_period/reveal_epoch are also the same
+        // All mechanisms share the same epoch, but each can override the reveal period
         // Reveal for all mechanisms
         for mecid in 0..MechanismCountCurrent::<T>::get(netuid).into() {
             let netuid_index = Self::get_mechanism_storage_index(netuid, mecid.into());
+            let reveal_period = Self::get_mechanism_reveal_period(netuid_index);
+
+            // Weights revealed must have been committed during epoch `cur_epoch - reveal_period`.
+            let reveal_epoch = cur_epoch.saturating_sub(reveal_period);
 
             // Clean expired commits
             for (epoch, _) in TimelockedWeightCommits::<T>::iter_prefix(netuid_index) {
@@ -60,7 +60,7 @@ impl<T: Config> Pallet<T> {
             // No commits to reveal until at least epoch reveal_period.
             if cur_epoch < reveal_period {
                 log::trace!("Failed to reveal commit for mechanism {netuid_index} Too early");
-                return Ok(());
+                continue;
             }
 
             let mut entries = TimelockedWeightCommits::<T>::take(netuid_index, reveal_epoch);
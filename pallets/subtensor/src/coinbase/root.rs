@@ -212,7 +212,9 @@ impl<T: Config> Pallet<T> {
 
         Self::finalize_all_subnet_root_dividends(netuid);
 
-        // --- Perform the cleanup before removing the network.
+        // --- Perform the cleanup before removing the network. User liquidity is
+        // returned to its providers first so that it is settled with the stakes.
+        T::SwapInterface::dissolve_user_liquidity(netuid)?;
         Self::destroy_alpha_in_out_stakes(netuid)?;
         T::SwapInterface::clear_protocol_liquidity(netuid)?;
         T::CommitmentsInterface::purge_netuid(netuid);
@@ -360,6 +362,10 @@ impl<T: Config> Pallet<T> {
         // --- 17. Subtoken / feature flags.
         LiquidAlphaOn::<T>::remove(netuid);
         Yuma3On::<T>::remove(netuid);
+        SubnetConsensusAlgorithm::<T>::remove(netuid);
+        let _ = StakeCostBasis::<T>::clear_prefix(netuid, u32::MAX, None);
+        // Due entries of the removed changes are skipped when their block is reached.
+        let _ = ScheduledHyperparameterChanges::<T>::clear_prefix(netuid, u32::MAX, None);
         AlphaValues::<T>::remove(netuid);
         SubtokenEnabled::<T>::remove(netuid);
         OwnerCutAutoLockEnabled::<T>::remove(netuid);
@@ -384,13 +390,17 @@ impl<T: Config> Pallet<T> {
             let netuid_index = Self::get_mechanism_storage_index(netuid, subid.into());
             LastUpdate::<T>::remove(netuid_index);
             Incentive::<T>::remove(netuid_index);
+            Self::clear_epoch_history(netuid_index);
+            MechanismHyperparamOverrides::<T>::remove(netuid_index);
             let _ = WeightCommits::<T>::clear_prefix(netuid_index, u32::MAX, None);
             let _ = TimelockedWeightCommits::<T>::clear_prefix(netuid_index, u32::MAX, None);
             let _ = CRV3WeightCommits::<T>::clear_prefix(netuid_index, u32::MAX, None);
             let _ = CRV3WeightCommitsV2::<T>::clear_prefix(netuid_index, u32::MAX, None);
             let _ = Bonds::<T>::clear_prefix(netuid_index, u32::MAX, None);
             let _ = Weights::<T>::clear_prefix(netuid_index, u32::MAX, None);
+            let _ = FollowedWeightRows::<T>::clear_prefix(netuid_index, u32::MAX, None);
         }
+        let _ = WeightsLeader::<T>::clear_prefix(netuid, u32::MAX, None);
         RevealPeriodEpochs::<T>::remove(netuid);
         MechanismCountCurrent::<T>::remove(netuid);
         MechanismEmissionSplit::<T>::remove(netuid);
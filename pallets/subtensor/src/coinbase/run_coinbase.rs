@@ -946,8 +946,40 @@ impl<T: Config> Pallet<T> {
         netuid: NetUid,
         dividends: AlphaBalance,
     ) -> Vec<(T::AccountId, AlphaBalance)> {
-        // hotkey dividends.
-        let mut dividend_tuples: Vec<(T::AccountId, AlphaBalance)> = vec![];
+        let (parent_splits, child_emission) =
+            Self::split_parent_child_dividends(hotkey, netuid, dividends);
+
+        let mut dividend_tuples: Vec<(T::AccountId, AlphaBalance)> =
+            Vec::with_capacity(parent_splits.len().saturating_add(1));
+        for (parent, parent_emission, _, burn_take) in parent_splits {
+            Self::recycle_subnet_alpha(netuid, burn_take);
+            dividend_tuples.push((parent, parent_emission));
+        }
+
+        // Add the hotkey's own emission to the distribution list
+        dividend_tuples.push((hotkey.clone(), child_emission));
+
+        dividend_tuples
+    }
+
+    /// Splits the dividends of a hotkey between its parents and itself, without recycling the
+    /// burned childkey take.
+    ///
+    /// # Returns
+    /// * `(Vec<(parent, parent_emission, child_take, burn_take)>, child_emission)` - What each
+    ///   parent gets, with the take kept by the hotkey and burned out of its share, and what the
+    ///   hotkey gets.
+    pub fn split_parent_child_dividends(
+        hotkey: &T::AccountId,
+        netuid: NetUid,
+        dividends: AlphaBalance,
+    ) -> (
+        Vec<(T::AccountId, AlphaBalance, AlphaBalance, AlphaBalance)>,
+        AlphaBalance,
+    ) {
+        // Dividends of each parent.
+        let mut parent_splits: Vec<(T::AccountId, AlphaBalance, AlphaBalance, AlphaBalance)> =
+            vec![];
 
         // Calculate the hotkey's share of the validator emission based on its childkey take
         let validating_emission: U96F32 = U96F32::saturating_from_num(dividends);
@@ -1037,11 +1069,6 @@ impl<T: Config> Pallet<T> {
                 parent_emission = parent_emission.saturating_sub(burn_take);
                 parent_emission = parent_emission.saturating_sub(child_take);
                 total_child_take = total_child_take.saturating_add(child_take);
-
-                Self::recycle_subnet_alpha(
-                    netuid,
-                    AlphaBalance::from(burn_take.saturating_to_num::<u64>()),
-                );
             };
             log::debug!("burn_takee: {burn_take:?} for hotkey {hotkey:?}");
             log::debug!("child_take: {child_take:?} for hotkey {hotkey:?}");
@@ -1051,9 +1078,11 @@ impl<T: Config> Pallet<T> {
             log::debug!("remaining emission: {remaining_emission:?}");
 
             // Add the parent's emission to the distribution list
-            dividend_tuples.push((
+            parent_splits.push((
                 parent.clone(),
                 parent_emission.saturating_to_num::<u64>().into(),
+                child_take.saturating_to_num::<u64>().into(),
+                burn_take.saturating_to_num::<u64>().into(),
             ));
 
             // Keep track of total emission distributed to parents
@@ -1069,10 +1098,7 @@ impl<T: Config> Pallet<T> {
             .saturating_to_num::<u64>()
             .into();
 
-        // Add the hotkey's own emission to the distribution list
-        dividend_tuples.push((hotkey.clone(), child_emission));
-
-        dividend_tuples
+        (parent_splits, child_emission)
     }
 
     /// Checks if the epoch should run for a given subnet based on the current block.
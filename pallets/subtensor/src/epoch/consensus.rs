@@ -0,0 +1,305 @@
+//! Consensus algorithms run by the subnet epoch.
+//!
+//! Every algorithm receives the same masked and row-normalized weights and active stake, and
+//! produces the terms that `epoch_mechanism` turns into emissions and persists through
+//! `persist_mechanism_epoch_terms`. The algorithm is selected per subnet with
+//! `SubnetConsensusAlgorithm`.
+
+use super::*;
+use crate::epoch::math::*;
+use sp_std::vec::Vec;
+use substrate_fixed::types::I32F32;
+use subtensor_runtime_common::{NetUid, NetUidStorageIndex};
+
+/// Epoch state shared by all consensus algorithms.
+#[derive(Clone, Copy)]
+pub struct ConsensusInputs<'a> {
+    pub netuid: NetUid,
+    pub netuid_index: NetUidStorageIndex,
+    /// Subnetwork size.
+    pub n: u16,
+    pub current_block: u64,
+    pub tempo: u64,
+    pub block_at_registration: &'a [u64],
+    /// Normalized stake of active validators.
+    pub active_stake: &'a [I32F32],
+    /// Masked and row-normalized weights.
+    pub weights: &'a [Vec<(u16, I32F32)>],
+}
+
+/// Output of a consensus algorithm, all vectors indexed by uid.
+#[derive(Debug, Default)]
+pub struct ConsensusTerms {
+    pub consensus: Vec<I32F32>,
+    /// Weights after clipping at consensus, or the weights themselves if not clipped.
+    pub clipped_weights: Vec<Vec<(u16, I32F32)>>,
+    /// Stake-weighted sum of the clipped weights.
+    pub ranks: Vec<I32F32>,
+    pub validator_trust: Vec<I32F32>,
+    /// Normalized miner incentive.
+    pub incentive: Vec<I32F32>,
+    /// Normalized validator dividends.
+    pub dividends: Vec<I32F32>,
+    /// Bonds to persist; empty rows for algorithms without bonds.
+    pub bonds: Vec<Vec<(u16, I32F32)>>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Dispatches the epoch consensus to the selected algorithm.
+    pub fn run_consensus(
+        algorithm: ConsensusAlgorithm,
+        inputs: &ConsensusInputs,
+    ) -> ConsensusTerms {
+        log::trace!("Consensus algorithm: {algorithm:?}");
+        match algorithm {
+            ConsensusAlgorithm::Yuma => Self::yuma_consensus_sparse(inputs),
+            ConsensusAlgorithm::MedianClipped => Self::median_clipped_consensus_sparse(inputs),
+            ConsensusAlgorithm::StakeWeighted => Self::stake_weighted_consensus_sparse(inputs),
+        }
+    }
+
+    /// Yuma consensus: weights are clipped at the stake-weighted median and validators earn
+    /// dividends through their EMA bonds (Yuma3 and liquid alpha variants included).
+    pub fn yuma_consensus_sparse(inputs: &ConsensusInputs) -> ConsensusTerms {
+        let ConsensusInputs {
+            netuid,
+            netuid_index,
+            n,
+            current_block,
+            tempo,
+            block_at_registration,
+            active_stake,
+            weights,
+        } = *inputs;
+
+        // Consensus majority ratio, e.g. 51%.
+        let kappa: I32F32 = Self::get_float_mechanism_kappa(netuid_index);
+        // Calculate consensus as stake-weighted median of weights.
+        let consensus: Vec<I32F32> = weighted_median_col_sparse(active_stake, weights, n, kappa);
+        log::trace!("Consensus: {:?}", &consensus);
+
+        // Clip weights at majority consensus.
+        let clipped_weights: Vec<Vec<(u16, I32F32)>> = col_clip_sparse(weights, &consensus);
+        log::trace!("Clipped Weights: {:?}", &clipped_weights);
+
+        // Calculate validator trust as sum of clipped weights set by validator.
+        let validator_trust: Vec<I32F32> = row_sum_sparse(&clipped_weights);
+        log::trace!("Validator Trust: {:?}", &validator_trust);
+
+        // =============================
+        // == Ranks, Trust, Incentive ==
+        // =============================
+
+        // Compute ranks: r_j = SUM(i) w_ij * s_i.
+        let mut ranks: Vec<I32F32> = matmul_sparse(&clipped_weights, active_stake, n);
+
+        inplace_normalize(&mut ranks); // range: I32F32(0, 1)
+        let incentive: Vec<I32F32> = ranks.clone();
+        log::trace!("Incentive (=Rank): {:?}", &incentive);
+
+        // =========================
+        // == Bonds and Dividends ==
+        // =========================
+
+        // Get validator bonds penalty in [0, 1].
+        let bonds_penalty: I32F32 = Self::get_float_bonds_penalty(netuid);
+        // Calculate weights for bonds, apply bonds penalty to weights.
+        // bonds_penalty = 0: weights_for_bonds = weights.clone()
+        // bonds_penalty = 1: weights_for_bonds = clipped_weights.clone()
+        let weights_for_bonds: Vec<Vec<(u16, I32F32)>> =
+            interpolate_sparse(weights, &clipped_weights, n, bonds_penalty);
+
+        let mut dividends: Vec<I32F32>;
+        let mut ema_bonds: Vec<Vec<(u16, I32F32)>>;
+        if Yuma3On::<T>::get(netuid) {
+            // Access network bonds.
+            let mut bonds = Self::get_bonds_sparse_fixed_proportion(netuid_index);
+            log::trace!("Bonds: {:?}", &bonds);
+
+            // Remove bonds referring to neurons that have registered since last tempo.
+            // Mask if: the last tempo block happened *before* the registration block
+            // ==> last_tempo <= registered
+            // For dynamic tempo - we pick previous-successful-epoch block: `LastMechansimStepBlock + 1`
+            let lms = LastMechansimStepBlock::<T>::get(netuid);
+            let last_tempo: u64 = if lms == 0 {
+                current_block.saturating_sub(tempo)
+            } else {
+                lms.saturating_add(1)
+            };
+            bonds = scalar_vec_mask_sparse_matrix(
+                &bonds,
+                last_tempo,
+                block_at_registration,
+                &|last_tempo, registered| last_tempo <= registered,
+            );
+            log::trace!("Bonds: (mask) {:?}", &bonds);
+
+            // Compute the Exponential Moving Average (EMA) of bonds.
+            log::trace!("weights_for_bonds: {:?}", &weights_for_bonds);
+            ema_bonds =
+                Self::compute_bonds_sparse(netuid_index, &weights_for_bonds, &bonds, &consensus);
+            log::trace!("emaB: {:?}", &ema_bonds);
+
+            // Normalize EMA bonds.
+            let mut ema_bonds_norm = ema_bonds.clone();
+            inplace_col_normalize_sparse(&mut ema_bonds_norm, n); // sum_i b_ij = 1
+            log::trace!("emaB norm: {:?}", &ema_bonds_norm);
+
+            // # === Dividend Calculation===
+            let total_bonds_per_validator: Vec<I32F32> =
+                row_sum_sparse(&mat_vec_mul_sparse(&ema_bonds_norm, &incentive));
+            log::trace!(
+                "total_bonds_per_validator: {:?}",
+                &total_bonds_per_validator
+            );
+
+            dividends = vec_mul(&total_bonds_per_validator, active_stake);
+            inplace_normalize(&mut dividends);
+            log::trace!("Dividends: {:?}", &dividends);
+        } else {
+            // original Yuma - liquid alpha disabled
+            // Access network bonds.
+            let mut bonds: Vec<Vec<(u16, I32F32)>> = Self::get_bonds_sparse(netuid_index);
+            log::trace!("B: {:?}", &bonds);
+
+            // Remove bonds referring to neurons that have registered since last tempo.
+            // Mask if: the last tempo block happened *before* the registration block
+            // ==> last_tempo <= registered
+            // For dynamic tempo - we pick previous-successful-epoch block: `LastMechansimStepBlock + 1`
+            let lms = LastMechansimStepBlock::<T>::get(netuid);
+            let last_tempo: u64 = if lms == 0 {
+                current_block.saturating_sub(tempo)
+            } else {
+                lms.saturating_add(1)
+            };
+            bonds = scalar_vec_mask_sparse_matrix(
+                &bonds,
+                last_tempo,
+                block_at_registration,
+                &|last_tempo, registered| last_tempo <= registered,
+            );
+            log::trace!("B (outdatedmask): {:?}", &bonds);
+
+            // Normalize remaining bonds: sum_i b_ij = 1.
+            inplace_col_normalize_sparse(&mut bonds, n);
+            log::trace!("B (mask+norm): {:?}", &bonds);
+
+            // Compute bonds delta column normalized.
+            let mut bonds_delta: Vec<Vec<(u16, I32F32)>> =
+                row_hadamard_sparse(&weights_for_bonds, active_stake); // ΔB = W◦S (outdated W masked)
+            log::trace!("ΔB: {:?}", &bonds_delta);
+
+            // Normalize bonds delta.
+            inplace_col_normalize_sparse(&mut bonds_delta, n); // sum_i b_ij = 1
+            log::trace!("ΔB (norm): {:?}", &bonds_delta);
+
+            // Compute the Exponential Moving Average (EMA) of bonds.
+            ema_bonds = Self::compute_ema_bonds_normal_sparse(&bonds_delta, &bonds, netuid_index);
+            // Normalize EMA bonds.
+            inplace_col_normalize_sparse(&mut ema_bonds, n); // sum_i b_ij = 1
+            log::trace!("Exponential Moving Average Bonds: {:?}", &ema_bonds);
+
+            // Compute dividends: d_i = SUM(j) b_ij * inc_j.
+            // range: I32F32(0, 1)
+            dividends = matmul_transpose_sparse(&ema_bonds, &incentive);
+            inplace_normalize(&mut dividends);
+            log::trace!("Dividends: {:?}", &dividends);
+
+            // Column max-upscale EMA bonds for storage: max_i w_ij = 1.
+            inplace_col_max_upscale_sparse(&mut ema_bonds, n);
+        }
+
+        ConsensusTerms {
+            consensus,
+            clipped_weights,
+            ranks,
+            validator_trust,
+            incentive,
+            dividends,
+            bonds: ema_bonds,
+        }
+    }
+
+    /// Median-clipped consensus without bonds: weights are clipped at the stake-weighted
+    /// median as in Yuma, and validators earn dividends in proportion to their stake times
+    /// their validator trust.
+    pub fn median_clipped_consensus_sparse(inputs: &ConsensusInputs) -> ConsensusTerms {
+        let ConsensusInputs {
+            netuid,
+            netuid_index,
+            n,
+            active_stake,
+            weights,
+            ..
+        } = *inputs;
+
+        // Calculate consensus as stake-weighted median of weights.
+        let kappa: I32F32 = Self::get_float_mechanism_kappa(netuid_index);
+        let consensus: Vec<I32F32> = weighted_median_col_sparse(active_stake, weights, n, kappa);
+        log::trace!("Consensus: {:?}", &consensus);
+
+        // Clip weights at majority consensus.
+        let clipped_weights: Vec<Vec<(u16, I32F32)>> = col_clip_sparse(weights, &consensus);
+        log::trace!("Clipped Weights: {:?}", &clipped_weights);
+
+        let validator_trust: Vec<I32F32> = row_sum_sparse(&clipped_weights);
+        log::trace!("Validator Trust: {:?}", &validator_trust);
+
+        let ranks: Vec<I32F32> = matmul_sparse(&clipped_weights, active_stake, n);
+        let mut incentive: Vec<I32F32> = ranks.clone();
+        inplace_normalize(&mut incentive);
+        log::trace!("Incentive: {:?}", &incentive);
+
+        let mut dividends: Vec<I32F32> = vec_mul(&validator_trust, active_stake);
+        inplace_normalize(&mut dividends);
+        log::trace!("Dividends: {:?}", &dividends);
+
+        ConsensusTerms {
+            consensus,
+            clipped_weights,
+            ranks,
+            validator_trust,
+            incentive,
+            dividends,
+            bonds: Vec::new(),
+        }
+    }
+
+    /// Stake-weighted averaging without clipping nor bonds: miner incentive is the
+    /// stake-weighted average of the weights, and validators that set weights earn dividends
+    /// in proportion to their stake.
+    pub fn stake_weighted_consensus_sparse(inputs: &ConsensusInputs) -> ConsensusTerms {
+        let ConsensusInputs {
+            n,
+            active_stake,
+            weights,
+            ..
+        } = *inputs;
+
+        // Calculate consensus as stake-weighted average of weights.
+        let consensus: Vec<I32F32> = matmul_sparse(weights, active_stake, n);
+        log::trace!("Consensus: {:?}", &consensus);
+
+        let validator_trust: Vec<I32F32> = row_sum_sparse(weights);
+        log::trace!("Validator Trust: {:?}", &validator_trust);
+
+        let ranks: Vec<I32F32> = consensus.clone();
+        let mut incentive: Vec<I32F32> = ranks.clone();
+        inplace_normalize(&mut incentive);
+        log::trace!("Incentive: {:?}", &incentive);
+
+        let mut dividends: Vec<I32F32> = vec_mul(&validator_trust, active_stake);
+        inplace_normalize(&mut dividends);
+        log::trace!("Dividends: {:?}", &dividends);
+
+        ConsensusTerms {
+            consensus,
+            clipped_weights: weights.to_vec(),
+            ranks,
+            validator_trust,
+            incentive,
+            dividends,
+            bonds: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use super::*;
+use crate::epoch::run_epoch::EpochTerms;
+use alloc::collections::BTreeMap;
+use codec::{Decode, DecodeWithMemTracking, Encode};
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+use subtensor_runtime_common::{AlphaBalance, MechId, NetUid, NetUidStorageIndex};
+
+/// Summary of the epoch terms of a subnet mechanism, indexed by uid.
+#[crate::freeze_struct("7490b3c142d23e43")]
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct EpochRecord<AccountId: TypeInfo + Encode + Decode> {
+    /// Block the epoch ran at.
+    pub block: u64,
+    /// Hotkey registered on each uid when the epoch ran.
+    pub hotkeys: Vec<AccountId>,
+    pub incentive: Vec<u16>,
+    pub dividend: Vec<u16>,
+    pub emission: Vec<AlphaBalance>,
+    pub consensus: Vec<u16>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Appends the epoch output of a subnet mechanism to its history and prunes the record that
+    /// falls out of the last `EpochHistoryLength` epochs.
+    pub(crate) fn record_epoch_history(
+        netuid_index: NetUidStorageIndex,
+        output: &BTreeMap<T::AccountId, EpochTerms>,
+    ) {
+        let mut entries_sorted: Vec<(&T::AccountId, &EpochTerms)> = output.iter().collect();
+        entries_sorted.sort_unstable_by_key(|(_, t)| t.uid);
+        let hotkeys: Vec<T::AccountId> = entries_sorted
+            .iter()
+            .map(|(hotkey, _)| (*hotkey).clone())
+            .collect();
+        let terms_sorted: Vec<&EpochTerms> = entries_sorted.into_iter().map(|(_, t)| t).collect();
+
+        let record = EpochRecord {
+            block: Self::get_current_block_as_u64(),
+            hotkeys,
+            incentive: crate::extract_from_sorted_terms!(terms_sorted, incentive),
+            dividend: crate::extract_from_sorted_terms!(terms_sorted, dividend),
+            emission: crate::extract_from_sorted_terms!(terms_sorted, emission),
+            consensus: crate::extract_from_sorted_terms!(terms_sorted, consensus),
+        };
+
+        // Records are keyed by their absolute epoch index, so changing `EpochHistoryLength`
+        // never makes a read window overlap stale slots.
+        let count = EpochHistoryCount::<T>::get(netuid_index);
+        EpochHistory::<T>::insert(netuid_index, count, record);
+        if let Some(expired) = count.checked_sub(T::EpochHistoryLength::get().into()) {
+            EpochHistory::<T>::remove(netuid_index, expired);
+        }
+        EpochHistoryCount::<T>::insert(netuid_index, count.saturating_add(1));
+    }
+
+    /// Recorded epochs of a subnet mechanism, oldest first.
+    pub fn get_epoch_history(netuid: NetUid, mecid: MechId) -> Vec<EpochRecord<T::AccountId>> {
+        let netuid_index = Self::get_mechanism_storage_index(netuid, mecid);
+        let count = EpochHistoryCount::<T>::get(netuid_index);
+        let first = count.saturating_sub(T::EpochHistoryLength::get().into());
+        (first..count)
+            .filter_map(|index| EpochHistory::<T>::get(netuid_index, index))
+            .collect()
+    }
+
+    /// Removes the epoch history of a subnet mechanism, including records left behind by a
+    /// lowered `EpochHistoryLength`.
+    pub(crate) fn clear_epoch_history(netuid_index: NetUidStorageIndex) {
+        let _ = EpochHistory::<T>::clear_prefix(netuid_index, u32::MAX, None);
+        EpochHistoryCount::<T>::remove(netuid_index);
+    }
+}
@@ -1592,3 +1592,44 @@ pub fn mat_ema_alpha(
 pub fn safe_ln(value: I32F32) -> I32F32 {
     ln(value).unwrap_or(I32F32::saturating_from_num(0.0))
 }
+
+/// Cosine similarity of two sparse vectors sorted by index, 0 if either vector is zero.
+pub fn cosine_similarity_sparse(a: &[(u16, I32F32)], b: &[(u16, I32F32)]) -> I32F32 {
+    let zero = I64F64::saturating_from_num(0);
+    let mut dot = zero;
+    let mut a_it = a.iter().peekable();
+    let mut b_it = b.iter().peekable();
+    while let (Some((i, x)), Some((j, y))) = (a_it.peek(), b_it.peek()) {
+        match i.cmp(j) {
+            core::cmp::Ordering::Less => {
+                a_it.next();
+            }
+            core::cmp::Ordering::Greater => {
+                b_it.next();
+            }
+            core::cmp::Ordering::Equal => {
+                dot = dot
+                    .saturating_add(fixed32_to_fixed64(*x).saturating_mul(fixed32_to_fixed64(*y)));
+                a_it.next();
+                b_it.next();
+            }
+        }
+    }
+
+    let squared_norm = |v: &[(u16, I32F32)]| {
+        v.iter().fold(zero, |acc, (_, x)| {
+            let x = fixed32_to_fixed64(*x);
+            acc.saturating_add(x.saturating_mul(x))
+        })
+    };
+    let epsilon =
+        I64F64::saturating_from_num(1).safe_div(I64F64::saturating_from_num(1_000_000_000_000_u64));
+    let norm = squared_norm(a)
+        .saturating_mul(squared_norm(b))
+        .checked_sqrt(epsilon)
+        .unwrap_or(zero);
+    if norm == zero {
+        return I32F32::saturating_from_num(0);
+    }
+    fixed64_to_fixed32(dot.safe_div(norm).min(I64F64::saturating_from_num(1)))
+}
@@ -1,3 +1,5 @@
 use super::*;
+pub mod consensus;
+pub mod history;
 pub mod math;
 pub mod run_epoch;
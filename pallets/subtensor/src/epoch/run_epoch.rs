@@ -1,4 +1,5 @@
 use super::*;
+use crate::epoch::consensus::{ConsensusInputs, ConsensusTerms};
 use crate::epoch::math::*;
 use alloc::collections::{BTreeMap, BTreeSet};
 use frame_support::IterableStorageDoubleMap;
@@ -25,6 +26,24 @@ pub struct EpochTerms {
     pub stake: AlphaBalance,
 }
 
+/// Intermediate vectors and matrices of an epoch, indexed by uid.
+#[derive(Debug, Default)]
+pub struct EpochIntermediates {
+    /// Normalized stake, after the stake threshold filter.
+    pub stake: Vec<I32F32>,
+    /// Normalized stake of active validators.
+    pub active_stake: Vec<I32F32>,
+    /// Masked and row-normalized weights.
+    pub weights: Vec<Vec<(u16, I32F32)>>,
+    pub clipped_weights: Vec<Vec<(u16, I32F32)>>,
+    pub consensus: Vec<I32F32>,
+    pub ranks: Vec<I32F32>,
+    pub validator_trust: Vec<I32F32>,
+    pub bonds: Vec<Vec<(u16, I32F32)>>,
+    pub dividends: Vec<I32F32>,
+    pub incentive: Vec<I32F32>,
+}
+
 pub struct EpochOutput<T: frame_system::Config>(pub BTreeMap<T::AccountId, EpochTerms>);
 
 impl<T: frame_system::Config> EpochOutput<T> {
@@ -103,6 +122,7 @@ impl<T: Config> Pallet<T> {
             .collect::<sp_std::vec::Vec<_>>();
 
         Incentive::<T>::insert(netuid_index, incentive);
+        Self::record_epoch_history(netuid_index, output);
 
         let server_emission = extract_from_sorted_terms!(terms_sorted, server_emission);
         Self::deposit_event(Event::IncentiveAlphaEmittedToMiners {
@@ -142,7 +162,7 @@ impl<T: Config> Pallet<T> {
     }
 
     /// Calculates reward consensus and returns the emissions for uids/hotkeys in a given `netuid`.
-    /// (Dense version used only for testing purposes.)
+    /// (Dense version used only for testing purposes, always runs Yuma consensus.)
     #[allow(clippy::indexing_slicing)]
     pub fn epoch_dense_mechanism(
         netuid: NetUid,
@@ -567,10 +587,24 @@ impl<T: Config> Pallet<T> {
         netuid: NetUid,
         mecid: MechId,
         rao_emission: AlphaBalance,
+    ) -> EpochOutput<T> {
+        Self::epoch_mechanism_with_intermediates(netuid, mecid, rao_emission, None)
+    }
+
+    /// Runs `epoch_mechanism` and, if `intermediates` is given, fills it with the intermediate
+    /// vectors and matrices of the epoch.
+    pub fn epoch_mechanism_with_intermediates(
+        netuid: NetUid,
+        mecid: MechId,
+        rao_emission: AlphaBalance,
+        intermediates: Option<&mut EpochIntermediates>,
     ) -> EpochOutput<T> {
         // Calculate netuid storage index
         let netuid_index = Self::get_mechanism_storage_index(netuid, mecid);
 
+        // Derive the weight rows of the validators following another validator
+        Self::apply_followed_weights(netuid, mecid);
+
         // Initialize output keys (neuron hotkeys) and UIDs
         let mut terms_map: BTreeMap<T::AccountId, EpochTerms> = Keys::<T>::iter_prefix(netuid)
             .map(|(uid, hotkey)| {
@@ -740,7 +774,7 @@ impl<T: Config> Pallet<T> {
             // `commit_block` against `block_at_registration` (both block numbers).
             for (who, q) in WeightCommits::<T>::iter_prefix(netuid_index) {
                 for (_, commit_epoch, commit_block, _) in q.iter() {
-                    if !Self::is_commit_expired(netuid, *commit_epoch) {
+                    if !Self::is_commit_expired(netuid_index, *commit_epoch) {
                         if let Some(cell) = uid_of(&who).and_then(|i| commit_blocks.get_mut(i)) {
                             *cell = (*cell).min(*commit_block);
                         }
@@ -753,7 +787,7 @@ impl<T: Config> Pallet<T> {
             // `TimelockedWeightCommits` is keyed by `commit_epoch`; the value tuple
             // carries the absolute `commit_block` in field 1.
             for (commit_epoch, q) in TimelockedWeightCommits::<T>::iter_prefix(netuid_index) {
-                if Self::is_commit_expired(netuid, commit_epoch) {
+                if Self::is_commit_expired(netuid_index, commit_epoch) {
                     continue;
                 }
                 for (who, commit_block, ..) in q.iter() {
@@ -780,146 +814,32 @@ impl<T: Config> Pallet<T> {
         inplace_row_normalize_sparse(&mut weights);
         log::trace!("Weights (mask+norm): {:?}", &weights);
 
-        // ================================
-        // == Consensus, Validator Trust ==
-        // ================================
-
-        // Consensus majority ratio, e.g. 51%.
-        let kappa: I32F32 = Self::get_float_kappa(netuid);
-        // Calculate consensus as stake-weighted median of weights.
-        let consensus: Vec<I32F32> = weighted_median_col_sparse(&active_stake, &weights, n, kappa);
-        log::trace!("Consensus: {:?}", &consensus);
-
-        // Clip weights at majority consensus.
-        let clipped_weights: Vec<Vec<(u16, I32F32)>> = col_clip_sparse(&weights, &consensus);
-        log::trace!("Clipped Weights: {:?}", &clipped_weights);
-
-        // Calculate validator trust as sum of clipped weights set by validator.
-        let validator_trust: Vec<I32F32> = row_sum_sparse(&clipped_weights);
-        log::trace!("Validator Trust: {:?}", &validator_trust);
-
-        // =============================
-        // == Ranks, Trust, Incentive ==
-        // =============================
-
-        // Compute ranks: r_j = SUM(i) w_ij * s_i.
-        let mut ranks: Vec<I32F32> = matmul_sparse(&clipped_weights, &active_stake, n);
-
-        inplace_normalize(&mut ranks); // range: I32F32(0, 1)
-        let incentive: Vec<I32F32> = ranks.clone();
-        log::trace!("Incentive (=Rank): {:?}", &incentive);
-
-        // =========================
-        // == Bonds and Dividends ==
-        // =========================
-
-        // Get validator bonds penalty in [0, 1].
-        let bonds_penalty: I32F32 = Self::get_float_bonds_penalty(netuid);
-        // Calculate weights for bonds, apply bonds penalty to weights.
-        // bonds_penalty = 0: weights_for_bonds = weights.clone()
-        // bonds_penalty = 1: weights_for_bonds = clipped_weights.clone()
-        let weights_for_bonds: Vec<Vec<(u16, I32F32)>> =
-            interpolate_sparse(&weights, &clipped_weights, n, bonds_penalty);
-
-        let mut dividends: Vec<I32F32>;
-        let mut ema_bonds: Vec<Vec<(u16, I32F32)>>;
-        if Yuma3On::<T>::get(netuid) {
-            // Access network bonds.
-            let mut bonds = Self::get_bonds_sparse_fixed_proportion(netuid_index);
-            log::trace!("Bonds: {:?}", &bonds);
-
-            // Remove bonds referring to neurons that have registered since last tempo.
-            // Mask if: the last tempo block happened *before* the registration block
-            // ==> last_tempo <= registered
-            // For dynamic tempo - we pick previous-successful-epoch block: `LastMechansimStepBlock + 1`
-            let lms = LastMechansimStepBlock::<T>::get(netuid);
-            let last_tempo: u64 = if lms == 0 {
-                current_block.saturating_sub(tempo)
-            } else {
-                lms.saturating_add(1)
-            };
-            bonds = scalar_vec_mask_sparse_matrix(
-                &bonds,
-                last_tempo,
-                &block_at_registration,
-                &|last_tempo, registered| last_tempo <= registered,
-            );
-            log::trace!("Bonds: (mask) {:?}", &bonds);
-
-            // Compute the Exponential Moving Average (EMA) of bonds.
-            log::trace!("weights_for_bonds: {:?}", &weights_for_bonds);
-            ema_bonds =
-                Self::compute_bonds_sparse(netuid_index, &weights_for_bonds, &bonds, &consensus);
-            log::trace!("emaB: {:?}", &ema_bonds);
-
-            // Normalize EMA bonds.
-            let mut ema_bonds_norm = ema_bonds.clone();
-            inplace_col_normalize_sparse(&mut ema_bonds_norm, n); // sum_i b_ij = 1
-            log::trace!("emaB norm: {:?}", &ema_bonds_norm);
-
-            // # === Dividend Calculation===
-            let total_bonds_per_validator: Vec<I32F32> =
-                row_sum_sparse(&mat_vec_mul_sparse(&ema_bonds_norm, &incentive));
-            log::trace!(
-                "total_bonds_per_validator: {:?}",
-                &total_bonds_per_validator
-            );
-
-            dividends = vec_mul(&total_bonds_per_validator, &active_stake);
-            inplace_normalize(&mut dividends);
-            log::trace!("Dividends: {:?}", &dividends);
-        } else {
-            // original Yuma - liquid alpha disabled
-            // Access network bonds.
-            let mut bonds: Vec<Vec<(u16, I32F32)>> = Self::get_bonds_sparse(netuid_index);
-            log::trace!("B: {:?}", &bonds);
-
-            // Remove bonds referring to neurons that have registered since last tempo.
-            // Mask if: the last tempo block happened *before* the registration block
-            // ==> last_tempo <= registered
-            // For dynamic tempo - we pick previous-successful-epoch block: `LastMechansimStepBlock + 1`
-            let lms = LastMechansimStepBlock::<T>::get(netuid);
-            let last_tempo: u64 = if lms == 0 {
-                current_block.saturating_sub(tempo)
-            } else {
-                lms.saturating_add(1)
-            };
-            bonds = scalar_vec_mask_sparse_matrix(
-                &bonds,
-                last_tempo,
-                &block_at_registration,
-                &|last_tempo, registered| last_tempo <= registered,
-            );
-            log::trace!("B (outdatedmask): {:?}", &bonds);
-
-            // Normalize remaining bonds: sum_i b_ij = 1.
-            inplace_col_normalize_sparse(&mut bonds, n);
-            log::trace!("B (mask+norm): {:?}", &bonds);
-
-            // Compute bonds delta column normalized.
-            let mut bonds_delta: Vec<Vec<(u16, I32F32)>> =
-                row_hadamard_sparse(&weights_for_bonds, &active_stake); // ΔB = W◦S (outdated W masked)
-            log::trace!("ΔB: {:?}", &bonds_delta);
-
-            // Normalize bonds delta.
-            inplace_col_normalize_sparse(&mut bonds_delta, n); // sum_i b_ij = 1
-            log::trace!("ΔB (norm): {:?}", &bonds_delta);
-
-            // Compute the Exponential Moving Average (EMA) of bonds.
-            ema_bonds = Self::compute_ema_bonds_normal_sparse(&bonds_delta, &bonds, netuid_index);
-            // Normalize EMA bonds.
-            inplace_col_normalize_sparse(&mut ema_bonds, n); // sum_i b_ij = 1
-            log::trace!("Exponential Moving Average Bonds: {:?}", &ema_bonds);
-
-            // Compute dividends: d_i = SUM(j) b_ij * inc_j.
-            // range: I32F32(0, 1)
-            dividends = matmul_transpose_sparse(&ema_bonds, &incentive);
-            inplace_normalize(&mut dividends);
-            log::trace!("Dividends: {:?}", &dividends);
-
-            // Column max-upscale EMA bonds for storage: max_i w_ij = 1.
-            inplace_col_max_upscale_sparse(&mut ema_bonds, n);
-        }
+        // ===============
+        // == Consensus ==
+        // ===============
+
+        // Run the consensus algorithm selected for this subnet.
+        let ConsensusTerms {
+            consensus,
+            clipped_weights,
+            ranks,
+            validator_trust,
+            incentive,
+            dividends,
+            bonds: ema_bonds,
+        } = Self::run_consensus(
+            SubnetConsensusAlgorithm::<T>::get(netuid),
+            &ConsensusInputs {
+                netuid,
+                netuid_index,
+                n,
+                current_block,
+                tempo,
+                block_at_registration: &block_at_registration,
+                active_stake: &active_stake,
+                weights: &weights,
+            },
+        );
 
         // =================================
         // == Emission and Pruning scores ==
@@ -1072,6 +992,21 @@ impl<T: Config> Pallet<T> {
             }
         }
 
+        if let Some(intermediates) = intermediates {
+            *intermediates = EpochIntermediates {
+                stake,
+                active_stake,
+                weights,
+                clipped_weights,
+                consensus,
+                ranks,
+                validator_trust,
+                bonds: ema_bonds,
+                dividends,
+                incentive,
+            };
+        }
+
         EpochOutput(terms_map)
     }
 
@@ -1082,6 +1017,10 @@ impl<T: Config> Pallet<T> {
         I32F32::saturating_from_num(Self::get_kappa(netuid))
             .safe_div(I32F32::saturating_from_num(u16::MAX))
     }
+    pub fn get_float_mechanism_kappa(netuid_index: NetUidStorageIndex) -> I32F32 {
+        I32F32::saturating_from_num(Self::get_mechanism_kappa(netuid_index))
+            .safe_div(I32F32::saturating_from_num(u16::MAX))
+    }
     pub fn get_float_bonds_penalty(netuid: NetUid) -> I32F32 {
         I32F32::saturating_from_num(Self::get_bonds_penalty(netuid))
             .safe_div(I32F32::saturating_from_num(u16::MAX))
@@ -1230,11 +1169,9 @@ impl<T: Config> Pallet<T> {
         bonds: &[Vec<(u16, I32F32)>],
         netuid_index: NetUidStorageIndex,
     ) -> Vec<Vec<(u16, I32F32)>> {
-        let (netuid, _) = Self::get_netuid_and_subid(netuid_index).unwrap_or_default();
-
-        // Retrieve the bonds moving average for the given network ID and scale it down.
+        // Retrieve the bonds moving average for the given mechanism and scale it down.
         let bonds_moving_average: I64F64 =
-            I64F64::saturating_from_num(Self::get_bonds_moving_average(netuid))
+            I64F64::saturating_from_num(Self::get_mechanism_bonds_moving_average(netuid_index))
                 .safe_div(I64F64::saturating_from_num(1_000_000));
 
         // Calculate the alpha value for the EMA calculation.
@@ -1319,7 +1256,7 @@ impl<T: Config> Pallet<T> {
             mat_ema_alpha(weights, bonds, &alphas)
         } else {
             // Liquid Alpha is disabled, compute the liquid alpha value.
-            let alpha: I32F32 = Self::compute_disabled_liquid_alpha(netuid);
+            let alpha: I32F32 = Self::compute_disabled_liquid_alpha(netuid.into());
 
             // Compute the Exponential Moving Average (EMA) of bonds using the calculated alpha value.
             mat_ema(weights, bonds, alpha)
@@ -1329,7 +1266,7 @@ impl<T: Config> Pallet<T> {
     /// Compute the Exponential Moving Average (EMA) of bonds based on the Liquid Alpha setting for a sparse matrix.
     ///
     /// # Args:
-    /// * `netuid` - The network ID.
+    /// * `netuid_index` - The mechanism storage index.
     /// * `weights` - A vector of weights.
     /// * `bonds` - A vector of bonds.
     /// * `consensus` - A vector of consensus values.
@@ -1343,10 +1280,8 @@ impl<T: Config> Pallet<T> {
         bonds: &[Vec<(u16, I32F32)>],
         consensus: &[I32F32],
     ) -> Vec<Vec<(u16, I32F32)>> {
-        let (netuid, _) = Self::get_netuid_and_subid(netuid_index).unwrap_or_default();
-
         // Check if Liquid Alpha is enabled, consensus is not empty, and contains non-zero values.
-        if LiquidAlphaOn::<T>::get(netuid)
+        if Self::get_mechanism_liquid_alpha_enabled(netuid_index)
             && !consensus.is_empty()
             && consensus
                 .iter()
@@ -1354,14 +1289,14 @@ impl<T: Config> Pallet<T> {
         {
             // Liquid Alpha is enabled, compute the liquid alphas matrix.
             let alphas: Vec<Vec<I32F32>> =
-                Self::compute_liquid_alpha_values_sparse(netuid, weights, bonds, consensus);
+                Self::compute_liquid_alpha_values_sparse(netuid_index, weights, bonds, consensus);
             log::trace!("alphas: {:?}", &alphas);
 
             // Compute the Exponential Moving Average (EMA) of bonds using the provided clamped alpha values.
             mat_ema_alpha_sparse(weights, bonds, &alphas)
         } else {
             // Liquid Alpha is disabled, compute the liquid alpha value.
-            let alpha: I32F32 = Self::compute_disabled_liquid_alpha(netuid);
+            let alpha: I32F32 = Self::compute_disabled_liquid_alpha(netuid_index);
 
             // Compute the Exponential Moving Average (EMA) of bonds using the calculated alpha value.
             mat_ema_sparse(weights, bonds, alpha)
@@ -1425,7 +1360,7 @@ impl<T: Config> Pallet<T> {
     /// There is a separate alpha param for each validator-miner binding
     ///
     /// # Args:
-    /// * `netuid` - The network ID.
+    /// * `netuid_index` - The mechanism storage index.
     /// * `weights` - A vector of weights.
     /// * `bonds` - A vector of bonds.
     /// * `consensus` - A vector of consensus values.
@@ -1433,7 +1368,7 @@ impl<T: Config> Pallet<T> {
     /// # Returns:
     /// A dense matrix of alphas
     pub fn compute_liquid_alpha_values_sparse(
-        netuid: NetUid,
+        netuid_index: NetUidStorageIndex,
         weights: &[Vec<(u16, I32F32)>], // current epoch weights
         bonds: &[Vec<(u16, I32F32)>],   // previous epoch bonds
         consensus: &[I32F32],           // previous epoch consensus weights
@@ -1449,8 +1384,10 @@ impl<T: Config> Pallet<T> {
             return alphas;
         }
 
-        let alpha_sigmoid_steepness: I32F32 = Self::get_alpha_sigmoid_steepness(netuid);
-        let (alpha_low, alpha_high): (I32F32, I32F32) = Self::get_alpha_values_32(netuid);
+        let alpha_sigmoid_steepness: I32F32 =
+            Self::get_alpha_sigmoid_steepness(Self::get_netuid(netuid_index));
+        let (alpha_low, alpha_high): (I32F32, I32F32) =
+            Self::get_mechanism_alpha_values_32(netuid_index);
 
         let zero = I32F32::from_num(0.0);
 
@@ -1531,10 +1468,11 @@ impl<T: Config> Pallet<T> {
         clamp_value(alpha, alpha_low, alpha_high)
     }
 
-    pub fn compute_disabled_liquid_alpha(netuid: NetUid) -> I32F32 {
-        // Retrieve the bonds moving average for the given network ID and scale it down.
-        let bonds_moving_average: I64F64 = I64F64::from_num(Self::get_bonds_moving_average(netuid))
-            .saturating_div(I64F64::from_num(1_000_000));
+    pub fn compute_disabled_liquid_alpha(netuid_index: NetUidStorageIndex) -> I32F32 {
+        // Retrieve the bonds moving average for the given mechanism and scale it down.
+        let bonds_moving_average: I64F64 =
+            I64F64::from_num(Self::get_mechanism_bonds_moving_average(netuid_index))
+                .saturating_div(I64F64::from_num(1_000_000));
 
         // Calculate the alpha value for the EMA calculation.
         // Alpha is derived by subtracting the scaled bonds moving average from 1.
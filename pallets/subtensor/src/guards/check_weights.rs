@@ -119,7 +119,6 @@ impl<T: Config> CheckWeights<T> {
 
     fn check_reveal(
         who: &T::AccountId,
-        netuid: NetUid,
         netuid_index: NetUidStorageIndex,
         uids: &[u16],
         values: &[u16],
@@ -132,7 +131,7 @@ impl<T: Config> CheckWeights<T> {
         let commit_epoch =
             Self::find_commit_epoch(&commits, hash).ok_or(Error::<T>::NoWeightsCommitFound)?;
 
-        if Pallet::<T>::is_reveal_block_range(netuid, commit_epoch) {
+        if Pallet::<T>::is_reveal_block_range(netuid_index, commit_epoch) {
             Ok(())
         } else {
             Err(Error::<T>::RevealTooEarly)
@@ -169,7 +168,7 @@ impl<T: Config> CheckWeights<T> {
             let commit_epoch =
                 Self::find_commit_epoch(&commits, hash).ok_or(Error::<T>::NoWeightsCommitFound)?;
 
-            if !Pallet::<T>::is_reveal_block_range(netuid, commit_epoch) {
+            if !Pallet::<T>::is_reveal_block_range(netuid_index, commit_epoch) {
                 return Err(Error::<T>::RevealTooEarly);
             }
         }
@@ -187,7 +186,6 @@ impl<T: Config> CheckWeights<T> {
                 version_key,
             } => Self::check_reveal(
                 who,
-                *netuid,
                 NetUidStorageIndex::from(*netuid),
                 uids,
                 values,
@@ -203,7 +201,6 @@ impl<T: Config> CheckWeights<T> {
                 version_key,
             } => Self::check_reveal(
                 who,
-                *netuid,
                 Pallet::<T>::get_mechanism_storage_index(*netuid, *mecid),
                 uids,
                 values,
@@ -82,8 +82,10 @@ pub const ACCOUNT_FLAGS_ACCEPT_LOCKED_ALPHA: u128 = 1u128 << 0;
 #[allow(clippy::expect_used)]
 pub mod pallet {
     use crate::RateLimitKey;
+    use crate::epoch::history::EpochRecord;
     use crate::migrations;
     use crate::staking::lock::LockState;
+    use crate::staking::rebalance::RebalanceLeg;
     use crate::subnets::leasing::{LeaseId, SubnetLeaseOf};
     use frame_support::Twox64Concat;
     use frame_support::{
@@ -99,6 +101,7 @@ pub mod pallet {
     use runtime_common::prod_or_fast;
     use share_pool::SafeFloat;
     use sp_core::{ConstU32, H160, H256};
+    use sp_runtime::Perbill;
     use sp_runtime::traits::{Dispatchable, TrailingZeroInput};
     use sp_std::collections::btree_map::BTreeMap;
     use sp_std::collections::btree_set::BTreeSet;
@@ -129,6 +132,20 @@ pub mod pallet {
     pub const MIN_COMMIT_REVEAL_PEROIDS: u64 = 1;
     /// Maximum commit reveal periods
     pub const MAX_COMMIT_REVEAL_PEROIDS: u64 = 100;
+    /// Maximum number of epochs a followed weight row can lag behind the leader
+    pub const MAX_WEIGHTS_FOLLOW_LAG_EPOCHS: u16 = 16;
+    /// Maximum number of leader rows queued for a follower, `MAX_WEIGHTS_FOLLOW_LAG_EPOCHS + 1`
+    pub const MAX_FOLLOWED_WEIGHT_ROWS: u32 = 17;
+    /// Maximum number of validators following the weights of another validator on a subnet
+    pub const MAX_WEIGHTS_FOLLOWERS_PER_SUBNET: u32 = 64;
+    /// Maximum number of hotkeys a coldkey can stake through when turning cost-basis tracking on
+    pub const MAX_COST_BASIS_STAKING_HOTKEYS: u32 = 32;
+    /// Maximum number of recurring stakes a coldkey can have at once
+    pub const MAX_RECURRING_STAKES_PER_COLDKEY: u32 = 16;
+    /// Maximum number of recurring stakes due at a block, later ones move to the next block
+    pub const MAX_RECURRING_STAKES_DUE_PER_BLOCK: u32 = 64;
+    /// Maximum number of hyperparameter changes scheduled for a single block
+    pub const MAX_HYPERPARAMETER_CHANGES_DUE_PER_BLOCK: u32 = 32;
 
     #[pallet::pallet]
     #[pallet::without_storage_info]
@@ -330,17 +347,60 @@ pub mod pallet {
         Recycle,
     }
 
+    /// Enum for the consensus algorithm run by the subnet epoch
+    #[derive(
+        TypeInfo, Encode, Decode, DecodeWithMemTracking, Default, Clone, Copy, PartialEq, Eq, Debug,
+    )]
+    pub enum ConsensusAlgorithm {
+        /// Yuma consensus with bonds (Yuma3 and liquid alpha apply)
+        #[default]
+        Yuma,
+        /// Weights clipped at the stake-weighted median, without bonds
+        MedianClipped,
+        /// Stake-weighted average of weights, without clipping nor bonds
+        StakeWeighted,
+    }
+
+    /// Hyperparameters a subnet mechanism sets apart from its subnet. `None` falls back to
+    /// the subnet value.
+    #[crate::freeze_struct("7bfe1f3f15764dfb")]
+    #[derive(
+        Encode, Decode, DecodeWithMemTracking, Default, TypeInfo, Clone, PartialEq, Eq, Debug,
+    )]
+    pub struct MechanismHyperparams {
+        pub kappa: Option<u16>,
+        pub bonds_moving_average: Option<u64>,
+        pub max_weight_limit: Option<u16>,
+        pub min_allowed_weights: Option<u16>,
+        pub liquid_alpha_enabled: Option<bool>,
+        /// (alpha_low, alpha_high)
+        pub alpha_values: Option<(u16, u16)>,
+        pub reveal_period: Option<u64>,
+    }
+
+    /// A hyperparameter value announced to take effect at a later block.
+    #[crate::freeze_struct("f6d5d00b8a233c6c")]
+    #[derive(
+        Encode, Decode, DecodeWithMemTracking, TypeInfo, Clone, Copy, PartialEq, Eq, Debug,
+    )]
+    pub struct ScheduledHyperparameterChange {
+        /// The new value. Booleans are encoded as 0 or 1.
+        pub value: u64,
+        /// The block at which the value is applied.
+        pub at_block: u64,
+        /// Whether the subnet owner scheduled the change. The owner rate limit of the
+        /// hyperparameter then applies when the value is applied.
+        pub by_owner: bool,
+    }
+
     /// ============================
     /// ==== Staking + Accounts ====
     /// ============================
 
-    #[derive(
-        Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug, DecodeWithMemTracking,
-    )]
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug, DecodeWithMemTracking)]
     /// Enum for the per-coldkey root claim setting.
-    pub enum RootClaimTypeEnum {
+    pub enum RootClaimTypeEnum<AccountId> {
         /// Swap any alpha emission for TAO.
-        #[default]
         Swap,
         /// Keep all alpha emission.
         Keep,
@@ -349,6 +409,56 @@ pub mod pallet {
             /// Subnets to keep alpha emissions (swap everything else).
             subnets: BTreeSet<NetUid>,
         },
+        /// Restake alpha emission on chosen hotkeys.
+        Restake {
+            /// Hotkey to stake the alpha emission of a subnet on. Alpha of subnets without a
+            /// target is swapped for TAO and staked on root with the `NetUid::ROOT` target, or
+            /// with the hotkey that earned it when no root target is set.
+            targets: BTreeMap<NetUid, AccountId>,
+            /// Maximum drop below the current alpha price accepted when swapping for TAO.
+            max_slippage: Perbill,
+        },
+    }
+
+    impl<AccountId> Default for RootClaimTypeEnum<AccountId> {
+        fn default() -> Self {
+            Self::Swap
+        }
+    }
+
+    /// TAO cost of the alpha a coldkey holds on a subnet, with the PnL realized on it.
+    #[crate::freeze_struct("d0d660ca531a73ef")]
+    #[derive(
+        Encode, Decode, DecodeWithMemTracking, TypeInfo, Clone, PartialEq, Eq, Debug, Default,
+    )]
+    pub struct CostBasis {
+        /// TAO paid for the alpha still held.
+        pub cost: TaoBalance,
+        /// TAO received minus the cost of the alpha disposed of.
+        pub realized_pnl: i64,
+    }
+
+    /// A recurring stake of a coldkey, executed every `interval` blocks until `end_block`.
+    #[crate::freeze_struct("bd9b59ef96b088fd")]
+    #[derive(Encode, Decode, DecodeWithMemTracking, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct RecurringStake<AccountId> {
+        /// The subnet to stake into.
+        pub netuid: NetUid,
+        /// The hotkey to stake on.
+        pub hotkey: AccountId,
+        /// TAO staked at each execution.
+        pub amount_per_interval: TaoBalance,
+        /// Number of blocks between two executions.
+        pub interval: u64,
+        /// Highest price, in rao per alpha, at which an execution may stake.
+        pub price_ceiling: Option<TaoBalance>,
+        /// Last block at which the schedule may execute.
+        pub end_block: u64,
+        /// Block of the next execution.
+        pub next_block: u64,
+        /// `NetworkRegisteredAt` of the subnet when the schedule was created. The schedule ends
+        /// if the netuid is registered again.
+        pub registered_at: u64,
     }
 
     /// The Max Burn HalfLife Settable
@@ -387,7 +497,7 @@ pub mod pallet {
     /// This is the type of root claim that will be made.
     /// This is set by the user. Either swap to TAO or keep as alpha.
     #[pallet::type_value]
-    pub fn DefaultRootClaimType<T: Config>() -> RootClaimTypeEnum {
+    pub fn DefaultRootClaimType<T: Config>() -> RootClaimTypeEnum<T::AccountId> {
         RootClaimTypeEnum::default()
     }
 
@@ -2184,6 +2294,52 @@ pub mod pallet {
     pub type Yuma3On<T> =
         StorageMap<_, Blake2_128Concat, NetUid, bool, ValueQuery, DefaultYuma3<T>>;
 
+    /// --- MAP ( netuid ) --> Consensus algorithm run by the subnet epoch
+    #[pallet::storage]
+    pub type SubnetConsensusAlgorithm<T> =
+        StorageMap<_, Identity, NetUid, ConsensusAlgorithm, ValueQuery>;
+
+    /// --- DMAP ( netuid, hyperparameter ) --> change | Announced hyperparameter changes of a
+    /// subnet, at most one per hyperparameter.
+    #[pallet::storage]
+    pub type ScheduledHyperparameterChanges<T> = StorageDoubleMap<
+        _,
+        Identity,
+        NetUid,
+        Twox64Concat,
+        Hyperparameter,
+        ScheduledHyperparameterChange,
+        OptionQuery,
+    >;
+
+    /// --- MAP ( block ) --> Vec<( netuid, hyperparameter )> | Scheduled changes due at a block.
+    /// Entries replaced by a later schedule are skipped when the block is reached.
+    #[pallet::storage]
+    pub type HyperparameterChangesDue<T> = StorageMap<
+        _,
+        Twox64Concat,
+        u64,
+        BoundedVec<(NetUid, Hyperparameter), ConstU32<MAX_HYPERPARAMETER_CHANGES_DUE_PER_BLOCK>>,
+        ValueQuery,
+    >;
+
+    /// --- DMAP ( netuid_index, epoch_index ) --> epoch_record | The last `EpochHistoryLength`
+    /// epochs of a subnet mechanism.
+    #[pallet::storage]
+    pub type EpochHistory<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        NetUidStorageIndex,
+        Identity,
+        u64,
+        EpochRecord<T::AccountId>,
+        OptionQuery,
+    >;
+
+    /// --- MAP ( netuid_index ) --> Number of epochs ever recorded in EpochHistory
+    #[pallet::storage]
+    pub type EpochHistoryCount<T> = StorageMap<_, Identity, NetUidStorageIndex, u64, ValueQuery>;
+
     ///  MAP ( netuid ) --> (alpha_low, alpha_high)
     #[pallet::storage]
     pub type AlphaValues<T> =
@@ -2344,6 +2500,32 @@ pub mod pallet {
         DefaultWeights<T>,
     >;
 
+    /// --- DMAP ( netuid, follower_hotkey ) --> ( leader_hotkey, lag_epochs ) | Validators whose
+    /// weights are derived from the weights of another validator
+    #[pallet::storage]
+    pub type WeightsLeader<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        NetUid,
+        Blake2_128Concat,
+        T::AccountId,
+        (T::AccountId, u16),
+        OptionQuery,
+    >;
+
+    /// --- DMAP ( netuid_index, follower_hotkey ) --> Vec<(leader_last_update, leader_row)> |
+    /// Leader rows of the last lag_epochs + 1 epochs, oldest first
+    #[pallet::storage]
+    pub type FollowedWeightRows<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        NetUidStorageIndex,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<(u64, Vec<(u16, u16)>), ConstU32<MAX_FOLLOWED_WEIGHT_ROWS>>,
+        ValueQuery,
+    >;
+
     /// --- DMAP ( netuid, uid ) --> bonds
     #[pallet::storage]
     pub type Bonds<T: Config> = StorageDoubleMap<
@@ -2569,10 +2751,46 @@ pub mod pallet {
         _,
         Blake2_128Concat,
         T::AccountId,
-        RootClaimTypeEnum,
+        RootClaimTypeEnum<T::AccountId>,
         ValueQuery,
         DefaultRootClaimType<T>,
     >;
+    #[pallet::storage] // --- MAP ( cold ) --> block | Coldkeys tracking their cost basis, since a block
+    pub type CostBasisTrackingSince<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, OptionQuery>;
+    #[pallet::storage] // --- DMAP ( netuid, cold ) --> cost_basis | Cost basis of tracking coldkeys
+    pub type StakeCostBasis<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        NetUid,
+        Blake2_128Concat,
+        T::AccountId,
+        CostBasis,
+        ValueQuery,
+    >;
+    #[pallet::storage] // --- DMAP ( cold, id ) --> recurring_stake | Recurring stakes of a coldkey
+    pub type RecurringStakes<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Identity,
+        u64,
+        RecurringStake<T::AccountId>,
+        OptionQuery,
+    >;
+    #[pallet::storage] // --- ITEM ( id ) | Id of the next recurring stake
+    pub type NextRecurringStakeId<T: Config> = StorageValue<_, u64, ValueQuery>;
+    #[pallet::storage] // --- MAP ( block ) --> Vec<( cold, id )> | Recurring stakes due at a block.
+    /// Entries of cancelled or rescheduled stakes are skipped when the block is processed.
+    pub type RecurringStakesDue<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64,
+        BoundedVec<(T::AccountId, u64), ConstU32<MAX_RECURRING_STAKES_DUE_PER_BLOCK>>,
+        ValueQuery,
+    >;
+    #[pallet::storage] // --- ITEM ( block ) | Next block whose due recurring stakes are executed
+    pub type RecurringStakeCursor<T: Config> = StorageValue<_, u64, ValueQuery>;
     #[pallet::storage] // --- MAP ( u64 ) --> coldkey | Maps coldkeys that have stake to an index
     pub type StakingColdkeysByIndex<T: Config> =
         StorageMap<_, Identity, u64, T::AccountId, OptionQuery>;
@@ -2682,6 +2900,11 @@ pub mod pallet {
     pub type MechanismEmissionSplit<T: Config> =
         StorageMap<_, Twox64Concat, NetUid, Vec<u16>, OptionQuery>;
 
+    /// --- MAP ( netuid_index ) --> Hyperparameters overridden by a subnet mechanism
+    #[pallet::storage]
+    pub type MechanismHyperparamOverrides<T: Config> =
+        StorageMap<_, Twox64Concat, NetUidStorageIndex, MechanismHyperparams, ValueQuery>;
+
     /// --- MAP ( netuid ) --> BurnHalfLife (blocks)
     #[pallet::storage]
     pub type BurnHalfLife<T> =
@@ -2912,6 +3135,13 @@ impl<T: Config + pallet_balances::Config<Balance = TaoBalance>>
 
         Self::increase_stake_for_hotkey_and_coldkey_on_subnet(hotkey, coldkey, netuid, alpha);
 
+        // Alpha coming back from a liquidity position enters at its market value
+        Self::record_cost_basis_acquisition(
+            coldkey,
+            netuid,
+            Self::cost_basis_market_value(netuid, alpha),
+        );
+
         Ok(())
     }
 
@@ -2931,6 +3161,9 @@ impl<T: Config + pallet_balances::Config<Balance = TaoBalance>>
             Error::<T>::InsufficientBalance
         );
 
+        // Locked alpha cannot leave the stake, not even into a liquidity position
+        Self::ensure_available_to_unstake(coldkey, netuid, alpha)?;
+
         // Decrese alpha out counter
         SubnetAlphaOut::<T>::mutate(netuid, |total| {
             *total = total.saturating_sub(alpha);
@@ -2938,6 +3171,45 @@ impl<T: Config + pallet_balances::Config<Balance = TaoBalance>>
 
         Self::decrease_stake_for_hotkey_and_coldkey_on_subnet(hotkey, coldkey, netuid, alpha);
 
+        // Alpha leaving the stake for a liquidity position is disposed of at its market value
+        Self::record_cost_basis_disposal(
+            coldkey,
+            netuid,
+            alpha,
+            Self::cost_basis_market_value(netuid, alpha),
+        );
+
+        Ok(())
+    }
+
+    fn deposit_tao_to_subnet(
+        coldkey: &T::AccountId,
+        netuid: NetUid,
+        tao: TaoBalance,
+    ) -> Result<(), DispatchError> {
+        let transferred = Self::transfer_tao_to_subnet(netuid, coldkey, tao)?;
+        ensure!(transferred == tao, Error::<T>::InsufficientBalance);
+
+        // Increase total TAO reserves
+        TotalStake::<T>::mutate(|total| *total = total.saturating_add(tao));
+
+        Ok(())
+    }
+
+    fn withdraw_tao_from_subnet(
+        coldkey: &T::AccountId,
+        netuid: NetUid,
+        tao: TaoBalance,
+    ) -> Result<(), DispatchError> {
+        if tao.is_zero() {
+            return Ok(());
+        }
+
+        Self::transfer_tao_from_subnet(netuid, coldkey, tao)?;
+
+        // Reduce total TAO reserves
+        TotalStake::<T>::mutate(|total| *total = total.saturating_sub(tao));
+
         Ok(())
     }
 }
@@ -287,5 +287,8 @@ mod config {
         /// `PendingEpochAt`.
         #[pallet::constant]
         type InitialMaxEpochsPerBlock: Get<u8>;
+        /// Number of epochs kept in the epoch history of each subnet mechanism.
+        #[pallet::constant]
+        type EpochHistoryLength: Get<u32>;
     }
 }
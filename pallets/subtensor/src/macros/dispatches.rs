@@ -2186,6 +2186,10 @@ mod dispatches {
         /// * 'origin': (<T as frame_system::Config>Origin):
         /// 	- The signature of the caller's coldkey.
         ///
+        /// * 'new_root_claim_type' (RootClaimTypeEnum):
+        /// 	- How claimed alpha dividends are handled. `Restake` targets must be existing
+        /// 	  hotkeys on existing subnets.
+        ///
         /// # Event:
         /// * RootClaimTypeSet;
         /// 	- On the successfully setting the root claim type for the coldkey.
@@ -2194,12 +2198,28 @@ mod dispatches {
         #[pallet::weight(<T as crate::pallet::Config>::WeightInfo::set_root_claim_type())]
         pub fn set_root_claim_type(
             origin: OriginFor<T>,
-            new_root_claim_type: RootClaimTypeEnum,
+            new_root_claim_type: RootClaimTypeEnum<T::AccountId>,
         ) -> DispatchResult {
             let coldkey: T::AccountId = ensure_signed(origin)?;
 
-            if let RootClaimTypeEnum::KeepSubnets { subnets } = &new_root_claim_type {
-                ensure!(!subnets.is_empty(), Error::<T>::InvalidSubnetNumber);
+            match &new_root_claim_type {
+                RootClaimTypeEnum::KeepSubnets { subnets } => {
+                    ensure!(!subnets.is_empty(), Error::<T>::InvalidSubnetNumber);
+                }
+                RootClaimTypeEnum::Restake { targets, .. } => {
+                    ensure!(!targets.is_empty(), Error::<T>::InvalidSubnetNumber);
+                    for (netuid, target) in targets.iter() {
+                        ensure!(
+                            netuid.is_root() || Self::if_subnet_exist(*netuid),
+                            Error::<T>::SubnetNotExists
+                        );
+                        ensure!(
+                            Self::hotkey_account_exists(target),
+                            Error::<T>::HotKeyAccountNotExists
+                        );
+                    }
+                }
+                _ => {}
             }
 
             Self::maybe_add_coldkey_index(&coldkey);
@@ -2643,5 +2663,217 @@ mod dispatches {
             Self::deposit_event(Event::RejectLockedAlphaUpdated { coldkey, enabled });
             Ok(())
         }
+
+        /// Transfers locked stake, with the proportional share of its conviction, to another
+        /// coldkey on the same subnet and lock hotkey.
+        ///
+        /// # Arguments:
+        /// * `origin` - The origin of the transaction, which must be signed by the coldkey that owns the lock.
+        /// * `destination_coldkey` - The coldkey receiving the lock.
+        /// * `netuid` - The subnet on which the lock exists.
+        /// * `amount` - The amount of locked alpha to transfer.
+        /// # Errors:
+        /// * `Error::<T>::NoExistingLock` - If no lock exists for the given coldkey and subnet.
+        /// * `Error::<T>::InsufficientLockedAlpha` - If the lock is lower than `amount`.
+        /// * `Error::<T>::LockHotkeyMismatch` - If the destination coldkey is locked to another hotkey.
+        /// * `Error::<T>::AccountRejectsLockedAlpha` - If the destination coldkey rejects locked alpha.
+        #[pallet::call_index(143)]
+        #[pallet::weight(<T as crate::pallet::Config>::WeightInfo::transfer_locked_stake())]
+        pub fn transfer_locked_stake(
+            origin: OriginFor<T>,
+            destination_coldkey: T::AccountId,
+            netuid: NetUid,
+            amount: AlphaBalance,
+        ) -> DispatchResult {
+            let coldkey = ensure_signed(origin)?;
+            Self::do_transfer_locked_stake(&coldkey, &destination_coldkey, netuid, amount)
+        }
+
+        /// Derives the weights of the calling validator from the weights of another validator.
+        ///
+        /// At each epoch, the weight row of the caller is replaced by the row the leader had
+        /// `lag_epochs` epochs before. Setting weights again stops following.
+        ///
+        /// # Arguments:
+        /// * `origin` - The origin of the transaction, which must be signed by the following hotkey.
+        /// * `netuid` - The subnet on which the weights are followed.
+        /// * `leader` - The hotkey whose weights are followed.
+        /// * `lag_epochs` - Number of epochs the followed weights lag behind the leader.
+        /// # Errors:
+        /// * `Error::<T>::CannotFollowOwnWeights` - If the leader is the calling hotkey.
+        /// * `Error::<T>::WeightsFollowLagTooHigh` - If `lag_epochs` is above the maximum.
+        /// * `Error::<T>::WeightsFollowLagBelowRevealPeriod` - If commit-reveal is enabled and
+        ///   `lag_epochs` is below the reveal period of a mechanism.
+        /// * `Error::<T>::HotKeyNotRegisteredInSubNet` - If the caller or the leader is not registered.
+        /// * `Error::<T>::NotEnoughStakeToSetWeights` - If the caller cannot set weights.
+        /// * `Error::<T>::WeightsLeaderIsFollowing` - If the leader follows another validator.
+        /// * `Error::<T>::TooManyWeightsFollowers` - If the subnet has the maximum number of
+        ///   followers.
+        #[pallet::call_index(144)]
+        #[pallet::weight(<T as crate::pallet::Config>::WeightInfo::follow_weights())]
+        pub fn follow_weights(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            leader: T::AccountId,
+            lag_epochs: u16,
+        ) -> DispatchResult {
+            Self::do_follow_weights(origin, netuid, leader, lag_epochs)
+        }
+
+        /// Schedules a recurring stake from the calling coldkey, executed with the idle weight of
+        /// the blocks every `interval` blocks until `end_block`. The first execution is at the
+        /// next block.
+        ///
+        /// Each execution adds `amount_per_interval` TAO of stake like `add_stake_limit` without
+        /// partial fills. An execution whose price is above `price_ceiling` or whose balance check
+        /// fails is skipped with a `RecurringStakeSkipped` event.
+        ///
+        /// # Arguments:
+        /// * `origin` - The origin of the transaction, which must be signed by the coldkey.
+        /// * `hotkey` - The hotkey to stake on.
+        /// * `netuid` - The subnet to stake into.
+        /// * `amount_per_interval` - TAO staked at each execution.
+        /// * `interval` - Number of blocks between two executions.
+        /// * `price_ceiling` - Highest price, in rao per alpha, to stake at. `None` for no limit.
+        /// * `end_block` - Last block at which the schedule may execute.
+        /// # Errors:
+        /// * `Error::<T>::SubnetNotExists` - If the subnet does not exist.
+        /// * `Error::<T>::HotKeyAccountNotExists` - If the hotkey is not registered.
+        /// * `Error::<T>::AmountTooLow` - If the amount is below the minimum stake.
+        /// * `Error::<T>::InvalidRecurringStakeInterval` - If the interval is zero.
+        /// * `Error::<T>::ScheduledBlockInPast` - If `end_block` is not in the future.
+        /// * `Error::<T>::TooManyRecurringStakes` - If the coldkey has too many recurring stakes.
+        #[pallet::call_index(145)]
+        #[pallet::weight(<T as crate::pallet::Config>::WeightInfo::schedule_recurring_stake())]
+        pub fn schedule_recurring_stake(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: NetUid,
+            amount_per_interval: TaoBalance,
+            interval: u64,
+            price_ceiling: Option<TaoBalance>,
+            end_block: u64,
+        ) -> DispatchResult {
+            let coldkey = ensure_signed(origin)?;
+            Self::do_schedule_recurring_stake(
+                coldkey,
+                hotkey,
+                netuid,
+                amount_per_interval,
+                interval,
+                price_ceiling,
+                end_block,
+            )
+        }
+
+        /// Cancels a recurring stake of the calling coldkey.
+        ///
+        /// # Arguments:
+        /// * `origin` - The origin of the transaction, which must be signed by the coldkey.
+        /// * `id` - Id of the recurring stake.
+        /// # Errors:
+        /// * `Error::<T>::RecurringStakeNotFound` - If the coldkey has no recurring stake with this id.
+        #[pallet::call_index(146)]
+        #[pallet::weight(<T as crate::pallet::Config>::WeightInfo::cancel_recurring_stake())]
+        pub fn cancel_recurring_stake(origin: OriginFor<T>, id: u64) -> DispatchResult {
+            let coldkey = ensure_signed(origin)?;
+            Self::do_cancel_recurring_stake(coldkey, id)
+        }
+
+        /// Rebalances the stake of the calling coldkey over the given positions, each to its
+        /// weight of their total value at the current prices.
+        ///
+        /// Alpha is moved between hotkeys of the same subnet without a swap and only the net
+        /// difference of each subnet is sold or bought. Sells run before buys, and buys are paid
+        /// from the sells only. A position with a zero weight is sold entirely.
+        ///
+        /// # Arguments:
+        /// * `origin` - The origin of the transaction, which must be signed by the coldkey.
+        /// * `targets` - The positions as `(netuid, hotkey, weight)`.
+        /// * `max_slippage` - Maximum price move of any swap from the price before the rebalance.
+        /// # Errors:
+        /// * `Error::<T>::InvalidRebalanceTargets` - If the targets are empty, too many, repeat a
+        ///   position or add up to more than 100%.
+        /// * `Error::<T>::SubnetNotExists` - If a subnet does not exist.
+        /// * `Error::<T>::HotKeyAccountNotExists` - If a hotkey is not registered.
+        /// * `Error::<T>::SlippageTooHigh` - If any swap would go past `max_slippage`.
+        #[pallet::call_index(147)]
+        #[pallet::weight(<T as crate::pallet::Config>::WeightInfo::rebalance_portfolio(targets.len() as u32))]
+        pub fn rebalance_portfolio(
+            origin: OriginFor<T>,
+            targets: Vec<(NetUid, T::AccountId, Perbill)>,
+            max_slippage: Perbill,
+        ) -> DispatchResult {
+            let coldkey = ensure_signed(origin)?;
+            Self::do_rebalance_portfolio(coldkey, targets, max_slippage)
+        }
+
+        /// Turns cost-basis tracking on or off for the calling coldkey.
+        ///
+        /// While on, the TAO paid for the alpha of the coldkey on each subnet and the PnL realized
+        /// when it is sold are recorded. Alpha already held enters at its current market value and
+        /// emissions enter at zero cost. Turning it off clears the records.
+        ///
+        /// # Arguments:
+        /// * `origin` - The origin of the transaction, which must be signed by the coldkey.
+        /// * `enabled` - Whether to track the cost basis.
+        ///
+        /// # Errors:
+        /// * `Error::<T>::TooManyStakingHotkeys` - If tracking is turned on for a coldkey staking
+        ///   through more than `MAX_COST_BASIS_STAKING_HOTKEYS` hotkeys.
+        #[pallet::call_index(148)]
+        #[pallet::weight(<T as crate::pallet::Config>::WeightInfo::set_cost_basis_tracking(
+            u32::from(TotalNetworks::<T>::get()),
+            MAX_COST_BASIS_STAKING_HOTKEYS,
+        ))]
+        pub fn set_cost_basis_tracking(
+            origin: OriginFor<T>,
+            enabled: bool,
+        ) -> DispatchResultWithPostInfo {
+            let coldkey = ensure_signed(origin)?;
+            Self::do_set_cost_basis_tracking(coldkey, enabled)
+        }
+
+        /// Swaps stake like `swap_stake_limit`, protected by the minimum amount of destination
+        /// alpha received instead of a limit price.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the transaction, which must be signed by the coldkey that owns the `hotkey`.
+        /// * `hotkey` - The hotkey whose stake is being swapped.
+        /// * `origin_netuid` - The network/subnet ID from which stake is removed.
+        /// * `destination_netuid` - The network/subnet ID to which stake is added.
+        /// * `alpha_amount` - The amount of stake to swap.
+        /// * `min_alpha_out` - The minimum amount of destination alpha received for the whole `alpha_amount`,
+        ///   fees and price impact of both swaps included.
+        /// * `allow_partial` - Allows partial execution of the amount at an average rate of at least
+        ///   `min_alpha_out / alpha_amount`. If set to false, this becomes fill or kill type or order.
+        ///
+        /// # Errors
+        /// Returns the errors of `swap_stake_limit`, and an error if `allow_partial` is false and
+        /// less than `min_alpha_out` would be received.
+        ///
+        /// # Events
+        /// May emit a `StakeSwapped` event on success.
+        #[pallet::call_index(149)]
+        #[pallet::weight(<T as crate::pallet::Config>::WeightInfo::swap_stake_min_out())]
+        pub fn swap_stake_min_out(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            origin_netuid: NetUid,
+            destination_netuid: NetUid,
+            alpha_amount: AlphaBalance,
+            min_alpha_out: AlphaBalance,
+            allow_partial: bool,
+        ) -> DispatchResult {
+            Self::do_swap_stake_min_out(
+                origin,
+                hotkey,
+                origin_netuid,
+                destination_netuid,
+                alpha_amount,
+                min_alpha_out,
+                allow_partial,
+            )
+        }
     }
 }
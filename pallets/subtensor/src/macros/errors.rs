@@ -319,5 +319,39 @@ mod errors {
         DynamicTempoBlockedByCommitReveal,
         /// The destination coldkey rejects incoming locked alpha.
         AccountRejectsLockedAlpha,
+        /// A lock cannot be transferred to the coldkey that holds it.
+        LockTransferToSameColdkey,
+        /// The lock does not cover the amount to transfer.
+        InsufficientLockedAlpha,
+        /// A validator cannot follow its own weights.
+        CannotFollowOwnWeights,
+        /// The followed validator follows the weights of another validator.
+        WeightsLeaderIsFollowing,
+        /// The lag of followed weights is above the maximum.
+        WeightsFollowLagTooHigh,
+        /// The hyperparameter cannot be changed through a schedule.
+        HyperparameterNotSchedulable,
+        /// The value is out of range for the hyperparameter.
+        HyperparameterValueOutOfRange,
+        /// A hyperparameter change or a recurring stake must be scheduled for a future block.
+        ScheduledBlockInPast,
+        /// The interval of a recurring stake must be at least one block.
+        InvalidRecurringStakeInterval,
+        /// The coldkey has reached the maximum number of recurring stakes.
+        TooManyRecurringStakes,
+        /// The coldkey has no recurring stake with this id.
+        RecurringStakeNotFound,
+        /// Rebalance targets are empty, too many, repeat a position or add up to more than 100%.
+        InvalidRebalanceTargets,
+        /// A hyperparameter change must be scheduled at least one tempo ahead.
+        HyperparameterChangeNoticeTooShort,
+        /// The lag of followed weights is below the reveal period of a subnet mechanism.
+        WeightsFollowLagBelowRevealPeriod,
+        /// The subnet has reached the maximum number of validators following weights.
+        TooManyWeightsFollowers,
+        /// The coldkey stakes through too many hotkeys to turn cost-basis tracking on.
+        TooManyStakingHotkeys,
+        /// The block already has the maximum number of hyperparameter changes scheduled.
+        TooManyHyperparameterChangesDue,
     }
 }
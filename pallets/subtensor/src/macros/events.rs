@@ -480,7 +480,7 @@ mod events {
             coldkey: T::AccountId,
 
             /// Claim type
-            root_claim_type: RootClaimTypeEnum,
+            root_claim_type: RootClaimTypeEnum<T::AccountId>,
         },
 
         /// Voting power tracking has been enabled for a subnet.
@@ -614,6 +614,22 @@ mod events {
             netuid: NetUid,
         },
 
+        /// Locked stake and its share of conviction have been transferred to another coldkey.
+        LockTransferred {
+            /// The coldkey that transferred the lock.
+            origin_coldkey: T::AccountId,
+            /// The coldkey that received the lock.
+            destination_coldkey: T::AccountId,
+            /// The hotkey the lock is for.
+            hotkey: T::AccountId,
+            /// The subnet the lock is on.
+            netuid: NetUid,
+            /// The locked alpha amount transferred.
+            amount: AlphaBalance,
+            /// The conviction transferred along with the locked alpha.
+            conviction: U64F64,
+        },
+
         /// Activity-cutoff factor (per-mille) set on a subnet by its owner.
         ActivityCutoffFactorMilliSet {
             /// The subnet identifier.
@@ -677,5 +693,134 @@ mod events {
             /// Whether this coldkey rejects incoming locked alpha.
             enabled: bool,
         },
+
+        /// A validator started following the weights of another validator.
+        WeightsFollowed {
+            /// The subnet on which the weights are followed.
+            netuid: NetUid,
+            /// The following hotkey.
+            hotkey: T::AccountId,
+            /// The hotkey whose weights are followed.
+            leader: T::AccountId,
+            /// Number of epochs the followed weights lag behind the leader.
+            lag_epochs: u16,
+        },
+
+        /// A validator stopped following the weights of another validator.
+        WeightsUnfollowed {
+            /// The subnet on which the weights were followed.
+            netuid: NetUid,
+            /// The hotkey that stopped following.
+            hotkey: T::AccountId,
+        },
+
+        /// A hyperparameter change was announced for a later block.
+        HyperparameterChangeScheduled {
+            /// The subnet whose hyperparameter changes.
+            netuid: NetUid,
+            /// The hyperparameter that changes.
+            hyperparameter: Hyperparameter,
+            /// The new value.
+            value: u64,
+            /// The block at which the value is applied.
+            at_block: u64,
+        },
+
+        /// A scheduled hyperparameter change was applied.
+        ScheduledHyperparameterChangeApplied {
+            /// The subnet whose hyperparameter changed.
+            netuid: NetUid,
+            /// The hyperparameter that changed.
+            hyperparameter: Hyperparameter,
+            /// The new value.
+            value: u64,
+        },
+
+        /// A scheduled hyperparameter change could not be applied and was dropped.
+        ScheduledHyperparameterChangeFailed {
+            /// The subnet of the dropped change.
+            netuid: NetUid,
+            /// The hyperparameter of the dropped change.
+            hyperparameter: Hyperparameter,
+            /// The error returned when applying the value.
+            error: sp_runtime::DispatchError,
+        },
+
+        /// A coldkey scheduled a recurring stake.
+        RecurringStakeScheduled {
+            /// The coldkey that stakes.
+            coldkey: T::AccountId,
+            /// Id of the recurring stake.
+            id: u64,
+            /// The subnet staked into.
+            netuid: NetUid,
+            /// The hotkey staked on.
+            hotkey: T::AccountId,
+            /// TAO staked at each execution.
+            amount_per_interval: TaoBalance,
+            /// Number of blocks between two executions.
+            interval: u64,
+            /// Last block at which the schedule may execute.
+            end_block: u64,
+        },
+
+        /// A recurring stake was executed.
+        RecurringStakeExecuted {
+            /// The coldkey that staked.
+            coldkey: T::AccountId,
+            /// Id of the recurring stake.
+            id: u64,
+            /// The subnet staked into.
+            netuid: NetUid,
+            /// The hotkey staked on.
+            hotkey: T::AccountId,
+            /// TAO staked.
+            tao_staked: TaoBalance,
+            /// Alpha received.
+            alpha_staked: AlphaBalance,
+        },
+
+        /// An execution of a recurring stake was skipped. The schedule keeps running.
+        RecurringStakeSkipped {
+            /// The coldkey of the recurring stake.
+            coldkey: T::AccountId,
+            /// Id of the recurring stake.
+            id: u64,
+            /// Why the stake could not be added, e.g. the price is above the ceiling or the
+            /// balance is too low.
+            reason: sp_runtime::DispatchError,
+        },
+
+        /// A recurring stake reached its end block or its subnet was dissolved.
+        RecurringStakeEnded {
+            /// The coldkey of the recurring stake.
+            coldkey: T::AccountId,
+            /// Id of the recurring stake.
+            id: u64,
+        },
+
+        /// A coldkey cancelled a recurring stake.
+        RecurringStakeCancelled {
+            /// The coldkey of the recurring stake.
+            coldkey: T::AccountId,
+            /// Id of the recurring stake.
+            id: u64,
+        },
+
+        /// A coldkey rebalanced its stake.
+        PortfolioRebalanced {
+            /// The coldkey that rebalanced.
+            coldkey: T::AccountId,
+            /// The moves, sells and buys executed, in order.
+            legs: Vec<RebalanceLeg<T::AccountId>>,
+        },
+
+        /// A coldkey turned cost-basis tracking on or off.
+        CostBasisTrackingSet {
+            /// The coldkey.
+            coldkey: T::AccountId,
+            /// Whether its cost basis is tracked.
+            enabled: bool,
+        },
     }
 }
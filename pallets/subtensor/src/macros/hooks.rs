@@ -16,6 +16,14 @@ mod hooks {
         // 		- The number of the block we are initializing.
         fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
             let hotkey_swap_clean_up_weight = Self::clean_up_hotkey_swap_records(block_number);
+            // Each epoch of each mechanism applies the followed weight rows of the subnet.
+            let followed_weights_weight = T::WeightInfo::apply_followed_weights()
+                .saturating_mul(u64::from(Self::get_max_epochs_per_block()))
+                .saturating_mul(u64::from(u8::from(MaxMechanismCount::<T>::get())))
+                .saturating_add(T::DbWeight::get().reads(2_u64));
+            // Read before the block step takes the changes due at this block.
+            let hyperparameter_changes_weight =
+                Self::scheduled_hyperparameter_changes_weight(Self::get_current_block_as_u64());
 
             let block_step_result = Self::block_step();
             match block_step_result {
@@ -26,6 +34,8 @@ mod hooks {
                         .saturating_add(T::DbWeight::get().reads(8304_u64))
                         .saturating_add(T::DbWeight::get().writes(110_u64))
                         .saturating_add(hotkey_swap_clean_up_weight)
+                        .saturating_add(followed_weights_weight)
+                        .saturating_add(hyperparameter_changes_weight)
                 }
                 Err(e) => {
                     // --- If the block step was unsuccessful, return the weight anyway.
@@ -34,10 +44,21 @@ mod hooks {
                         .saturating_add(T::DbWeight::get().reads(8304_u64))
                         .saturating_add(T::DbWeight::get().writes(110_u64))
                         .saturating_add(hotkey_swap_clean_up_weight)
+                        .saturating_add(followed_weights_weight)
+                        .saturating_add(hyperparameter_changes_weight)
                 }
             }
         }
 
+        // ---- Called with the weight left over at the end of the block. Executes recurring stakes.
+        //
+        // # Args:
+        // 	* 'remaining_weight': (Weight):
+        // 		- The weight available to the hook.
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::execute_due_recurring_stakes(Self::get_current_block_as_u64(), remaining_weight)
+        }
+
         fn on_runtime_upgrade() -> frame_support::weights::Weight {
             // --- Migrate storage
             let mut weight = frame_support::weights::Weight::from_parts(0, 0);
@@ -0,0 +1,219 @@
+use super::*;
+extern crate alloc;
+use codec::Compact;
+use frame_support::pallet_prelude::{Decode, Encode};
+use safe_math::*;
+use sp_std::collections::btree_set::BTreeSet;
+use substrate_fixed::types::U96F32;
+use subtensor_macros::freeze_struct;
+use subtensor_runtime_common::{AlphaBalance, NetUid, NetUidStorageIndex, TaoBalance};
+
+/// A hotkey of the childkey graph with the stake it holds, lends to its children and inherits
+/// from its parents.
+///
+/// Alpha stake is on the subnet, TAO stake is on root. The effective stake of the hotkey is its
+/// own stake minus the stake lent to children plus the stake inherited from parents.
+#[freeze_struct("a750b232d45252f5")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct ChildkeyNode<AccountId: TypeInfo + Encode + Decode> {
+    pub hotkey: AccountId,
+    pub own_alpha: Compact<AlphaBalance>,
+    pub alpha_to_children: Compact<AlphaBalance>,
+    pub alpha_from_parents: Compact<AlphaBalance>,
+    pub own_tao: Compact<TaoBalance>,
+    pub tao_to_children: Compact<TaoBalance>,
+    pub tao_from_parents: Compact<TaoBalance>,
+    /// Childkey take, out of `u16::MAX`.
+    pub childkey_take: Compact<u16>,
+    /// Dividends of the hotkey in the last epoch, before they are split with its parents.
+    pub last_epoch_dividends: Compact<AlphaBalance>,
+    /// Part of `last_epoch_dividends` the hotkey keeps, including its childkey take.
+    pub retained_dividends: Compact<AlphaBalance>,
+}
+
+/// A parent-child relation of the childkey graph, with the stake lent along it and the
+/// dividends of the child for the last epoch flowing back to the parent.
+#[freeze_struct("f9ae208c7cc5a415")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct ChildkeyEdge<AccountId: TypeInfo + Encode + Decode> {
+    pub parent: AccountId,
+    pub child: AccountId,
+    /// Proportion of the parent stake lent to the child, out of `u64::MAX`.
+    pub proportion: Compact<u64>,
+    pub alpha: Compact<AlphaBalance>,
+    pub tao: Compact<TaoBalance>,
+    /// Dividends paid to the parent, net of the childkey take and burn.
+    pub dividends: Compact<AlphaBalance>,
+    /// Childkey take kept by the child out of the share of the parent.
+    pub child_take: Compact<AlphaBalance>,
+    /// Alpha burned out of the share of the parent.
+    pub burned: Compact<AlphaBalance>,
+}
+
+/// Children set by a parent that are still in their cooldown.
+#[freeze_struct("e781d848fa32886d")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct PendingChildkeyChange<AccountId: TypeInfo + Encode + Decode> {
+    pub parent: AccountId,
+    /// The new children as `(proportion, child)`. Empty when the children are being revoked.
+    pub children: Vec<(Compact<u64>, AccountId)>,
+    /// Block from which the new children apply.
+    pub activation_block: Compact<u64>,
+}
+
+/// The childkey graph of a subnet.
+///
+/// Dividend flows are projected from the dividends of the last epoch and the current stake, so
+/// they match what was paid only if the stake has not moved since.
+#[freeze_struct("c437a9973d627ed1")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct ChildkeyGraph<AccountId: TypeInfo + Encode + Decode> {
+    pub netuid: Compact<NetUid>,
+    pub nodes: Vec<ChildkeyNode<AccountId>>,
+    pub edges: Vec<ChildkeyEdge<AccountId>>,
+    pub pending: Vec<PendingChildkeyChange<AccountId>>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Childkey graph of `netuid`, made of the hotkeys registered on the subnet that have a
+    /// parent or a child, their parents and children, and the parents with pending children.
+    pub fn get_childkey_graph(netuid: NetUid) -> Option<ChildkeyGraph<T::AccountId>> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+
+        let mut hotkeys: BTreeSet<T::AccountId> = BTreeSet::new();
+        for hotkey in Keys::<T>::iter_prefix_values(netuid) {
+            let relatives: Vec<T::AccountId> = Self::get_children(&hotkey, netuid)
+                .into_iter()
+                .chain(Self::get_parents(&hotkey, netuid))
+                .map(|(_, relative)| relative)
+                .collect();
+            if !relatives.is_empty() {
+                hotkeys.insert(hotkey);
+                hotkeys.extend(relatives);
+            }
+        }
+
+        let mut pending = Vec::new();
+        for (parent, (children, activation_block)) in PendingChildKeys::<T>::iter_prefix(netuid) {
+            hotkeys.extend(children.iter().map(|(_, child)| child.clone()));
+            hotkeys.insert(parent.clone());
+            pending.push(PendingChildkeyChange {
+                parent,
+                children: children
+                    .into_iter()
+                    .map(|(proportion, child)| (proportion.into(), child))
+                    .collect(),
+                activation_block: activation_block.into(),
+            });
+        }
+
+        let mut nodes = Vec::with_capacity(hotkeys.len());
+        let mut edges = Vec::new();
+        for hotkey in hotkeys.iter() {
+            let own_alpha = Self::get_stake_for_hotkey_on_subnet(hotkey, netuid);
+            let own_tao = Self::get_stake_for_hotkey_on_subnet(hotkey, NetUid::ROOT);
+
+            let mut alpha_to_children = AlphaBalance::ZERO;
+            let mut tao_to_children = TaoBalance::ZERO;
+            for (proportion, _) in Self::get_children(hotkey, netuid) {
+                alpha_to_children = alpha_to_children
+                    .saturating_add(Self::childkey_share(own_alpha.into(), proportion).into());
+                tao_to_children = tao_to_children
+                    .saturating_add(Self::childkey_share(own_tao.into(), proportion).into());
+            }
+
+            let last_epoch_dividends = Self::get_last_epoch_dividends(hotkey, netuid);
+            let (parent_splits, retained_dividends) =
+                Self::split_parent_child_dividends(hotkey, netuid, last_epoch_dividends);
+
+            let mut alpha_from_parents = AlphaBalance::ZERO;
+            let mut tao_from_parents = TaoBalance::ZERO;
+            for (proportion, parent) in Self::get_parents(hotkey, netuid) {
+                let alpha: AlphaBalance = Self::childkey_share(
+                    Self::get_stake_for_hotkey_on_subnet(&parent, netuid).into(),
+                    proportion,
+                )
+                .into();
+                let tao: TaoBalance = Self::childkey_share(
+                    Self::get_stake_for_hotkey_on_subnet(&parent, NetUid::ROOT).into(),
+                    proportion,
+                )
+                .into();
+                alpha_from_parents = alpha_from_parents.saturating_add(alpha);
+                tao_from_parents = tao_from_parents.saturating_add(tao);
+
+                let (dividends, child_take, burned) = parent_splits
+                    .iter()
+                    .find(|(split_parent, ..)| *split_parent == parent)
+                    .map(|(_, dividends, child_take, burned)| (*dividends, *child_take, *burned))
+                    .unwrap_or_default();
+                edges.push(ChildkeyEdge {
+                    parent,
+                    child: hotkey.clone(),
+                    proportion: proportion.into(),
+                    alpha: alpha.into(),
+                    tao: tao.into(),
+                    dividends: dividends.into(),
+                    child_take: child_take.into(),
+                    burned: burned.into(),
+                });
+            }
+
+            nodes.push(ChildkeyNode {
+                hotkey: hotkey.clone(),
+                own_alpha: own_alpha.into(),
+                alpha_to_children: alpha_to_children.into(),
+                alpha_from_parents: alpha_from_parents.into(),
+                own_tao: TaoBalance::from(u64::from(own_tao)).into(),
+                tao_to_children: tao_to_children.into(),
+                tao_from_parents: tao_from_parents.into(),
+                childkey_take: Self::get_childkey_take(hotkey, netuid).into(),
+                last_epoch_dividends: last_epoch_dividends.into(),
+                retained_dividends: retained_dividends.into(),
+            });
+        }
+
+        Some(ChildkeyGraph {
+            netuid: netuid.into(),
+            nodes,
+            edges,
+            pending,
+        })
+    }
+
+    /// Validator part of the emission of `hotkey` on `netuid` in the last epoch, split from the
+    /// miner part pro rata to its dividends and incentive.
+    fn get_last_epoch_dividends(hotkey: &T::AccountId, netuid: NetUid) -> AlphaBalance {
+        let Ok(uid) = Self::get_uid_for_net_and_hotkey(netuid, hotkey) else {
+            return AlphaBalance::ZERO;
+        };
+        let emission = Self::get_emission_for_uid(netuid, uid);
+        let dividends = u64::from(Self::get_dividends_for_uid(netuid, uid));
+        let incentive = u64::from(Self::get_incentive_for_uid(
+            NetUidStorageIndex::from(netuid),
+            uid,
+        ));
+        // Without dividends nor incentive, the epoch pays validators by stake.
+        if dividends.saturating_add(incentive) == 0 {
+            return emission;
+        }
+        U96F32::saturating_from_num(emission)
+            .saturating_mul(U96F32::saturating_from_num(dividends))
+            .safe_div(U96F32::saturating_from_num(
+                dividends.saturating_add(incentive),
+            ))
+            .saturating_to_num::<u64>()
+            .into()
+    }
+
+    fn childkey_share(stake: u64, proportion: u64) -> u64 {
+        U96F32::saturating_from_num(stake)
+            .saturating_mul(
+                U96F32::saturating_from_num(proportion)
+                    .safe_div(U96F32::saturating_from_num(u64::MAX)),
+            )
+            .saturating_to_num()
+    }
+}
@@ -0,0 +1,125 @@
+use super::*;
+extern crate alloc;
+use crate::epoch::math::*;
+use crate::epoch::run_epoch::EpochIntermediates;
+use codec::Compact;
+use frame_support::pallet_prelude::{Decode, Encode};
+use frame_support::storage::{TransactionOutcome, with_transaction};
+use substrate_fixed::types::I32F32;
+use subtensor_macros::freeze_struct;
+use subtensor_runtime_common::{AlphaBalance, MechId, NetUid};
+
+/// Result of an epoch dry-run at `block`. Vectors are indexed by uid, and proportions are
+/// scaled to `u16::MAX` as in the persisted epoch terms. `weights` are the masked and
+/// row-normalized weights the consensus algorithm received.
+#[freeze_struct("13b23d4244ef74b3")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct EpochSimulation<AccountId: TypeInfo + Encode + Decode> {
+    pub netuid: Compact<NetUid>,
+    pub mecid: MechId,
+    pub block: Compact<u64>,
+    pub consensus_algorithm: ConsensusAlgorithm,
+    pub hotkeys: Vec<AccountId>,
+    pub stake: Vec<Compact<u16>>,
+    pub active_stake: Vec<Compact<u16>>,
+    pub weights: Vec<Vec<(Compact<u16>, Compact<u16>)>>,
+    pub clipped_weights: Vec<Vec<(Compact<u16>, Compact<u16>)>>,
+    pub consensus: Vec<Compact<u16>>,
+    pub ranks: Vec<Compact<u16>>,
+    pub validator_trust: Vec<Compact<u16>>,
+    pub bonds: Vec<Vec<(Compact<u16>, Compact<u16>)>>,
+    pub dividends: Vec<Compact<u16>>,
+    pub incentive: Vec<Compact<u16>>,
+}
+
+fn proportions_to_u16(values: &[I32F32]) -> Vec<Compact<u16>> {
+    values
+        .iter()
+        .map(|value| fixed_proportion_to_u16(*value).into())
+        .collect()
+}
+
+fn sparse_proportions_to_u16(
+    matrix: &[Vec<(u16, I32F32)>],
+) -> Vec<Vec<(Compact<u16>, Compact<u16>)>> {
+    matrix
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|(uid, value)| ((*uid).into(), fixed_proportion_to_u16(*value).into()))
+                .collect()
+        })
+        .collect()
+}
+
+impl<T: Config> Pallet<T> {
+    /// Runs the epoch of a subnet mechanism at the current block without persisting anything.
+    ///
+    /// `override_weights` replaces the weights of the given uids as if they had been set at
+    /// the current block. Uids that are not registered on the subnet are ignored.
+    pub fn simulate_epoch(
+        netuid: NetUid,
+        mecid: MechId,
+        override_weights: Option<Vec<(u16, Vec<(u16, u16)>)>>,
+    ) -> Option<EpochSimulation<T::AccountId>> {
+        if Self::ensure_mechanism_exists(netuid, mecid).is_err() {
+            return None;
+        }
+
+        let n = Self::get_subnetwork_n(netuid);
+        let block = Self::get_current_block_as_u64();
+        let intermediates = Self::dry_run_epoch(netuid, mecid, override_weights)?;
+
+        Some(EpochSimulation {
+            netuid: netuid.into(),
+            mecid,
+            block: block.into(),
+            consensus_algorithm: SubnetConsensusAlgorithm::<T>::get(netuid),
+            hotkeys: (0..n).map(|uid| Keys::<T>::get(netuid, uid)).collect(),
+            stake: proportions_to_u16(&intermediates.stake),
+            active_stake: proportions_to_u16(&intermediates.active_stake),
+            weights: sparse_proportions_to_u16(&intermediates.weights),
+            clipped_weights: sparse_proportions_to_u16(&intermediates.clipped_weights),
+            consensus: proportions_to_u16(&intermediates.consensus),
+            ranks: proportions_to_u16(&intermediates.ranks),
+            validator_trust: proportions_to_u16(&intermediates.validator_trust),
+            bonds: sparse_proportions_to_u16(&intermediates.bonds),
+            dividends: proportions_to_u16(&intermediates.dividends),
+            incentive: proportions_to_u16(&intermediates.incentive),
+        })
+    }
+
+    /// Intermediate terms of the epoch of a subnet mechanism at the current block, with every
+    /// storage change rolled back. See `simulate_epoch` for `override_weights`.
+    pub(crate) fn dry_run_epoch(
+        netuid: NetUid,
+        mecid: MechId,
+        override_weights: Option<Vec<(u16, Vec<(u16, u16)>)>>,
+    ) -> Option<EpochIntermediates> {
+        let netuid_index = Self::get_mechanism_storage_index(netuid, mecid);
+        let n = Self::get_subnetwork_n(netuid);
+        let block = Self::get_current_block_as_u64();
+
+        with_transaction(|| {
+            for (uid, weights) in override_weights.unwrap_or_default() {
+                if uid >= n {
+                    continue;
+                }
+                let weights: Vec<(u16, u16)> =
+                    weights.into_iter().filter(|(dest, _)| *dest < n).collect();
+                Weights::<T>::insert(netuid_index, uid, weights);
+                Self::set_last_update_for_uid(netuid_index, uid, block);
+            }
+
+            let mut intermediates = EpochIntermediates::default();
+            Self::epoch_mechanism_with_intermediates(
+                netuid,
+                mecid,
+                AlphaBalance::ZERO,
+                Some(&mut intermediates),
+            );
+            TransactionOutcome::Rollback(Ok::<_, DispatchError>(intermediates))
+        })
+        .ok()
+    }
+}
@@ -110,7 +110,7 @@ pub struct Metagraph<AccountId: TypeInfo + Encode + Decode> {
     alpha_dividends_per_hotkey: Vec<(AccountId, Compact<AlphaBalance>)>, // List of dividend payout in alpha via subnet.
 }
 
-#[freeze_struct("5f9c8beab622882c")]
+#[freeze_struct("9b3a51c521b51475")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
 pub struct SelectiveMetagraph<AccountId: TypeInfo + Encode + Decode + Clone> {
     // Subnet index
@@ -213,6 +213,8 @@ pub struct SelectiveMetagraph<AccountId: TypeInfo + Encode + Decode + Clone> {
     validators: Option<Vec<Compact<u16>>>, // List of validators
     // commitments
     commitments: Option<Vec<(AccountId, Vec<Compact<u8>>)>>, // List of commitments
+    // weights following
+    weights_leaders: Option<Vec<(AccountId, AccountId)>>, // List of (follower, leader) hotkeys
 }
 
 impl<AccountId> SelectiveMetagraph<AccountId>
@@ -373,6 +375,9 @@ where
             Some(SelectiveMetagraphIndex::Commitments) => {
                 self.commitments = other.commitments.clone()
             }
+            Some(SelectiveMetagraphIndex::WeightsLeaders) => {
+                self.weights_leaders = other.weights_leaders.clone()
+            }
             None => {}
         };
     }
@@ -458,6 +463,7 @@ where
             alpha_dividends_per_hotkey: None,
             validators: None,
             commitments: None,
+            weights_leaders: None,
         }
     }
 }
@@ -537,6 +543,7 @@ pub enum SelectiveMetagraphIndex {
     AlphaDividendsPerHotkey,
     Validators,
     Commitments,
+    WeightsLeaders,
 }
 
 impl SelectiveMetagraphIndex {
@@ -616,6 +623,7 @@ impl SelectiveMetagraphIndex {
             71 => Some(SelectiveMetagraphIndex::AlphaDividendsPerHotkey),
             72 => Some(SelectiveMetagraphIndex::Validators),
             73 => Some(SelectiveMetagraphIndex::Commitments),
+            74 => Some(SelectiveMetagraphIndex::WeightsLeaders),
             _ => None,
         }
     }
@@ -820,6 +828,17 @@ impl<T: Config> Pallet<T> {
                 .map(Compact::from)
                 .collect();
 
+            // Hyperparameters the mechanism may override
+            let (alpha_low, alpha_high) = Self::get_mechanism_alpha_values(netuid_index);
+            meta.kappa = Self::get_mechanism_kappa(netuid_index).into();
+            meta.min_allowed_weights = Self::get_mechanism_min_allowed_weights(netuid_index).into();
+            meta.max_weights_limit = Self::get_mechanism_max_weight_limit(netuid_index).into();
+            meta.commit_reveal_period = Self::get_mechanism_reveal_period(netuid_index).into();
+            meta.liquid_alpha_enabled = Self::get_mechanism_liquid_alpha_enabled(netuid_index);
+            meta.alpha_high = alpha_high.into();
+            meta.alpha_low = alpha_low.into();
+            meta.bonds_moving_avg = Self::get_mechanism_bonds_moving_average(netuid_index).into();
+
             Some(meta)
         } else {
             None
@@ -1420,6 +1439,7 @@ impl<T: Config> Pallet<T> {
             }
             Some(SelectiveMetagraphIndex::Validators) => Self::get_validators(netuid),
             Some(SelectiveMetagraphIndex::Commitments) => Self::get_commitments(netuid),
+            Some(SelectiveMetagraphIndex::WeightsLeaders) => Self::get_weights_leaders(netuid),
             None => SelectiveMetagraph {
                 // Subnet index
                 netuid: netuid.into(),
@@ -1463,6 +1483,28 @@ impl<T: Config> Pallet<T> {
                 let mut meta = Self::get_single_selective_metagraph(netuid, metagraph_index);
                 // Replace netuid with index
                 meta.netuid = NetUid::from(u16::from(netuid_index)).into();
+                // Replace hyperparameters the mechanism may override
+                let (alpha_low, alpha_high) = Self::get_mechanism_alpha_values(netuid_index);
+                meta.kappa = meta
+                    .kappa
+                    .map(|_| Self::get_mechanism_kappa(netuid_index).into());
+                meta.min_allowed_weights = meta
+                    .min_allowed_weights
+                    .map(|_| Self::get_mechanism_min_allowed_weights(netuid_index).into());
+                meta.max_weights_limit = meta
+                    .max_weights_limit
+                    .map(|_| Self::get_mechanism_max_weight_limit(netuid_index).into());
+                meta.commit_reveal_period = meta
+                    .commit_reveal_period
+                    .map(|_| Self::get_mechanism_reveal_period(netuid_index).into());
+                meta.liquid_alpha_enabled = meta
+                    .liquid_alpha_enabled
+                    .map(|_| Self::get_mechanism_liquid_alpha_enabled(netuid_index));
+                meta.alpha_high = meta.alpha_high.map(|_| alpha_high.into());
+                meta.alpha_low = meta.alpha_low.map(|_| alpha_low.into());
+                meta.bonds_moving_avg = meta
+                    .bonds_moving_avg
+                    .map(|_| Self::get_mechanism_bonds_moving_average(netuid_index).into());
                 meta
             }
         }
@@ -1525,6 +1567,19 @@ impl<T: Config> Pallet<T> {
             ..Default::default()
         }
     }
+
+    fn get_weights_leaders(netuid: NetUid) -> SelectiveMetagraph<T::AccountId> {
+        let weights_leaders: Vec<(T::AccountId, T::AccountId)> =
+            WeightsLeader::<T>::iter_prefix(netuid)
+                .map(|(follower, (leader, _))| (follower, leader))
+                .collect();
+
+        SelectiveMetagraph {
+            netuid: netuid.into(),
+            weights_leaders: Some(weights_leaders),
+            ..Default::default()
+        }
+    }
 }
 
 #[test]
@@ -1605,6 +1660,7 @@ fn test_selective_metagraph() {
         alpha_dividends_per_hotkey: None,
         validators: None,
         commitments: None,
+        weights_leaders: None,
     };
 
     // test init value
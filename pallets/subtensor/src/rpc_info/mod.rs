@@ -1,8 +1,12 @@
 use super::*;
+pub mod childkey_graph;
 pub mod delegate_info;
 pub mod dynamic_info;
+pub mod epoch_simulation;
 pub mod metagraph;
 pub mod neuron_info;
 pub mod show_subnet;
 pub mod stake_info;
 pub mod subnet_info;
+pub mod validator_agreement;
+pub mod weight_commits;
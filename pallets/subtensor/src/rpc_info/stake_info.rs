@@ -3,10 +3,12 @@ extern crate alloc;
 use codec::Compact;
 use frame_support::pallet_prelude::{Decode, Encode};
 use sp_std::collections::btree_map::BTreeMap;
+use substrate_fixed::types::U64F64;
 use subtensor_runtime_common::{AlphaBalance, NetUid, TaoBalance, Token};
 use subtensor_swap_interface::SwapHandler;
 
 use super::*;
+use safe_math::*;
 
 #[freeze_struct("8cef3fae262a623e")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
@@ -30,6 +32,19 @@ pub struct StakeAvailability {
     available: Compact<AlphaBalance>,
 }
 
+#[freeze_struct("533c56f8920657a4")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct StakeInfoWithCostBasis {
+    netuid: Compact<NetUid>,
+    stake: Compact<AlphaBalance>,
+    cost_basis: Compact<TaoBalance>,
+    /// In rao per alpha.
+    average_entry_price: Compact<u64>,
+    value: Compact<TaoBalance>,
+    unrealized_pnl: i64,
+    realized_pnl: i64,
+}
+
 // Per-subnet stake breakdown: total alpha, locked mass, and what is free to unstake.
 impl StakeAvailability {
     pub fn total(&self) -> AlphaBalance {
@@ -45,6 +60,37 @@ impl StakeAvailability {
     }
 }
 
+// Stake of a coldkey on one subnet, with the TAO it cost and the PnL on it.
+impl StakeInfoWithCostBasis {
+    pub fn netuid(&self) -> NetUid {
+        self.netuid.into()
+    }
+
+    pub fn stake(&self) -> AlphaBalance {
+        self.stake.into()
+    }
+
+    pub fn cost_basis(&self) -> TaoBalance {
+        self.cost_basis.into()
+    }
+
+    pub fn average_entry_price(&self) -> u64 {
+        self.average_entry_price.into()
+    }
+
+    pub fn value(&self) -> TaoBalance {
+        self.value.into()
+    }
+
+    pub fn unrealized_pnl(&self) -> i64 {
+        self.unrealized_pnl
+    }
+
+    pub fn realized_pnl(&self) -> i64 {
+        self.realized_pnl
+    }
+}
+
 impl<T: Config> Pallet<T> {
     fn _get_stake_info_for_coldkeys(
         coldkeys: Vec<T::AccountId>,
@@ -206,6 +252,38 @@ impl<T: Config> Pallet<T> {
             .collect()
     }
 
+    /// Stake of a tracked coldkey on each subnet with its cost basis and PnL at the current
+    /// price. Empty if the coldkey does not track its cost basis.
+    pub fn get_stake_info_with_cost_basis(coldkey: T::AccountId) -> Vec<StakeInfoWithCostBasis> {
+        if !CostBasisTrackingSince::<T>::contains_key(&coldkey) {
+            return Vec::new();
+        }
+        Self::get_all_subnet_netuids()
+            .into_iter()
+            .filter_map(|netuid| {
+                let stake = Self::total_coldkey_alpha_on_subnet(&coldkey, netuid);
+                let basis = StakeCostBasis::<T>::get(netuid, &coldkey);
+                if stake.is_zero() && basis == CostBasis::default() {
+                    return None;
+                }
+                let value = Self::cost_basis_market_value(netuid, stake);
+                let average_entry_price: u64 = U64F64::saturating_from_num(basis.cost)
+                    .saturating_mul(U64F64::saturating_from_num(1_000_000_000_u64))
+                    .safe_div(U64F64::saturating_from_num(stake))
+                    .saturating_to_num();
+                Some(StakeInfoWithCostBasis {
+                    netuid: netuid.into(),
+                    stake: stake.into(),
+                    cost_basis: basis.cost.into(),
+                    average_entry_price: average_entry_price.into(),
+                    value: value.into(),
+                    unrealized_pnl: Self::cost_basis_pnl(value, basis.cost),
+                    realized_pnl: basis.realized_pnl,
+                })
+            })
+            .collect()
+    }
+
     pub fn get_stake_fee(
         origin: Option<(T::AccountId, NetUid)>,
         _origin_coldkey_account: T::AccountId,
@@ -4,7 +4,7 @@ use frame_support::storage::IterableStorageMap;
 extern crate alloc;
 use codec::Compact;
 use substrate_fixed::types::{I32F32, U64F64};
-use subtensor_runtime_common::{NetUid, TaoBalance};
+use subtensor_runtime_common::{MechId, NetUid, TaoBalance};
 
 #[freeze_struct("f691073111c39620")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
@@ -85,7 +85,7 @@ pub struct SubnetHyperparams {
     liquid_alpha_enabled: bool,
 }
 
-#[freeze_struct("336a6658e70b5554")]
+#[freeze_struct("2e917b485397d882")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
 pub struct SubnetHyperparamsV2 {
     rho: Compact<u16>,
@@ -121,6 +121,9 @@ pub struct SubnetHyperparamsV2 {
     transfers_enabled: bool,
     bonds_reset_enabled: bool,
     user_liquidity_enabled: bool,
+    /// Hyperparameter overrides of each mechanism, indexed by mechanism id. Unset fields use
+    /// the subnet value above.
+    mechanism_hyperparams: Vec<MechanismHyperparams>,
 }
 
 /// Tagged value for a single hyperparameter in [`SubnetHyperparamsV3`].
@@ -414,6 +417,14 @@ impl<T: Config> Pallet<T> {
         let subnet_token_enabled = Self::get_subtoken_enabled(netuid);
         let transfers_enabled = Self::get_transfer_toggle(netuid);
         let bonds_reset = Self::get_bonds_reset(netuid);
+        let mechanism_hyperparams = (0..u8::from(MechanismCountCurrent::<T>::get(netuid)))
+            .map(|mecid| {
+                MechanismHyperparamOverrides::<T>::get(Self::get_mechanism_storage_index(
+                    netuid,
+                    MechId::from(mecid),
+                ))
+            })
+            .collect();
 
         Some(SubnetHyperparamsV2 {
             rho: rho.into(),
@@ -449,6 +460,7 @@ impl<T: Config> Pallet<T> {
             transfers_enabled,
             bonds_reset_enabled: bonds_reset,
             user_liquidity_enabled: false,
+            mechanism_hyperparams,
         })
     }
 
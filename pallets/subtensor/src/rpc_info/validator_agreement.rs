@@ -0,0 +1,150 @@
+use super::*;
+extern crate alloc;
+use crate::epoch::math::*;
+use alloc::collections::VecDeque;
+use codec::Compact;
+use frame_support::pallet_prelude::{Decode, Encode};
+use substrate_fixed::types::I32F32;
+use subtensor_macros::freeze_struct;
+use subtensor_runtime_common::{MechId, NetUid};
+
+/// How closely a validator follows the consensus of a subnet mechanism. Proportions are scaled
+/// to `u16::MAX`.
+#[freeze_struct("23eda244c80ed86")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct ValidatorAgreement<AccountId: TypeInfo + Encode + Decode> {
+    pub uid: Compact<u16>,
+    pub hotkey: AccountId,
+    /// Cosine similarity of the validator weights with the consensus vector.
+    pub consensus_similarity: Compact<u16>,
+    /// Weight removed by clipping the validator weights to consensus.
+    pub clipped_mass: Compact<u16>,
+    /// Weight removed from the bond inputs, the clipped mass scaled by the bonds penalty.
+    pub bond_penalty: Compact<u16>,
+}
+
+/// Agreement of the validators of a subnet mechanism, computed from an epoch dry-run at `block`.
+///
+/// `clusters` are groups of validator uids linked by a pairwise weight similarity of at least
+/// the requested threshold. Only groups of two or more validators are listed.
+#[freeze_struct("394692593d1a03ed")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct ValidatorAgreementReport<AccountId: TypeInfo + Encode + Decode> {
+    pub netuid: Compact<NetUid>,
+    pub mecid: MechId,
+    pub block: Compact<u64>,
+    pub validators: Vec<ValidatorAgreement<AccountId>>,
+    pub clusters: Vec<Vec<Compact<u16>>>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Agreement report of the validators with weights on a subnet mechanism.
+    /// `similarity_threshold` is a proportion scaled to `u16::MAX`.
+    pub fn get_validator_agreement(
+        netuid: NetUid,
+        mecid: MechId,
+        similarity_threshold: u16,
+    ) -> Option<ValidatorAgreementReport<T::AccountId>> {
+        if Self::ensure_mechanism_exists(netuid, mecid).is_err() {
+            return None;
+        }
+
+        let intermediates = Self::dry_run_epoch(netuid, mecid, None)?;
+        let bonds_penalty = Self::get_float_bonds_penalty(netuid);
+        let weight_sums = row_sum_sparse(&intermediates.weights);
+        let clipped_sums = row_sum_sparse(&intermediates.clipped_weights);
+        let consensus: Vec<(u16, I32F32)> = intermediates
+            .consensus
+            .iter()
+            .enumerate()
+            .filter(|(_, value)| **value > I32F32::saturating_from_num(0))
+            .map(|(uid, value)| (uid as u16, *value))
+            .collect();
+
+        // Validators are the rows the consensus algorithm received weights for.
+        let rows: Vec<(u16, Vec<(u16, I32F32)>)> = intermediates
+            .weights
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| !row.is_empty())
+            .map(|(uid, row)| {
+                let mut row = row.clone();
+                row.sort_unstable_by_key(|(dest, _)| *dest);
+                (uid as u16, row)
+            })
+            .collect();
+
+        let validators = rows
+            .iter()
+            .map(|(uid, row)| {
+                let index = usize::from(*uid);
+                let clipped_mass =
+                    get_safe(&weight_sums, index).saturating_sub(get_safe(&clipped_sums, index));
+                ValidatorAgreement {
+                    uid: (*uid).into(),
+                    hotkey: Keys::<T>::get(netuid, *uid),
+                    consensus_similarity: fixed_proportion_to_u16(cosine_similarity_sparse(
+                        row, &consensus,
+                    ))
+                    .into(),
+                    clipped_mass: fixed_proportion_to_u16(clipped_mass).into(),
+                    bond_penalty: fixed_proportion_to_u16(
+                        clipped_mass.saturating_mul(bonds_penalty),
+                    )
+                    .into(),
+                }
+            })
+            .collect();
+
+        Some(ValidatorAgreementReport {
+            netuid: netuid.into(),
+            mecid,
+            block: Self::get_current_block_as_u64().into(),
+            validators,
+            clusters: Self::similarity_clusters(
+                &rows,
+                u16_proportion_to_fixed(similarity_threshold),
+            ),
+        })
+    }
+
+    /// Connected groups of the graph linking rows with a cosine similarity of at least
+    /// `threshold`.
+    fn similarity_clusters(
+        rows: &[(u16, Vec<(u16, I32F32)>)],
+        threshold: I32F32,
+    ) -> Vec<Vec<Compact<u16>>> {
+        let linked = |a: usize, b: usize| match (rows.get(a), rows.get(b)) {
+            (Some((_, row_a)), Some((_, row_b))) => {
+                cosine_similarity_sparse(row_a, row_b) >= threshold
+            }
+            _ => false,
+        };
+
+        let mut visited = vec![false; rows.len()];
+        let mut clusters = Vec::new();
+        for start in 0..rows.len() {
+            if visited.get(start).copied().unwrap_or(true) {
+                continue;
+            }
+            let mut cluster = Vec::new();
+            let mut queue = VecDeque::from([start]);
+            while let Some(current) = queue.pop_front() {
+                match visited.get_mut(current) {
+                    Some(seen) if !*seen => *seen = true,
+                    _ => continue,
+                }
+                if let Some((uid, _)) = rows.get(current) {
+                    cluster.push(Compact(*uid));
+                }
+                queue.extend((0..rows.len()).filter(|other| {
+                    !visited.get(*other).copied().unwrap_or(true) && linked(current, *other)
+                }));
+            }
+            if cluster.len() > 1 {
+                clusters.push(cluster);
+            }
+        }
+        clusters
+    }
+}
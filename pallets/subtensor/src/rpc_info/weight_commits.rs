@@ -0,0 +1,137 @@
+use super::*;
+extern crate alloc;
+use codec::Compact;
+use frame_support::pallet_prelude::{Decode, Encode};
+use sp_core::H256;
+use subtensor_macros::freeze_struct;
+use subtensor_runtime_common::{MechId, NetUid, NetUidStorageIndex};
+
+/// Where a pending weight commit stands relative to its reveal epoch.
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Copy, Debug, TypeInfo)]
+pub enum WeightCommitState {
+    /// The reveal epoch has not started yet.
+    Waiting,
+    /// The current epoch is the reveal epoch. CR-v2 commits must be revealed now, timelocked
+    /// commits are revealed by the chain once their Drand round is available.
+    Revealable,
+    /// The reveal epoch is over, the commit will never be applied.
+    Expired,
+}
+
+/// A weight commit of a hotkey that has not been revealed yet. Revealed commits are removed
+/// from storage, so they are no longer listed.
+///
+/// Epochs are `SubnetEpochIndex` values. Reveal window blocks are projected from the last
+/// epoch block with the current tempo, so a tempo change or a triggered epoch moves them.
+#[freeze_struct("1b14bde3a0a98ea5")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct WeightCommitStatus {
+    pub mecid: MechId,
+    /// Hash of a CR-v2 commit, `None` for timelocked commits.
+    pub commit_hash: Option<H256>,
+    /// Drand round of a timelocked commit, `None` for CR-v2 commits.
+    pub reveal_round: Option<Compact<u64>>,
+    pub commit_block: Compact<u64>,
+    pub commit_epoch: Compact<u64>,
+    pub reveal_epoch: Compact<u64>,
+    pub reveal_window_start: Option<Compact<u64>>,
+    pub reveal_window_end: Option<Compact<u64>>,
+    pub state: WeightCommitState,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Pending weight commits of `hotkey` on every mechanism of `netuid`, oldest first.
+    pub fn get_weight_commit_status(
+        hotkey: T::AccountId,
+        netuid: NetUid,
+    ) -> Vec<WeightCommitStatus> {
+        if !Self::if_subnet_exist(netuid) {
+            return Vec::new();
+        }
+
+        let mut statuses = Vec::new();
+        for mecid in 0..u8::from(MechanismCountCurrent::<T>::get(netuid)) {
+            let mecid = MechId::from(mecid);
+            let netuid_index = Self::get_mechanism_storage_index(netuid, mecid);
+            let reveal_period = Self::get_mechanism_reveal_period(netuid_index);
+
+            let hashed = WeightCommits::<T>::get(netuid_index, &hotkey)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(hash, commit_epoch, commit_block, _)| {
+                    (Some(hash), None, commit_block, commit_epoch)
+                });
+            let timelocked = Self::timelocked_commits_of(netuid_index, &hotkey)
+                .into_iter()
+                .map(|(commit_epoch, commit_block, round)| {
+                    (None, Some(round), commit_block, commit_epoch)
+                });
+
+            for (commit_hash, reveal_round, commit_block, commit_epoch) in hashed.chain(timelocked)
+            {
+                let reveal_epoch = commit_epoch.saturating_add(reveal_period);
+                let state = if Self::is_commit_expired(netuid_index, commit_epoch) {
+                    WeightCommitState::Expired
+                } else if Self::is_reveal_block_range(netuid_index, commit_epoch) {
+                    WeightCommitState::Revealable
+                } else {
+                    WeightCommitState::Waiting
+                };
+                let window_start = Self::projected_epoch_start_block(netuid, reveal_epoch);
+                let window_end =
+                    Self::projected_epoch_start_block(netuid, reveal_epoch.saturating_add(1))
+                        .map(|block| block.saturating_sub(1));
+
+                statuses.push(WeightCommitStatus {
+                    mecid,
+                    commit_hash,
+                    reveal_round: reveal_round.map(Compact),
+                    commit_block: commit_block.into(),
+                    commit_epoch: commit_epoch.into(),
+                    reveal_epoch: reveal_epoch.into(),
+                    reveal_window_start: window_start.map(Compact),
+                    reveal_window_end: window_end.map(Compact),
+                    state,
+                });
+            }
+        }
+        statuses
+    }
+
+    /// (commit_epoch, commit_block, reveal_round) of the timelocked commits of `hotkey`.
+    fn timelocked_commits_of(
+        netuid_index: NetUidStorageIndex,
+        hotkey: &T::AccountId,
+    ) -> Vec<(u64, u64, u64)> {
+        let mut commits: Vec<(u64, u64, u64)> =
+            TimelockedWeightCommits::<T>::iter_prefix(netuid_index)
+                .flat_map(|(commit_epoch, queue)| {
+                    queue
+                        .into_iter()
+                        .filter(move |(who, _, _, _)| who == hotkey)
+                        .map(move |(_, commit_block, _, round)| (commit_epoch, commit_block, round))
+                })
+                .collect();
+        commits.sort_unstable();
+        commits
+    }
+
+    /// First block of the epoch with counter value `epoch`, projected with the current tempo
+    /// from the last epoch block. `None` when the subnet has no epochs (tempo 0).
+    pub fn projected_epoch_start_block(netuid: NetUid, epoch: u64) -> Option<u64> {
+        let tempo = u64::from(Self::get_tempo(netuid));
+        if tempo == 0 {
+            return None;
+        }
+        let current = SubnetEpochIndex::<T>::get(netuid);
+        let last_epoch_block = LastEpochBlock::<T>::get(netuid);
+        if epoch <= current {
+            let epochs_back = current.saturating_sub(epoch);
+            Some(last_epoch_block.saturating_sub(epochs_back.saturating_mul(tempo)))
+        } else {
+            let epochs_ahead = epoch.saturating_sub(current).saturating_sub(1);
+            Self::get_next_epoch_start_block(netuid)
+                .map(|next| next.saturating_add(epochs_ahead.saturating_mul(tempo)))
+        }
+    }
+}
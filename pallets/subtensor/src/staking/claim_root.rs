@@ -3,9 +3,9 @@ use frame_support::dispatch::DispatchResult;
 use frame_support::storage::{TransactionOutcome, with_transaction};
 use frame_support::weights::Weight;
 use sp_core::Get;
-use sp_runtime::DispatchError;
-use sp_std::collections::btree_set::BTreeSet;
-use substrate_fixed::types::I96F32;
+use sp_runtime::{DispatchError, Perbill};
+use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
+use substrate_fixed::types::{I96F32, U64F64};
 use subtensor_swap_interface::SwapHandler;
 
 impl<T: Config> Pallet<T> {
@@ -131,7 +131,7 @@ impl<T: Config> Pallet<T> {
         hotkey: &T::AccountId,
         coldkey: &T::AccountId,
         netuid: NetUid,
-        root_claim_type: RootClaimTypeEnum,
+        root_claim_type: RootClaimTypeEnum<T::AccountId>,
         ignore_minimum_condition: bool,
     ) -> DispatchResult {
         // Subtract the root claimed.
@@ -160,10 +160,32 @@ impl<T: Config> Pallet<T> {
             return Ok(()); // no-op
         }
 
-        let swap = match root_claim_type {
-            RootClaimTypeEnum::Swap => true,
-            RootClaimTypeEnum::Keep => false,
-            RootClaimTypeEnum::KeepSubnets { subnets } => !subnets.contains(&netuid),
+        // The hotkey receiving the claim, whether the alpha is swapped for TAO and staked on
+        // root, and the price limit of that swap. Restake targets that were swapped away since
+        // fall back to the hotkey that earned the claim.
+        let restake_target = |netuid: NetUid, targets: &BTreeMap<NetUid, T::AccountId>| {
+            targets
+                .get(&netuid)
+                .filter(|target| Self::hotkey_account_exists(target))
+                .cloned()
+        };
+        let (stake_hotkey, swap, price_limit) = match &root_claim_type {
+            RootClaimTypeEnum::Swap => (hotkey.clone(), true, None),
+            RootClaimTypeEnum::Keep => (hotkey.clone(), false, None),
+            RootClaimTypeEnum::KeepSubnets { subnets } => {
+                (hotkey.clone(), !subnets.contains(&netuid), None)
+            }
+            RootClaimTypeEnum::Restake {
+                targets,
+                max_slippage,
+            } => match restake_target(netuid, targets) {
+                Some(target) => (target, false, None),
+                None => (
+                    restake_target(NetUid::ROOT, targets).unwrap_or_else(|| hotkey.clone()),
+                    true,
+                    Some(Self::root_claim_price_limit(netuid, *max_slippage)),
+                ),
+            },
         };
 
         if swap {
@@ -172,7 +194,7 @@ impl<T: Config> Pallet<T> {
                 let owed_tao = match Self::swap_alpha_for_tao(
                     netuid,
                     owed_u64.into(),
-                    T::SwapInterface::min_price::<TaoBalance>(),
+                    price_limit.unwrap_or_else(T::SwapInterface::min_price::<TaoBalance>),
                     true,
                 ) {
                     Ok(owed_tao) => owed_tao,
@@ -183,6 +205,11 @@ impl<T: Config> Pallet<T> {
                     }
                 };
 
+                // A swap stopped by the price limit leaves part of the claim unsold.
+                if price_limit.is_some() && owed_tao.amount_paid_in < owed_u64.into() {
+                    return TransactionOutcome::Rollback(Err(Error::<T>::SlippageTooHigh.into()));
+                }
+
                 let root_subnet_account_id = match Self::get_subnet_account_id(NetUid::ROOT) {
                     Some(account_id) => account_id,
                     None => {
@@ -210,7 +237,7 @@ impl<T: Config> Pallet<T> {
                 Self::record_protocol_outflow(netuid, root_sell_tao);
 
                 Self::increase_stake_for_hotkey_and_coldkey_on_subnet(
-                    hotkey,
+                    &stake_hotkey,
                     coldkey,
                     NetUid::ROOT,
                     owed_tao.amount_paid_out.to_u64().into(),
@@ -232,7 +259,7 @@ impl<T: Config> Pallet<T> {
                 });
 
                 Self::add_stake_adjust_root_claimed_for_hotkey_and_coldkey(
-                    hotkey,
+                    &stake_hotkey,
                     coldkey,
                     owed_tao.amount_paid_out.into(),
                 );
@@ -244,7 +271,7 @@ impl<T: Config> Pallet<T> {
         {
             // Increase the stake with the alpha owned
             Self::increase_stake_for_hotkey_and_coldkey_on_subnet(
-                hotkey,
+                &stake_hotkey,
                 coldkey,
                 netuid,
                 owed_u64.into(),
@@ -259,7 +286,15 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
-    fn root_claim_on_subnet_weight(_root_claim_type: RootClaimTypeEnum) -> Weight {
+    /// Lowest TAO price, in rao per alpha, accepted when swapping claimed alpha of `netuid`.
+    fn root_claim_price_limit(netuid: NetUid, max_slippage: Perbill) -> TaoBalance {
+        let price_rao: u64 = T::SwapInterface::current_alpha_price(netuid.into())
+            .saturating_mul(U64F64::saturating_from_num(1_000_000_000_u64))
+            .saturating_to_num();
+        max_slippage.left_from_one().mul_floor(price_rao).into()
+    }
+
+    fn root_claim_on_subnet_weight(_root_claim_type: RootClaimTypeEnum<T::AccountId>) -> Weight {
         Weight::from_parts(60_000_000, 6987)
             .saturating_add(T::DbWeight::get().reads(7_u64))
             .saturating_add(T::DbWeight::get().writes(5_u64))
@@ -440,7 +475,10 @@ impl<T: Config> Pallet<T> {
         weight
     }
 
-    pub fn change_root_claim_type(coldkey: &T::AccountId, new_type: RootClaimTypeEnum) {
+    pub fn change_root_claim_type(
+        coldkey: &T::AccountId,
+        new_type: RootClaimTypeEnum<T::AccountId>,
+    ) {
         RootClaimType::<T>::insert(coldkey.clone(), new_type.clone());
 
         Self::deposit_event(Event::RootClaimTypeSet {
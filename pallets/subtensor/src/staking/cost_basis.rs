@@ -0,0 +1,159 @@
+use super::*;
+use crate::weights::WeightInfo;
+use safe_math::*;
+use substrate_fixed::types::U64F64;
+use subtensor_runtime_common::NetUid;
+use subtensor_swap_interface::SwapHandler;
+
+impl<T: Config> Pallet<T> {
+    /// Turns cost-basis tracking on or off for `coldkey`.
+    ///
+    /// When turned on, the alpha already held on each subnet enters the ledger at its current
+    /// market value, which requires the coldkey to stake through at most
+    /// `MAX_COST_BASIS_STAKING_HOTKEYS` hotkeys. When turned off, the ledger of the coldkey is
+    /// cleared.
+    pub fn do_set_cost_basis_tracking(
+        coldkey: T::AccountId,
+        enabled: bool,
+    ) -> DispatchResultWithPostInfo {
+        let tracked = CostBasisTrackingSince::<T>::contains_key(&coldkey);
+        let netuids = Self::get_all_subnet_netuids();
+        let staking_hotkeys = StakingHotkeys::<T>::decode_len(&coldkey).unwrap_or_default();
+        if enabled && !tracked {
+            ensure!(
+                staking_hotkeys <= MAX_COST_BASIS_STAKING_HOTKEYS as usize,
+                Error::<T>::TooManyStakingHotkeys
+            );
+            CostBasisTrackingSince::<T>::insert(&coldkey, Self::get_current_block_as_u64());
+            for netuid in netuids.iter().copied() {
+                let alpha = Self::total_coldkey_alpha_on_subnet(&coldkey, netuid);
+                if alpha.is_zero() {
+                    continue;
+                }
+                StakeCostBasis::<T>::insert(
+                    netuid,
+                    &coldkey,
+                    CostBasis {
+                        cost: Self::cost_basis_market_value(netuid, alpha),
+                        realized_pnl: 0,
+                    },
+                );
+            }
+        } else if !enabled && tracked {
+            CostBasisTrackingSince::<T>::remove(&coldkey);
+            for netuid in netuids.iter() {
+                StakeCostBasis::<T>::remove(netuid, &coldkey);
+            }
+        }
+
+        Self::deposit_event(Event::CostBasisTrackingSet { coldkey, enabled });
+        Ok(Some(T::WeightInfo::set_cost_basis_tracking(
+            netuids.len() as u32,
+            staking_hotkeys as u32,
+        ))
+        .into())
+    }
+
+    /// Records `tao` paid by `coldkey` for alpha on `netuid`.
+    pub(crate) fn record_cost_basis_acquisition(
+        coldkey: &T::AccountId,
+        netuid: NetUid,
+        tao: TaoBalance,
+    ) {
+        if !CostBasisTrackingSince::<T>::contains_key(coldkey) {
+            return;
+        }
+        StakeCostBasis::<T>::mutate(netuid, coldkey, |basis| {
+            basis.cost = basis.cost.saturating_add(tao);
+        });
+    }
+
+    /// Records `alpha` of `coldkey` on `netuid` disposed of for `tao`, once the stake has been
+    /// decreased. The alpha leaves at the average cost and the difference is realized.
+    pub(crate) fn record_cost_basis_disposal(
+        coldkey: &T::AccountId,
+        netuid: NetUid,
+        alpha: AlphaBalance,
+        tao: TaoBalance,
+    ) {
+        if alpha.is_zero() || !CostBasisTrackingSince::<T>::contains_key(coldkey) {
+            return;
+        }
+        let removed = Self::take_cost_basis(coldkey, netuid, alpha);
+        StakeCostBasis::<T>::mutate(netuid, coldkey, |basis| {
+            basis.realized_pnl = basis
+                .realized_pnl
+                .saturating_add(Self::cost_basis_pnl(tao, removed));
+        });
+    }
+
+    /// Records `alpha` on `netuid` moved from `origin_coldkey` to `destination_coldkey`, once
+    /// the stake has been moved. The cost moves along with the alpha, or the alpha enters at its
+    /// market value if the origin coldkey is not tracked.
+    pub(crate) fn record_cost_basis_transfer(
+        origin_coldkey: &T::AccountId,
+        destination_coldkey: &T::AccountId,
+        netuid: NetUid,
+        alpha: AlphaBalance,
+    ) {
+        if origin_coldkey == destination_coldkey || alpha.is_zero() {
+            return;
+        }
+        let cost = if CostBasisTrackingSince::<T>::contains_key(origin_coldkey) {
+            Self::take_cost_basis(origin_coldkey, netuid, alpha)
+        } else {
+            Self::cost_basis_market_value(netuid, alpha)
+        };
+        Self::record_cost_basis_acquisition(destination_coldkey, netuid, cost);
+    }
+
+    /// Moves the tracking and the ledger of `old_coldkey` to `new_coldkey`.
+    pub fn transfer_cost_basis(old_coldkey: &T::AccountId, new_coldkey: &T::AccountId) {
+        let Some(since) = CostBasisTrackingSince::<T>::take(old_coldkey) else {
+            return;
+        };
+        CostBasisTrackingSince::<T>::mutate(new_coldkey, |tracked| {
+            *tracked = Some(tracked.map_or(since, |block| block.min(since)));
+        });
+        for netuid in Self::get_all_subnet_netuids() {
+            let old = StakeCostBasis::<T>::take(netuid, old_coldkey);
+            if old == CostBasis::default() {
+                continue;
+            }
+            StakeCostBasis::<T>::mutate(netuid, new_coldkey, |basis| {
+                basis.cost = basis.cost.saturating_add(old.cost);
+                basis.realized_pnl = basis.realized_pnl.saturating_add(old.realized_pnl);
+            });
+        }
+    }
+
+    /// Takes the cost of `alpha` out of the ledger of `coldkey` on `netuid` at the average cost,
+    /// once the alpha has left the stake of the coldkey.
+    fn take_cost_basis(coldkey: &T::AccountId, netuid: NetUid, alpha: AlphaBalance) -> TaoBalance {
+        let held = Self::total_coldkey_alpha_on_subnet(coldkey, netuid).saturating_add(alpha);
+        StakeCostBasis::<T>::mutate(netuid, coldkey, |basis| {
+            let removed: TaoBalance = U64F64::saturating_from_num(basis.cost)
+                .saturating_mul(U64F64::saturating_from_num(alpha))
+                .safe_div(U64F64::saturating_from_num(held))
+                .saturating_to_num::<u64>()
+                .into();
+            let removed = removed.min(basis.cost);
+            basis.cost = basis.cost.saturating_sub(removed);
+            removed
+        })
+    }
+
+    /// `value` minus `cost`, clamped to `i64`.
+    pub(crate) fn cost_basis_pnl(value: TaoBalance, cost: TaoBalance) -> i64 {
+        (u64::from(value) as i128)
+            .saturating_sub(u64::from(cost) as i128)
+            .clamp(i64::MIN as i128, i64::MAX as i128) as i64
+    }
+
+    pub(crate) fn cost_basis_market_value(netuid: NetUid, alpha: AlphaBalance) -> TaoBalance {
+        T::SwapInterface::current_alpha_price(netuid.into())
+            .saturating_mul(U64F64::saturating_from_num(alpha))
+            .saturating_to_num::<u64>()
+            .into()
+    }
+}
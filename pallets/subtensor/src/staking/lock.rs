@@ -1,5 +1,6 @@
 use super::*;
 use codec::{Decode, DecodeWithMemTracking, Encode};
+use frame_support::storage::{TransactionOutcome, with_transaction};
 use safe_math::FixedExt;
 use scale_info::TypeInfo;
 use sp_std::collections::btree_map::BTreeMap;
@@ -10,6 +11,8 @@ use subtensor_runtime_common::NetUid;
 
 pub const ONE_YEAR: u64 = 7200 * 365 + 1800;
 pub const LOCK_STATE_ZERO_THRESHOLD: u64 = 100;
+/// Maximum number of blocks a lock can be projected to in one call.
+pub const MAX_LOCK_PROJECTION_BLOCKS: usize = 1024;
 
 /// Exponential lock state for a coldkey on a subnet.
 #[crate::freeze_struct("1f6be20a66128b8d")]
@@ -50,6 +53,32 @@ impl RollDelta {
     }
 }
 
+/// Lock operation applied at the current block before projecting a coldkey lock.
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub enum LockAction<AccountId> {
+    /// `lock_stake` of `amount` to `hotkey`.
+    LockStake {
+        hotkey: AccountId,
+        amount: AlphaBalance,
+    },
+    /// `move_lock` to `destination_hotkey`.
+    MoveLock { destination_hotkey: AccountId },
+}
+
+/// Coldkey lock on a subnet projected to a block.
+#[crate::freeze_struct("8af2594a51e9faf7")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct LockProjection {
+    /// Block the lock is projected to.
+    pub block: u64,
+    /// Locked mass at that block.
+    pub locked_mass: AlphaBalance,
+    /// Conviction at that block.
+    pub conviction: U64F64,
+    /// Alpha the coldkey could unstake at that block, with its stake unchanged.
+    pub available_to_unstake: AlphaBalance,
+}
+
 /// A struct that incapsulates Lock primitives such as adding, removing,
 /// rolling, and updating aggregates.
 ///
@@ -750,6 +779,77 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Projects the coldkey lock on a subnet to each of `blocks`, after applying `action`
+    /// at the current block if given. The action is simulated and never persisted.
+    ///
+    /// The stake of the coldkey is assumed unchanged in the meantime. Blocks before the
+    /// current block are projected to the current block, and at most
+    /// `MAX_LOCK_PROJECTION_BLOCKS` blocks are projected.
+    pub fn project_coldkey_lock(
+        coldkey: &T::AccountId,
+        netuid: NetUid,
+        blocks: Vec<u64>,
+        action: Option<LockAction<T::AccountId>>,
+    ) -> Result<Vec<LockProjection>, DispatchError> {
+        with_transaction(|| {
+            let result = match action {
+                Some(LockAction::LockStake { hotkey, amount }) => {
+                    Self::do_lock_stake(coldkey, netuid, &hotkey, amount)
+                }
+                Some(LockAction::MoveLock { destination_hotkey }) => {
+                    Self::do_move_lock(coldkey, &destination_hotkey, netuid)
+                }
+                None => Ok(()),
+            }
+            .map(|()| Self::lock_projections(coldkey, netuid, blocks));
+
+            TransactionOutcome::Rollback(result)
+        })
+    }
+
+    fn lock_projections(
+        coldkey: &T::AccountId,
+        netuid: NetUid,
+        blocks: Vec<u64>,
+    ) -> Vec<LockProjection> {
+        let now = Self::get_current_block_as_u64();
+        let total = Self::total_coldkey_alpha_on_subnet(coldkey, netuid);
+        let unlock_rate = UnlockRate::<T>::get();
+        let maturity_rate = MaturityRate::<T>::get();
+        let lock = Lock::<T>::iter_prefix((coldkey, netuid))
+            .next()
+            .map(|(hotkey, lock)| (Self::is_subnet_owner_hotkey(netuid, &hotkey), lock));
+        let perpetual_lock = Self::is_perpetual_lock(coldkey, netuid);
+
+        blocks
+            .into_iter()
+            .take(MAX_LOCK_PROJECTION_BLOCKS)
+            .map(|block| {
+                let block = block.max(now);
+                let rolled = match &lock {
+                    Some((owner_lock, lock)) => {
+                        ConvictionModel::roll_forward_lock(
+                            lock.clone(),
+                            block,
+                            unlock_rate,
+                            maturity_rate,
+                            *owner_lock,
+                            perpetual_lock,
+                        )
+                        .0
+                    }
+                    None => Self::empty_lock(block),
+                };
+                LockProjection {
+                    block,
+                    locked_mass: rolled.locked_mass,
+                    conviction: rolled.conviction,
+                    available_to_unstake: total.saturating_sub(rolled.locked_mass),
+                }
+            })
+            .collect()
+    }
+
     /// Locks stake for a coldkey on a subnet to a specific hotkey.
     /// If no lock exists, creates one. If one exists, the hotkey must match.
     /// Top-up adds to locked_mass after rolling forward.
@@ -1820,34 +1920,12 @@ impl<T: Config> Pallet<T> {
         let mut remaining_to_transfer = amount;
 
         // Read the locks for source and destination coldkey (if exist) and roll forward
-        let Some((source_hotkey, mut source_model)) =
-            Self::read_conviction_model(origin_coldkey, netuid, now)
+        let Some((source_hotkey, source_lock)) =
+            Self::read_rolled_lock(origin_coldkey, netuid, now)
         else {
             return Ok(());
         };
-
-        let unlock_rate = UnlockRate::<T>::get();
-        let maturity_rate = MaturityRate::<T>::get();
-        source_model.roll_forward(now, unlock_rate, maturity_rate);
-        let mut source_lock = source_model.individual_lock().clone();
-        let maybe_destination_lock = Self::read_conviction_model(destination_coldkey, netuid, now)
-            .map(|(hotkey, mut model)| {
-                model.roll_forward(now, unlock_rate, maturity_rate);
-                (hotkey, model.individual_lock().clone())
-            });
-
-        let mut destination_hotkey = maybe_destination_lock
-            .as_ref()
-            .map(|(hotkey, _)| hotkey.clone())
-            .unwrap_or_else(|| source_hotkey.clone());
-        let mut destination_lock = maybe_destination_lock
-            .as_ref()
-            .map(|(_, lock)| lock.clone())
-            .unwrap_or(LockState {
-                locked_mass: AlphaBalance::ZERO,
-                conviction: U64F64::saturating_from_num(0),
-                last_update: now,
-            });
+        let maybe_destination_lock = Self::read_rolled_lock(destination_coldkey, netuid, now);
 
         // Calculate available stake by subtracting locked_mass from total alpha.
         let unavailable = source_lock.locked_mass;
@@ -1859,42 +1937,148 @@ impl<T: Config> Pallet<T> {
 
         // If result is non-zero, check the hotkey match between source and destination coldkey locks
         // (if destination coldkey lock exists). If no match, error out with LockHotkeyMismatch, otherwise,
-        // reduce remaining_to_transfer by min(remaining_to_transfer, locked_mass), reduce locked_mass on
-        // the source coldkey by the same amount, increase locked_mass on the destination coldkey by the
-        // same amount, reduce conviction on the source coldkey proportionally, and increase conviction
-        // on the destination coldkey proportionally.
+        // move min(remaining_to_transfer, locked_mass) of locked mass and the proportional conviction
+        // from the source coldkey to the destination coldkey.
         let mut locked_transfer = AlphaBalance::ZERO;
-        let mut conviction_transfer = U64F64::saturating_from_num(0);
         if !remaining_to_transfer.is_zero() {
             if let Some((existing_hotkey, _)) = maybe_destination_lock.as_ref() {
                 ensure!(
                     existing_hotkey == &source_hotkey,
                     Error::<T>::LockHotkeyMismatch
                 );
-                destination_hotkey = existing_hotkey.clone();
             }
-
             locked_transfer = remaining_to_transfer.min(source_lock.locked_mass);
-            conviction_transfer = if locked_transfer.is_zero() || source_lock.locked_mass.is_zero()
-            {
-                U64F64::saturating_from_num(0)
-            } else {
-                let locked_transfer = U64F64::saturating_from_num(locked_transfer.to_u64());
-                let source_locked = U64F64::saturating_from_num(source_lock.locked_mass.to_u64());
-                let transferred_proportion = locked_transfer.safe_div(source_locked);
-                source_lock
-                    .conviction
-                    .saturating_mul(transferred_proportion)
-            };
+        }
+
+        Self::move_locked_mass(
+            origin_coldkey,
+            destination_coldkey,
+            netuid,
+            (source_hotkey.clone(), source_lock),
+            maybe_destination_lock.unwrap_or_else(|| (source_hotkey, Self::empty_lock(now))),
+            locked_transfer,
+            now,
+        )
+        .map(|_| ())
+    }
 
-            source_lock.locked_mass = source_lock.locked_mass.saturating_sub(locked_transfer);
-            source_lock.conviction = source_lock.conviction.saturating_sub(conviction_transfer);
-            destination_lock.locked_mass =
-                destination_lock.locked_mass.saturating_add(locked_transfer);
-            destination_lock.conviction = destination_lock
+    /// Hands `amount` of the coldkey's locked alpha on a subnet to another coldkey, together
+    /// with the same share of its conviction and the stake backing it.
+    ///
+    /// The lock is rolled forward to the current block first. The stake is moved on the
+    /// lock hotkey, which the destination coldkey lock (if any) must also be locked to.
+    pub fn do_transfer_locked_stake(
+        origin_coldkey: &T::AccountId,
+        destination_coldkey: &T::AccountId,
+        netuid: NetUid,
+        amount: AlphaBalance,
+    ) -> DispatchResult {
+        ensure!(
+            origin_coldkey != destination_coldkey,
+            Error::<T>::LockTransferToSameColdkey
+        );
+        ensure!(!amount.is_zero(), Error::<T>::AmountTooLow);
+        ensure!(
+            TransferToggle::<T>::get(netuid),
+            Error::<T>::TransferDisallowed
+        );
+
+        let now = Self::get_current_block_as_u64();
+        let (hotkey, source_lock) = Self::read_rolled_lock(origin_coldkey, netuid, now)
+            .ok_or(Error::<T>::NoExistingLock)?;
+        ensure!(
+            source_lock.locked_mass >= amount,
+            Error::<T>::InsufficientLockedAlpha
+        );
+        ensure!(
+            Self::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, origin_coldkey, netuid)
+                >= amount,
+            Error::<T>::NotEnoughStakeToWithdraw
+        );
+        let destination_lock = match Self::read_rolled_lock(destination_coldkey, netuid, now) {
+            Some((destination_hotkey, lock)) => {
+                ensure!(destination_hotkey == hotkey, Error::<T>::LockHotkeyMismatch);
+                lock
+            }
+            None => Self::empty_lock(now),
+        };
+
+        let conviction = Self::move_locked_mass(
+            origin_coldkey,
+            destination_coldkey,
+            netuid,
+            (hotkey.clone(), source_lock),
+            (hotkey.clone(), destination_lock),
+            amount,
+            now,
+        )?;
+
+        // The lock has already moved, so only the now unlocked stake is left to transfer
+        Self::transfer_stake_within_subnet(
+            origin_coldkey,
+            &hotkey,
+            destination_coldkey,
+            &hotkey,
+            netuid,
+            amount,
+        )?;
+
+        Self::deposit_event(Event::LockTransferred {
+            origin_coldkey: origin_coldkey.clone(),
+            destination_coldkey: destination_coldkey.clone(),
+            hotkey,
+            netuid,
+            amount,
+            conviction,
+        });
+        Ok(())
+    }
+
+    /// Reads the coldkey lock on a subnet and its hotkey, rolled forward to `now`.
+    fn read_rolled_lock(
+        coldkey: &T::AccountId,
+        netuid: NetUid,
+        now: u64,
+    ) -> Option<(T::AccountId, LockState)> {
+        Self::read_conviction_model(coldkey, netuid, now).map(|(hotkey, mut model)| {
+            model.roll_forward(now, UnlockRate::<T>::get(), MaturityRate::<T>::get());
+            (hotkey, model.individual_lock().clone())
+        })
+    }
+
+    /// Moves `locked_transfer` of the rolled source lock, and the same share of its conviction,
+    /// to the rolled destination lock. Both locks are saved even if nothing is moved, and the
+    /// aggregates are updated. Returns the conviction moved.
+    fn move_locked_mass(
+        origin_coldkey: &T::AccountId,
+        destination_coldkey: &T::AccountId,
+        netuid: NetUid,
+        (source_hotkey, mut source_lock): (T::AccountId, LockState),
+        (destination_hotkey, mut destination_lock): (T::AccountId, LockState),
+        locked_transfer: AlphaBalance,
+        now: u64,
+    ) -> Result<U64F64, DispatchError> {
+        let unlock_rate = UnlockRate::<T>::get();
+        let maturity_rate = MaturityRate::<T>::get();
+
+        let conviction_transfer = if locked_transfer.is_zero() || source_lock.locked_mass.is_zero()
+        {
+            U64F64::saturating_from_num(0)
+        } else {
+            let locked_transfer = U64F64::saturating_from_num(locked_transfer.to_u64());
+            let source_locked = U64F64::saturating_from_num(source_lock.locked_mass.to_u64());
+            let transferred_proportion = locked_transfer.safe_div(source_locked);
+            source_lock
                 .conviction
-                .saturating_add(conviction_transfer);
-        }
+                .saturating_mul(transferred_proportion)
+        };
+
+        source_lock.locked_mass = source_lock.locked_mass.saturating_sub(locked_transfer);
+        source_lock.conviction = source_lock.conviction.saturating_sub(conviction_transfer);
+        destination_lock.locked_mass = destination_lock.locked_mass.saturating_add(locked_transfer);
+        destination_lock.conviction = destination_lock
+            .conviction
+            .saturating_add(conviction_transfer);
         Self::ensure_can_receive_locked_alpha(destination_coldkey, locked_transfer)?;
 
         source_lock = ConvictionModel::roll_forward_lock(
@@ -1916,7 +2100,7 @@ impl<T: Config> Pallet<T> {
         )
         .0;
 
-        // Upsert updated locks (only once per this fn) even if there were no updates because
+        // Upsert updated locks (only once per transfer) even if there were no updates because
         // of roll-forward
         Self::insert_lock_state(origin_coldkey, netuid, &source_hotkey, source_lock);
         Self::insert_lock_state(
@@ -1945,7 +2129,7 @@ impl<T: Config> Pallet<T> {
             );
         }
 
-        Ok(())
+        Ok(conviction_transfer)
     }
 
     /// Destroys all lock maps for network dissolution
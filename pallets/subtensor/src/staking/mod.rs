@@ -2,12 +2,15 @@ use super::*;
 pub mod account;
 pub mod add_stake;
 mod claim_root;
+pub mod cost_basis;
 pub mod decrease_take;
 pub mod helpers;
 pub mod increase_take;
 pub mod lock;
 pub mod move_stake;
 pub mod order_swap;
+pub mod rebalance;
+pub mod recurring_stake;
 pub mod recycle_alpha;
 pub mod remove_stake;
 pub mod set_children;
@@ -1,9 +1,18 @@
 use super::*;
 use safe_math::*;
 use sp_core::Get;
+use sp_runtime::Perbill;
 use substrate_fixed::types::U64F64;
 use subtensor_runtime_common::{AlphaBalance, NetUid, TaoBalance, Token};
-use subtensor_swap_interface::SwapHandler;
+use subtensor_swap_interface::{AlphaSwapResult, Order, SwapHandler, SwapResult};
+
+/// Slippage protection of a stake move between subnets.
+enum StakeMoveLimit {
+    /// Limit price between the origin and destination subnet tokens.
+    Price(TaoBalance),
+    /// Minimum amount of destination alpha received for the whole amount.
+    MinAlphaOut(AlphaBalance),
+}
 
 impl<T: Config> Pallet<T> {
     /// Moves stake from one hotkey to another across subnets.
@@ -255,6 +264,64 @@ impl<T: Config> Pallet<T> {
         alpha_amount: AlphaBalance,
         limit_price: TaoBalance,
         allow_partial: bool,
+    ) -> dispatch::DispatchResult {
+        Self::do_swap_stake_with_limit(
+            origin,
+            hotkey,
+            origin_netuid,
+            destination_netuid,
+            alpha_amount,
+            StakeMoveLimit::Price(limit_price),
+            allow_partial,
+        )
+    }
+
+    /// Swaps stake like `do_swap_stake_limit`, protected by the minimum amount of destination
+    /// alpha received instead of a limit price.
+    ///
+    /// # Arguments
+    /// * `origin` - The origin of the transaction, which must be signed by the coldkey that owns the hotkey.
+    /// * `hotkey` - The hotkey whose stake is being swapped.
+    /// * `origin_netuid` - The subnet ID from which stake is removed.
+    /// * `destination_netuid` - The subnet ID to which stake is added.
+    /// * `alpha_amount` - The amount of stake to swap.
+    /// * `min_alpha_out` - The minimum destination alpha received for the whole `alpha_amount`.
+    /// * `allow_partial` - Allow partial execution at the same average rate
+    ///
+    /// # Errors
+    /// Returns the errors of `do_swap_stake_limit`, and an error if `allow_partial` is false
+    /// and the swap would return less than `min_alpha_out`.
+    ///
+    /// # Events
+    /// Emits a `StakeSwapped` event upon successful completion.
+    pub fn do_swap_stake_min_out(
+        origin: OriginFor<T>,
+        hotkey: T::AccountId,
+        origin_netuid: NetUid,
+        destination_netuid: NetUid,
+        alpha_amount: AlphaBalance,
+        min_alpha_out: AlphaBalance,
+        allow_partial: bool,
+    ) -> dispatch::DispatchResult {
+        Self::do_swap_stake_with_limit(
+            origin,
+            hotkey,
+            origin_netuid,
+            destination_netuid,
+            alpha_amount,
+            StakeMoveLimit::MinAlphaOut(min_alpha_out),
+            allow_partial,
+        )
+    }
+
+    fn do_swap_stake_with_limit(
+        origin: OriginFor<T>,
+        hotkey: T::AccountId,
+        origin_netuid: NetUid,
+        destination_netuid: NetUid,
+        alpha_amount: AlphaBalance,
+        limit: StakeMoveLimit,
+        allow_partial: bool,
     ) -> dispatch::DispatchResult {
         // Ensure the extrinsic is signed by the coldkey.
         let coldkey = ensure_signed(origin)?;
@@ -268,7 +335,7 @@ impl<T: Config> Pallet<T> {
             origin_netuid,
             destination_netuid,
             alpha_amount,
-            Some(limit_price),
+            Some(limit),
             Some(allow_partial),
             false,
         )?;
@@ -289,8 +356,9 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
-    // If limit_price is None, this is a regular operation, otherwise, it is slippage-protected
-    // by setting limit price between origin_netuid and destination_netuid token
+    // If limit is None, this is a regular operation, otherwise, it is slippage-protected
+    // by the limit price between origin_netuid and destination_netuid token, or by the
+    // minimum amount of destination_netuid token received for alpha_amount
     fn transition_stake_internal(
         origin_coldkey: &T::AccountId,
         destination_coldkey: &T::AccountId,
@@ -299,7 +367,7 @@ impl<T: Config> Pallet<T> {
         origin_netuid: NetUid,
         destination_netuid: NetUid,
         alpha_amount: AlphaBalance,
-        maybe_limit_price: Option<TaoBalance>,
+        maybe_limit: Option<StakeMoveLimit>,
         maybe_allow_partial: Option<bool>,
         check_transfer_toggle: bool,
     ) -> Result<TaoBalance, DispatchError> {
@@ -314,10 +382,17 @@ impl<T: Config> Pallet<T> {
 
         // Calculate the maximum amount that can be executed
         let max_amount = if origin_netuid != destination_netuid {
-            if let Some(limit_price) = maybe_limit_price {
-                Self::get_max_amount_move(origin_netuid, destination_netuid, limit_price)?
-            } else {
-                alpha_amount
+            match maybe_limit {
+                Some(StakeMoveLimit::Price(limit_price)) => {
+                    Self::get_max_amount_move(origin_netuid, destination_netuid, limit_price)?
+                }
+                Some(StakeMoveLimit::MinAlphaOut(min_alpha_out)) => Self::get_max_amount_swap(
+                    origin_netuid,
+                    destination_netuid,
+                    alpha_amount,
+                    min_alpha_out,
+                ),
+                None => alpha_amount,
             }
         } else {
             alpha_amount
@@ -397,6 +472,166 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Simulates moving `alpha_amount` of origin alpha to the destination subnet the way
+    /// `swap_stake` executes it: the alpha is sold for TAO on the origin subnet and the TAO
+    /// is staked on the destination subnet. Nothing is written to storage.
+    ///
+    /// Only one leg is charged a swap fee: the origin leg, or the destination leg when
+    /// moving out of root. As in `swap_stake`, TAO below `DefaultMinStake` is not restaked
+    /// and no destination alpha is received.
+    pub fn sim_swap_alpha_for_alpha(
+        origin_netuid: NetUid,
+        destination_netuid: NetUid,
+        alpha_amount: AlphaBalance,
+    ) -> Result<AlphaSwapResult, DispatchError> {
+        let (sell, buy) =
+            Self::sim_alpha_for_alpha_legs(origin_netuid, destination_netuid, alpha_amount)?;
+        let alpha_out = if sell.amount_paid_out >= DefaultMinStake::<T>::get() {
+            buy.amount_paid_out
+        } else {
+            AlphaBalance::ZERO
+        };
+
+        // Destination alpha the origin alpha is worth at the current prices
+        let spot_alpha_out = U64F64::saturating_from_num(alpha_amount)
+            .saturating_mul(T::SwapInterface::current_alpha_price(origin_netuid.into()))
+            .safe_div(T::SwapInterface::current_alpha_price(
+                destination_netuid.into(),
+            ))
+            .saturating_to_num::<u64>();
+
+        Ok(AlphaSwapResult {
+            alpha_in: sell.amount_paid_in.saturating_add(sell.fee_paid),
+            tao_amount: sell.amount_paid_out,
+            alpha_out,
+            origin_fee: sell.fee_paid,
+            destination_fee: buy.fee_paid,
+            price_impact: Perbill::from_rational(
+                spot_alpha_out.saturating_sub(alpha_out.to_u64()),
+                spot_alpha_out.max(1),
+            ),
+        })
+    }
+
+    /// Simulates `swap_stake_min_out`: the result for the part of `alpha_amount` that would be
+    /// moved with `min_alpha_out` received for the whole amount.
+    pub fn sim_swap_alpha_for_alpha_limit(
+        origin_netuid: NetUid,
+        destination_netuid: NetUid,
+        alpha_amount: AlphaBalance,
+        min_alpha_out: AlphaBalance,
+        allow_partial: bool,
+    ) -> Result<AlphaSwapResult, DispatchError> {
+        let amount = Self::get_max_amount_swap(
+            origin_netuid,
+            destination_netuid,
+            alpha_amount,
+            min_alpha_out,
+        );
+        ensure!(
+            allow_partial || amount >= alpha_amount,
+            Error::<T>::SlippageTooHigh
+        );
+
+        Self::sim_swap_alpha_for_alpha(origin_netuid, destination_netuid, amount)
+    }
+
+    /// Returns the largest amount of origin alpha, up to `alpha_amount`, that can be moved
+    /// to the destination subnet while receiving `min_alpha_out` pro rata, that is at an
+    /// average rate of at least `min_alpha_out / alpha_amount`.
+    ///
+    /// The average rate only falls as the amount grows, so the amount is found by bisection
+    /// over simulated swaps. Amounts that cannot be swapped count as below the rate.
+    pub fn get_max_amount_swap(
+        origin_netuid: NetUid,
+        destination_netuid: NetUid,
+        alpha_amount: AlphaBalance,
+        min_alpha_out: AlphaBalance,
+    ) -> AlphaBalance {
+        let meets_rate = |amount: AlphaBalance| {
+            Self::sim_alpha_for_alpha_legs(origin_netuid, destination_netuid, amount).is_ok_and(
+                |(_, buy)| {
+                    u128::from(buy.amount_paid_out.to_u64())
+                        .saturating_mul(alpha_amount.to_u64().into())
+                        >= u128::from(min_alpha_out.to_u64()).saturating_mul(amount.to_u64().into())
+                },
+            )
+        };
+
+        if alpha_amount.is_zero() || meets_rate(alpha_amount) {
+            return alpha_amount;
+        }
+
+        // `low` always meets the rate, `high` never does
+        let (mut low, mut high) = (0_u64, alpha_amount.to_u64());
+        while high.saturating_sub(low) > 1 {
+            let mid = low.saturating_add(high.saturating_sub(low).safe_div(2));
+            if meets_rate(mid.into()) {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+
+        low.into()
+    }
+
+    /// Simulates both legs of an alpha to alpha move with the fees `swap_stake` charges.
+    fn sim_alpha_for_alpha_legs(
+        origin_netuid: NetUid,
+        destination_netuid: NetUid,
+        alpha_amount: AlphaBalance,
+    ) -> Result<
+        (
+            SwapResult<AlphaBalance, TaoBalance>,
+            SwapResult<TaoBalance, AlphaBalance>,
+        ),
+        DispatchError,
+    > {
+        let drop_fee_origin = origin_netuid == NetUid::ROOT;
+
+        let sell = if SubnetMechanism::<T>::get(origin_netuid) == 1 {
+            let order = GetTaoForAlpha::<T>::with_amount(alpha_amount);
+            T::SwapInterface::swap(
+                origin_netuid.into(),
+                order,
+                T::SwapInterface::min_price(),
+                drop_fee_origin,
+                true,
+            )?
+        } else {
+            SwapResult {
+                amount_paid_in: alpha_amount,
+                amount_paid_out: alpha_amount.to_u64().into(),
+                fee_paid: AlphaBalance::ZERO,
+                fee_to_block_author: AlphaBalance::ZERO,
+                fee_to_liquidity_providers: AlphaBalance::ZERO,
+            }
+        };
+
+        let tao = sell.amount_paid_out;
+        let buy = if SubnetMechanism::<T>::get(destination_netuid) == 1 {
+            let order = GetAlphaForTao::<T>::with_amount(tao);
+            T::SwapInterface::swap(
+                destination_netuid.into(),
+                order,
+                T::SwapInterface::max_price(),
+                !drop_fee_origin,
+                true,
+            )?
+        } else {
+            SwapResult {
+                amount_paid_in: tao,
+                amount_paid_out: tao.to_u64().into(),
+                fee_paid: TaoBalance::ZERO,
+                fee_to_block_author: TaoBalance::ZERO,
+                fee_to_liquidity_providers: TaoBalance::ZERO,
+            }
+        };
+
+        Ok((sell, buy))
+    }
+
     /// Returns the maximum amount of origin netuid Alpha that can be executed before we cross
     /// limit_price.
     ///
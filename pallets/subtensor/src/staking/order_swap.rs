@@ -155,6 +155,7 @@ impl<T: Config> OrderSwapInterface<T::AccountId> for Pallet<T> {
         Self::increase_stake_for_hotkey_and_coldkey_on_subnet(
             to_hotkey, to_coldkey, netuid, amount,
         );
+        Self::record_cost_basis_transfer(from_coldkey, to_coldkey, netuid, amount);
         LastColdkeyHotkeyStakeBlock::<T>::insert(
             to_coldkey,
             to_hotkey,
@@ -0,0 +1,301 @@
+use super::*;
+use codec::{Decode, DecodeWithMemTracking, Encode};
+use safe_math::*;
+use scale_info::TypeInfo;
+use sp_runtime::Perbill;
+use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
+use substrate_fixed::types::U64F64;
+use subtensor_runtime_common::NetUid;
+use subtensor_swap_interface::SwapHandler;
+
+/// Maximum number of positions in one rebalance.
+pub const MAX_REBALANCE_TARGETS: usize = 32;
+
+/// A trade or transfer executed by `rebalance_portfolio`.
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub enum RebalanceLeg<AccountId> {
+    /// Alpha moved between two hotkeys of the same subnet, without a swap.
+    Move {
+        netuid: NetUid,
+        from_hotkey: AccountId,
+        to_hotkey: AccountId,
+        alpha: AlphaBalance,
+    },
+    /// Alpha sold for TAO.
+    Sell {
+        netuid: NetUid,
+        hotkey: AccountId,
+        alpha: AlphaBalance,
+        tao: TaoBalance,
+    },
+    /// TAO from the sells swapped for alpha.
+    Buy {
+        netuid: NetUid,
+        hotkey: AccountId,
+        tao: TaoBalance,
+        alpha: AlphaBalance,
+    },
+}
+
+/// A position of the rebalanced portfolio, valued at the price before the rebalance.
+struct RebalancePosition<AccountId> {
+    netuid: NetUid,
+    hotkey: AccountId,
+    price: U64F64,
+    alpha: u64,
+    target_alpha: u64,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Rebalances the stake of `coldkey` over the positions in `targets` so that each position
+    /// holds its weight of the total value of these positions.
+    ///
+    /// Positions are valued at the current price. On each subnet, alpha is first moved between
+    /// hotkeys of the coldkey without a swap, and only the net difference is sold or bought. All
+    /// sells run before the buys, and the buys are paid by the sells only: weights adding up to
+    /// less than 100% leave the rest as free balance. Legs worth less than the minimum stake are
+    /// left out.
+    ///
+    /// Each swap is limited to `max_slippage` from the price before the rebalance. The whole
+    /// rebalance fails with `SlippageTooHigh` if any leg would go past it.
+    pub fn do_rebalance_portfolio(
+        coldkey: T::AccountId,
+        targets: Vec<(NetUid, T::AccountId, Perbill)>,
+        max_slippage: Perbill,
+    ) -> DispatchResult {
+        ensure!(
+            !targets.is_empty() && targets.len() <= MAX_REBALANCE_TARGETS,
+            Error::<T>::InvalidRebalanceTargets
+        );
+        let mut seen = BTreeSet::new();
+        let mut weight_sum: u64 = 0;
+        for (netuid, hotkey, weight) in targets.iter() {
+            ensure!(
+                seen.insert((*netuid, hotkey.clone())),
+                Error::<T>::InvalidRebalanceTargets
+            );
+            weight_sum = weight_sum.saturating_add(u64::from(weight.deconstruct()));
+            ensure!(Self::if_subnet_exist(*netuid), Error::<T>::SubnetNotExists);
+            Self::ensure_subtoken_enabled(*netuid)?;
+            ensure!(
+                Self::hotkey_account_exists(hotkey),
+                Error::<T>::HotKeyAccountNotExists
+            );
+        }
+        ensure!(
+            weight_sum <= u64::from(Perbill::one().deconstruct()),
+            Error::<T>::InvalidRebalanceTargets
+        );
+
+        // Value the positions at the current prices.
+        let mut positions = Vec::with_capacity(targets.len());
+        let mut total_value: u64 = 0;
+        for (netuid, hotkey, _) in targets.iter() {
+            let price = T::SwapInterface::current_alpha_price((*netuid).into());
+            ensure!(
+                price > U64F64::saturating_from_num(0),
+                Error::<T>::InsufficientLiquidity
+            );
+            let alpha: u64 =
+                Self::get_stake_for_hotkey_and_coldkey_on_subnet(hotkey, &coldkey, *netuid).into();
+            total_value = total_value.saturating_add(Self::rebalance_value(alpha, price));
+            positions.push(RebalancePosition {
+                netuid: *netuid,
+                hotkey: hotkey.clone(),
+                price,
+                alpha,
+                target_alpha: 0,
+            });
+        }
+        for (position, (_, _, weight)) in positions.iter_mut().zip(targets.iter()) {
+            position.target_alpha = U64F64::saturating_from_num(weight.mul_floor(total_value))
+                .safe_div(position.price)
+                .saturating_to_num::<u64>();
+        }
+
+        let mut subnets: BTreeMap<NetUid, Vec<usize>> = BTreeMap::new();
+        for (index, position) in positions.iter().enumerate() {
+            subnets.entry(position.netuid).or_default().push(index);
+        }
+
+        let mut legs = Vec::new();
+        let mut proceeds = TaoBalance::ZERO;
+        let mut buys: Vec<(usize, u64)> = Vec::new();
+
+        for (netuid, indexes) in subnets {
+            let minimum = Self::rebalance_leg_minimum(netuid);
+            let mut excess: Vec<(usize, u64)> = Vec::new();
+            let mut deficit: Vec<(usize, u64)> = Vec::new();
+            for index in indexes {
+                let Some(position) = positions.get(index) else {
+                    continue;
+                };
+                if position.alpha > position.target_alpha {
+                    excess.push((index, position.alpha.saturating_sub(position.target_alpha)));
+                } else {
+                    deficit.push((index, position.target_alpha.saturating_sub(position.alpha)));
+                }
+            }
+
+            // Match the excess of some hotkeys with the deficit of others within the subnet.
+            let mut excess_iter = excess.iter_mut().peekable();
+            let mut deficit_iter = deficit.iter_mut().peekable();
+            while let (Some(from), Some(to)) = (excess_iter.peek_mut(), deficit_iter.peek_mut()) {
+                let alpha = from.1.min(to.1);
+                from.1 = from.1.saturating_sub(alpha);
+                to.1 = to.1.saturating_sub(alpha);
+                if let (Some(from_position), Some(to_position)) =
+                    (positions.get(from.0), positions.get(to.0))
+                    && Self::rebalance_value(alpha, from_position.price) >= minimum
+                {
+                    Self::transfer_stake_within_subnet(
+                        &coldkey,
+                        &from_position.hotkey,
+                        &coldkey,
+                        &to_position.hotkey,
+                        netuid,
+                        alpha.into(),
+                    )?;
+                    legs.push(RebalanceLeg::Move {
+                        netuid,
+                        from_hotkey: from_position.hotkey.clone(),
+                        to_hotkey: to_position.hotkey.clone(),
+                        alpha: alpha.into(),
+                    });
+                }
+                if from.1 == 0 {
+                    excess_iter.next();
+                }
+                if to.1 == 0 {
+                    deficit_iter.next();
+                }
+            }
+
+            // Sell the net excess of the subnet.
+            for (index, alpha) in excess {
+                let Some(position) = positions.get(index) else {
+                    continue;
+                };
+                if Self::rebalance_value(alpha, position.price) < minimum {
+                    continue;
+                }
+                let price_limit = Self::rebalance_price_limit(position.price, max_slippage, false);
+                let max_amount = Self::get_max_amount_remove(netuid, price_limit)?;
+                Self::validate_remove_stake(
+                    &coldkey,
+                    &position.hotkey,
+                    netuid,
+                    alpha.into(),
+                    max_amount,
+                    false,
+                )?;
+                let tao = Self::unstake_from_subnet(
+                    &position.hotkey,
+                    &coldkey,
+                    &coldkey,
+                    netuid,
+                    alpha.into(),
+                    price_limit,
+                    false,
+                )?;
+                proceeds = proceeds.saturating_add(tao);
+                legs.push(RebalanceLeg::Sell {
+                    netuid,
+                    hotkey: position.hotkey.clone(),
+                    alpha: alpha.into(),
+                    tao,
+                });
+            }
+
+            // Buy the net deficit of the subnet once all sells have run.
+            for (index, alpha) in deficit {
+                if let Some(position) = positions.get(index) {
+                    buys.push((index, Self::rebalance_value(alpha, position.price)));
+                }
+            }
+        }
+
+        // Fees and price impact of the sells shrink the buys pro rata.
+        let planned = buys
+            .iter()
+            .fold(0_u64, |total, (_, tao)| total.saturating_add(*tao));
+        for (index, tao) in buys {
+            let Some(position) = positions.get(index) else {
+                continue;
+            };
+            let tao: u64 = if planned > u64::from(proceeds) {
+                U64F64::saturating_from_num(tao)
+                    .saturating_mul(U64F64::saturating_from_num(proceeds))
+                    .safe_div(U64F64::saturating_from_num(planned))
+                    .saturating_to_num()
+            } else {
+                tao
+            };
+            if tao < Self::rebalance_leg_minimum(position.netuid) {
+                continue;
+            }
+            Self::ensure_add_stake_input_within_swap_limit(position.netuid, tao.into())?;
+            let price_limit = Self::rebalance_price_limit(position.price, max_slippage, true);
+            let max_amount = Self::get_max_amount_add(position.netuid, price_limit)?;
+            Self::validate_add_stake(
+                &coldkey,
+                &position.hotkey,
+                position.netuid,
+                tao.into(),
+                max_amount.into(),
+                false,
+            )?;
+            if Self::get_owning_coldkey_for_hotkey(&position.hotkey) != coldkey {
+                Self::maybe_become_delegate(&position.hotkey);
+            }
+            let alpha = Self::stake_into_subnet(
+                &position.hotkey,
+                &coldkey,
+                position.netuid,
+                tao.into(),
+                price_limit,
+                false,
+            )?;
+            legs.push(RebalanceLeg::Buy {
+                netuid: position.netuid,
+                hotkey: position.hotkey.clone(),
+                tao: tao.into(),
+                alpha,
+            });
+        }
+
+        Self::deposit_event(Event::PortfolioRebalanced { coldkey, legs });
+        Ok(())
+    }
+
+    fn rebalance_value(alpha: u64, price: U64F64) -> u64 {
+        price
+            .saturating_mul(U64F64::saturating_from_num(alpha))
+            .saturating_to_num()
+    }
+
+    /// Smallest TAO value of a leg, so that the swap fee cannot take it below the minimum stake.
+    fn rebalance_leg_minimum(netuid: NetUid) -> u64 {
+        let min_stake = DefaultMinStake::<T>::get();
+        min_stake
+            .saturating_add(T::SwapInterface::approx_fee_amount(
+                netuid.into(),
+                min_stake,
+            ))
+            .into()
+    }
+
+    /// Price limit, in rao per alpha, `max_slippage` above (`buy`) or below the price before the
+    /// rebalance.
+    fn rebalance_price_limit(price: U64F64, max_slippage: Perbill, buy: bool) -> TaoBalance {
+        let price_rao: u64 = price
+            .saturating_mul(U64F64::saturating_from_num(1_000_000_000_u64))
+            .saturating_to_num();
+        if buy {
+            price_rao.saturating_add(max_slippage.mul_floor(price_rao))
+        } else {
+            max_slippage.left_from_one().mul_floor(price_rao)
+        }
+        .into()
+    }
+}
@@ -0,0 +1,278 @@
+use super::*;
+use crate::weights::WeightInfo;
+use frame_support::storage::{TransactionOutcome, with_transaction};
+use frame_support::weights::Weight;
+use sp_runtime::traits::Dispatchable;
+use subtensor_runtime_common::NetUid;
+use subtensor_swap_interface::SwapHandler;
+
+impl<T: Config> Pallet<T> {
+    /// Registers a recurring stake of `amount_per_interval` TAO from `coldkey` to `hotkey` on
+    /// `netuid`, executed every `interval` blocks from the next block until `end_block`.
+    ///
+    /// Each execution dispatches `add_stake_limit` without partial fills from `coldkey`, with
+    /// `price_ceiling` as the limit price, so it passes the same dispatch checks as a signed
+    /// call. An execution that fails is skipped and the schedule keeps running.
+    pub fn do_schedule_recurring_stake(
+        coldkey: T::AccountId,
+        hotkey: T::AccountId,
+        netuid: NetUid,
+        amount_per_interval: TaoBalance,
+        interval: u64,
+        price_ceiling: Option<TaoBalance>,
+        end_block: u64,
+    ) -> DispatchResult {
+        let current_block = Self::get_current_block_as_u64();
+
+        ensure!(Self::if_subnet_exist(netuid), Error::<T>::SubnetNotExists);
+        ensure!(
+            Self::hotkey_account_exists(&hotkey),
+            Error::<T>::HotKeyAccountNotExists
+        );
+        ensure!(
+            amount_per_interval >= DefaultMinStake::<T>::get(),
+            Error::<T>::AmountTooLow
+        );
+        ensure!(interval > 0, Error::<T>::InvalidRecurringStakeInterval);
+        ensure!(end_block > current_block, Error::<T>::ScheduledBlockInPast);
+        ensure!(
+            RecurringStakes::<T>::iter_prefix(&coldkey).count()
+                < MAX_RECURRING_STAKES_PER_COLDKEY as usize,
+            Error::<T>::TooManyRecurringStakes
+        );
+
+        let id = NextRecurringStakeId::<T>::get();
+        NextRecurringStakeId::<T>::put(id.saturating_add(1));
+
+        let next_block = Self::queue_recurring_stake(&coldkey, id, current_block.saturating_add(1));
+        RecurringStakes::<T>::insert(
+            &coldkey,
+            id,
+            RecurringStake {
+                netuid,
+                hotkey: hotkey.clone(),
+                amount_per_interval,
+                interval,
+                price_ceiling,
+                end_block,
+                next_block,
+                registered_at: NetworkRegisteredAt::<T>::get(netuid),
+            },
+        );
+
+        // Due blocks are only walked from the block of the first schedule onwards.
+        if RecurringStakeCursor::<T>::get() == 0 {
+            RecurringStakeCursor::<T>::put(next_block);
+        }
+
+        Self::deposit_event(Event::RecurringStakeScheduled {
+            coldkey,
+            id,
+            netuid,
+            hotkey,
+            amount_per_interval,
+            interval,
+            end_block,
+        });
+        Ok(())
+    }
+
+    /// Cancels the recurring stake `id` of `coldkey`. Its due entry is skipped when reached.
+    pub fn do_cancel_recurring_stake(coldkey: T::AccountId, id: u64) -> DispatchResult {
+        ensure!(
+            RecurringStakes::<T>::take(&coldkey, id).is_some(),
+            Error::<T>::RecurringStakeNotFound
+        );
+        Self::deposit_event(Event::RecurringStakeCancelled { coldkey, id });
+        Ok(())
+    }
+
+    /// Executes the recurring stakes due up to block `now` within `remaining_weight`.
+    ///
+    /// Due blocks are walked from `RecurringStakeCursor`, so executions that did not fit in the
+    /// idle weight of a block are picked up by the following blocks.
+    pub fn execute_due_recurring_stakes(now: u64, remaining_weight: Weight) -> Weight {
+        let db_weight = T::DbWeight::get();
+        let mut weight_used = db_weight.reads_writes(1, 1);
+
+        let mut block = RecurringStakeCursor::<T>::get();
+        if block == 0 {
+            return weight_used;
+        }
+
+        // Read the schedule and the stake before and after staking, run every dispatch
+        // extension and dispatch `add_stake_limit`, then write the schedule and its next due
+        // entry.
+        let per_stake = T::WeightInfo::add_stake_limit()
+            .saturating_add(T::WeightInfo::check_coldkey_swap_extension())
+            .saturating_add(T::WeightInfo::check_weights_extension())
+            .saturating_add(T::WeightInfo::check_rate_limits_extension())
+            .saturating_add(T::WeightInfo::check_delegate_take_extension())
+            .saturating_add(T::WeightInfo::check_serving_endpoints_extension())
+            .saturating_add(T::WeightInfo::check_evm_key_association_extension())
+            .saturating_add(db_weight.reads_writes(8, 2));
+        let per_block = db_weight.reads_writes(1, 2);
+
+        while block <= now {
+            if weight_used
+                .saturating_add(per_block)
+                .saturating_add(per_stake)
+                .any_gt(remaining_weight)
+            {
+                break;
+            }
+            weight_used.saturating_accrue(per_block);
+
+            let due = RecurringStakesDue::<T>::take(block);
+            for (index, (coldkey, id)) in due.iter().enumerate() {
+                if weight_used
+                    .saturating_add(per_stake)
+                    .any_gt(remaining_weight)
+                {
+                    // Leave the rest of the block for the next idle run.
+                    let rest = due.get(index..).unwrap_or_default().to_vec();
+                    RecurringStakesDue::<T>::insert(block, BoundedVec::truncate_from(rest));
+                    RecurringStakeCursor::<T>::put(block);
+                    return weight_used;
+                }
+                weight_used.saturating_accrue(per_stake);
+                Self::execute_recurring_stake(coldkey.clone(), *id, block, now);
+            }
+
+            block = block.saturating_add(1);
+        }
+
+        RecurringStakeCursor::<T>::put(block);
+        weight_used
+    }
+
+    /// Executes the recurring stake `id` of `coldkey` due at `block`, then schedules its next
+    /// execution after `now`, or ends it.
+    fn execute_recurring_stake(coldkey: T::AccountId, id: u64, block: u64, now: u64) {
+        let Some(mut schedule) = RecurringStakes::<T>::get(&coldkey, id) else {
+            return;
+        };
+        if schedule.next_block != block {
+            return;
+        }
+
+        if !Self::if_subnet_exist(schedule.netuid)
+            || NetworkRegisteredAt::<T>::get(schedule.netuid) != schedule.registered_at
+        {
+            RecurringStakes::<T>::remove(&coldkey, id);
+            Self::deposit_event(Event::RecurringStakeEnded { coldkey, id });
+            return;
+        }
+
+        let limit_price = schedule
+            .price_ceiling
+            .unwrap_or_else(T::SwapInterface::max_price::<TaoBalance>);
+        // Dispatched as a call rather than through `do_add_stake_limit`, so that the dispatch
+        // extensions, such as the coldkey swap guard, apply as they do to a signed call.
+        let call: <T as Config>::RuntimeCall = Call::<T>::add_stake_limit {
+            hotkey: schedule.hotkey.clone(),
+            netuid: schedule.netuid,
+            amount_staked: schedule.amount_per_interval,
+            limit_price,
+            allow_partial: false,
+        }
+        .into();
+        let stake_before = Self::get_stake_for_hotkey_and_coldkey_on_subnet(
+            &schedule.hotkey,
+            &coldkey,
+            schedule.netuid,
+        );
+        let result = with_transaction(|| {
+            match call.dispatch(frame_system::RawOrigin::Signed(coldkey.clone()).into()) {
+                Ok(_) => TransactionOutcome::Commit(Ok(())),
+                Err(err) => TransactionOutcome::Rollback(Err(err.error)),
+            }
+        })
+        .map(|()| {
+            Self::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &schedule.hotkey,
+                &coldkey,
+                schedule.netuid,
+            )
+            .saturating_sub(stake_before)
+        });
+
+        match result {
+            Ok(alpha_staked) => Self::deposit_event(Event::RecurringStakeExecuted {
+                coldkey: coldkey.clone(),
+                id,
+                netuid: schedule.netuid,
+                hotkey: schedule.hotkey.clone(),
+                tao_staked: schedule.amount_per_interval,
+                alpha_staked,
+            }),
+            Err(reason) => {
+                log::debug!(
+                    "RecurringStakeSkipped( coldkey: {coldkey:?}, id: {id:?}, reason: {reason:?} )"
+                );
+                Self::deposit_event(Event::RecurringStakeSkipped {
+                    coldkey: coldkey.clone(),
+                    id,
+                    reason,
+                })
+            }
+        }
+
+        // Executions missed while idle weight was short are not made up for.
+        let missed = now
+            .saturating_sub(block)
+            .checked_div(schedule.interval)
+            .unwrap_or_default();
+        let next_block =
+            block.saturating_add(schedule.interval.saturating_mul(missed.saturating_add(1)));
+        if next_block > schedule.end_block {
+            RecurringStakes::<T>::remove(&coldkey, id);
+            Self::deposit_event(Event::RecurringStakeEnded { coldkey, id });
+            return;
+        }
+
+        schedule.next_block = Self::queue_recurring_stake(&coldkey, id, next_block);
+        RecurringStakes::<T>::insert(&coldkey, id, schedule);
+    }
+
+    /// Queues the recurring stake `id` of `coldkey` at `block`, or at the first later block
+    /// with fewer than `MAX_RECURRING_STAKES_DUE_PER_BLOCK` stakes due, and returns that block.
+    fn queue_recurring_stake(coldkey: &T::AccountId, id: u64, block: u64) -> u64 {
+        let mut block = block;
+        while RecurringStakesDue::<T>::try_append(block, (coldkey.clone(), id)).is_err() {
+            block = block.saturating_add(1);
+        }
+        block
+    }
+
+    /// Moves the recurring stakes of `old_coldkey` to `new_coldkey`, keeping their ids.
+    ///
+    /// `new_coldkey` keeps at most `MAX_RECURRING_STAKES_PER_COLDKEY` schedules: the ones it
+    /// already has, then those of `old_coldkey` by id. The remaining ones are ended.
+    pub fn transfer_recurring_stakes(old_coldkey: &T::AccountId, new_coldkey: &T::AccountId) {
+        let mut free = (MAX_RECURRING_STAKES_PER_COLDKEY as usize)
+            .saturating_sub(RecurringStakes::<T>::iter_prefix(new_coldkey).count());
+        let mut stakes: Vec<_> = RecurringStakes::<T>::drain_prefix(old_coldkey).collect();
+        stakes.sort_by_key(|(id, _)| *id);
+
+        for (id, mut schedule) in stakes {
+            if free == 0 {
+                Self::deposit_event(Event::RecurringStakeEnded {
+                    coldkey: old_coldkey.clone(),
+                    id,
+                });
+                continue;
+            }
+            free = free.saturating_sub(1);
+            schedule.next_block = Self::queue_recurring_stake(new_coldkey, id, schedule.next_block);
+            RecurringStakes::<T>::insert(new_coldkey, id, schedule);
+        }
+    }
+
+    /// Recurring stakes of a coldkey with their ids, ordered by id.
+    pub fn get_recurring_stakes(coldkey: T::AccountId) -> Vec<(u64, RecurringStake<T::AccountId>)> {
+        let mut stakes: Vec<_> = RecurringStakes::<T>::iter_prefix(&coldkey).collect();
+        stakes.sort_by_key(|(id, _)| *id);
+        stakes
+    }
+}
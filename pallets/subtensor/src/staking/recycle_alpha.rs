@@ -56,6 +56,7 @@ impl<T: Config> Pallet<T> {
 
         // Deduct from the coldkey's stake.
         Self::decrease_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid, amount);
+        Self::record_cost_basis_disposal(&coldkey, netuid, amount, TaoBalance::ZERO);
 
         // Recycle means we should decrease the alpha issuance tracker.
         Self::recycle_subnet_alpha(netuid, amount);
@@ -117,6 +118,7 @@ impl<T: Config> Pallet<T> {
 
         // Deduct from the coldkey's stake.
         Self::decrease_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid, amount);
+        Self::record_cost_basis_disposal(&coldkey, netuid, amount, TaoBalance::ZERO);
 
         Self::burn_subnet_alpha(netuid, amount);
 
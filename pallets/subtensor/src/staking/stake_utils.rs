@@ -653,6 +653,7 @@ impl<T: Config> Pallet<T> {
                 amount_paid_out: tao.to_u64().into(),
                 fee_paid: TaoBalance::ZERO,
                 fee_to_block_author: TaoBalance::ZERO,
+                fee_to_liquidity_providers: TaoBalance::ZERO,
             }
         };
 
@@ -705,6 +706,7 @@ impl<T: Config> Pallet<T> {
                 amount_paid_out: alpha.to_u64().into(),
                 fee_paid: AlphaBalance::ZERO,
                 fee_to_block_author: AlphaBalance::ZERO,
+                fee_to_liquidity_providers: AlphaBalance::ZERO,
             }
         };
 
@@ -714,9 +716,12 @@ impl<T: Config> Pallet<T> {
             *total = total.saturating_add(alpha_delta.into());
         });
 
-        // Decrease Alpha outstanding.
+        // Decrease Alpha outstanding. The liquidity provider share of the fee
+        // leaves circulation too, it is minted back as stake when claimed.
         SubnetAlphaOut::<T>::mutate(netuid, |total| {
-            *total = total.saturating_sub(alpha_delta.into());
+            *total = total
+                .saturating_sub(alpha_delta.into())
+                .saturating_sub(swap_result.fee_to_liquidity_providers);
         });
 
         // Decrease tao reserves.
@@ -768,6 +773,13 @@ impl<T: Config> Pallet<T> {
         // Transfer unstaked TAO from subnet account to the coldkey.
         Self::transfer_tao_from_subnet(netuid, beneficiary, swap_result.amount_paid_out.into())?;
 
+        Self::record_cost_basis_disposal(
+            coldkey,
+            netuid,
+            alpha.saturating_sub(refund),
+            swap_result.amount_paid_out.into(),
+        );
+
         // Swap (in a fee-less way) the block builder alpha fee
         let mut fee_outflow = 0_u64;
         let maybe_block_author_coldkey = T::AuthorshipProvider::author();
@@ -892,6 +904,8 @@ impl<T: Config> Pallet<T> {
             StakingHotkeys::<T>::insert(coldkey, staking_hotkeys.clone());
         }
 
+        Self::record_cost_basis_acquisition(coldkey, netuid, tao_staked);
+
         // Increase the balance of the block author
         let maybe_block_author_coldkey = T::AuthorshipProvider::author();
         if let Some(block_author_coldkey) = maybe_block_author_coldkey {
@@ -995,6 +1009,8 @@ impl<T: Config> Pallet<T> {
             );
         }
 
+        Self::record_cost_basis_transfer(origin_coldkey, destination_coldkey, netuid, alpha);
+
         // Calculate TAO equivalent based on current price (it is accurate because
         // there's no slippage in this move)
         let current_price =
@@ -5,7 +5,7 @@ use super::*;
 use crate::epoch::run_epoch::EpochTerms;
 use alloc::collections::BTreeMap;
 use safe_math::*;
-use substrate_fixed::types::U64F64;
+use substrate_fixed::types::{I32F32, U64F64};
 use subtensor_runtime_common::{AlphaBalance, MechId, NetUid, NetUidStorageIndex};
 
 pub type LeaseId = u32;
@@ -171,10 +171,17 @@ impl<T: Config> Pallet<T> {
 
                     // Cleanup Weights
                     let _ = Weights::<T>::clear_prefix(netuid_index, u32::MAX, None);
+                    let _ = FollowedWeightRows::<T>::clear_prefix(netuid_index, u32::MAX, None);
 
                     // Cleanup Incentive
                     Incentive::<T>::remove(netuid_index);
 
+                    // Cleanup EpochHistory
+                    Self::clear_epoch_history(netuid_index);
+
+                    // Cleanup MechanismHyperparamOverrides
+                    MechanismHyperparamOverrides::<T>::remove(netuid_index);
+
                     // Cleanup LastUpdate
                     LastUpdate::<T>::remove(netuid_index);
 
@@ -197,6 +204,92 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Replaces the hyperparameter overrides of a subnet mechanism
+    pub fn do_set_mechanism_hyperparams(
+        netuid: NetUid,
+        mecid: MechId,
+        hyperparams: MechanismHyperparams,
+    ) -> DispatchResult {
+        Self::ensure_mechanism_exists(netuid, mecid)?;
+
+        if let Some((alpha_low, alpha_high)) = hyperparams.alpha_values {
+            let min_alpha: u16 = u16::MAX.safe_div(40);
+            ensure!(alpha_high >= min_alpha, Error::<T>::AlphaHighTooLow);
+            ensure!(
+                alpha_low >= min_alpha && alpha_low <= alpha_high,
+                Error::<T>::AlphaLowOutOfRange
+            );
+        }
+        if let Some(reveal_period) = hyperparams.reveal_period {
+            ensure!(
+                reveal_period <= MAX_COMMIT_REVEAL_PEROIDS,
+                Error::<T>::RevealPeriodTooLarge
+            );
+            ensure!(
+                reveal_period >= MIN_COMMIT_REVEAL_PEROIDS,
+                Error::<T>::RevealPeriodTooSmall
+            );
+        }
+
+        let netuid_index = Self::get_mechanism_storage_index(netuid, mecid);
+        if hyperparams == MechanismHyperparams::default() {
+            MechanismHyperparamOverrides::<T>::remove(netuid_index);
+        } else {
+            MechanismHyperparamOverrides::<T>::insert(netuid_index, hyperparams);
+        }
+        Ok(())
+    }
+
+    pub fn get_mechanism_kappa(netuid_index: NetUidStorageIndex) -> u16 {
+        MechanismHyperparamOverrides::<T>::get(netuid_index)
+            .kappa
+            .unwrap_or_else(|| Self::get_kappa(Self::get_netuid(netuid_index)))
+    }
+
+    pub fn get_mechanism_bonds_moving_average(netuid_index: NetUidStorageIndex) -> u64 {
+        MechanismHyperparamOverrides::<T>::get(netuid_index)
+            .bonds_moving_average
+            .unwrap_or_else(|| Self::get_bonds_moving_average(Self::get_netuid(netuid_index)))
+    }
+
+    pub fn get_mechanism_max_weight_limit(netuid_index: NetUidStorageIndex) -> u16 {
+        MechanismHyperparamOverrides::<T>::get(netuid_index)
+            .max_weight_limit
+            .unwrap_or_else(|| Self::get_max_weight_limit(Self::get_netuid(netuid_index)))
+    }
+
+    pub fn get_mechanism_min_allowed_weights(netuid_index: NetUidStorageIndex) -> u16 {
+        MechanismHyperparamOverrides::<T>::get(netuid_index)
+            .min_allowed_weights
+            .unwrap_or_else(|| Self::get_min_allowed_weights(Self::get_netuid(netuid_index)))
+    }
+
+    pub fn get_mechanism_liquid_alpha_enabled(netuid_index: NetUidStorageIndex) -> bool {
+        MechanismHyperparamOverrides::<T>::get(netuid_index)
+            .liquid_alpha_enabled
+            .unwrap_or_else(|| Self::get_liquid_alpha_enabled(Self::get_netuid(netuid_index)))
+    }
+
+    pub fn get_mechanism_alpha_values(netuid_index: NetUidStorageIndex) -> (u16, u16) {
+        MechanismHyperparamOverrides::<T>::get(netuid_index)
+            .alpha_values
+            .unwrap_or_else(|| Self::get_alpha_values(Self::get_netuid(netuid_index)))
+    }
+
+    pub fn get_mechanism_alpha_values_32(netuid_index: NetUidStorageIndex) -> (I32F32, I32F32) {
+        let (alpha_low, alpha_high) = Self::get_mechanism_alpha_values(netuid_index);
+        (
+            I32F32::saturating_from_num(alpha_low).safe_div(I32F32::saturating_from_num(u16::MAX)),
+            I32F32::saturating_from_num(alpha_high).safe_div(I32F32::saturating_from_num(u16::MAX)),
+        )
+    }
+
+    pub fn get_mechanism_reveal_period(netuid_index: NetUidStorageIndex) -> u64 {
+        MechanismHyperparamOverrides::<T>::get(netuid_index)
+            .reveal_period
+            .unwrap_or_else(|| Self::get_reveal_period(Self::get_netuid(netuid_index)))
+    }
+
     pub fn do_set_emission_split(netuid: NetUid, maybe_split: Option<Vec<u16>>) -> DispatchResult {
         // Make sure the subnet exists
         ensure!(
@@ -361,6 +361,13 @@ impl<T: Config> Pallet<T> {
                 Uids::<T>::insert(netuid, hotkey, *new_uid as u16);
             }
 
+            // Weight rows were removed and remapped, followers restart from the current rows of
+            // their leaders
+            for mecid in 0..mechanisms_count {
+                let netuid_index = Self::get_mechanism_storage_index(netuid, mecid.into());
+                let _ = FollowedWeightRows::<T>::clear_prefix(netuid_index, u32::MAX, None);
+            }
+
             // Update the subnet's uid count to reflect the new maximum
             SubnetworkN::<T>::insert(netuid, max_n);
         }
@@ -1,6 +1,8 @@
 use super::*;
 use crate::epoch::math::*;
-use crate::{Error, MAX_COMMIT_REVEAL_PEROIDS, MIN_COMMIT_REVEAL_PEROIDS};
+use crate::{
+    Error, MAX_COMMIT_REVEAL_PEROIDS, MAX_WEIGHTS_FOLLOW_LAG_EPOCHS, MIN_COMMIT_REVEAL_PEROIDS,
+};
 use codec::Compact;
 use frame_support::dispatch::DispatchResult;
 use safe_math::*;
@@ -110,7 +112,7 @@ impl<T: Config> Pallet<T> {
 
             // 7. Remove any expired commits from the front of the queue.
             while let Some((_, commit_epoch_existing, _, _)) = commits.front() {
-                if Self::is_commit_expired(netuid, *commit_epoch_existing) {
+                if Self::is_commit_expired(netuid_index, *commit_epoch_existing) {
                     commits.pop_front();
                 } else {
                     break;
@@ -483,7 +485,7 @@ impl<T: Config> Pallet<T> {
                 // --- 4. Remove any expired commits from the front of the queue, collecting their hashes.
                 let mut expired_hashes = Vec::new();
                 while let Some((hash, commit_block, _, _)) = commits.front() {
-                    if Self::is_commit_expired(netuid, *commit_block) {
+                    if Self::is_commit_expired(netuid_index, *commit_block) {
                         // Collect the expired commit hash
                         expired_hashes.push(*hash);
                         commits.pop_front();
@@ -518,7 +520,7 @@ impl<T: Config> Pallet<T> {
 
                     // --- 9. Ensure the commit is ready to be revealed in the current block range.
                     ensure!(
-                        Self::is_reveal_block_range(netuid, *commit_block),
+                        Self::is_reveal_block_range(netuid_index, *commit_block),
                         Error::<T>::RevealTooEarly
                     );
 
@@ -645,7 +647,7 @@ impl<T: Config> Pallet<T> {
                 // --- 5. Remove any expired commits from the front of the queue, collecting their hashes.
                 let mut expired_hashes = Vec::new();
                 while let Some((hash, commit_block, _, _)) = commits.front() {
-                    if Self::is_commit_expired(netuid, *commit_block) {
+                    if Self::is_commit_expired(netuid_index, *commit_block) {
                         // Collect the expired commit hash
                         expired_hashes.push(*hash);
                         commits.pop_front();
@@ -700,7 +702,7 @@ impl<T: Config> Pallet<T> {
 
                     // --- 7d. Check if the commit is within the reveal window.
                     ensure!(
-                        Self::is_reveal_block_range(netuid, commit.1),
+                        Self::is_reveal_block_range(netuid_index, commit.1),
                         Error::<T>::RevealTooEarly
                     );
                 }
@@ -825,7 +827,7 @@ impl<T: Config> Pallet<T> {
 
         // --- 13. Ensure that the weights have the required length.
         ensure!(
-            Self::check_length(netuid, neuron_uid, &uids, &values),
+            Self::check_length(netuid, mecid, neuron_uid, &uids, &values),
             Error::<T>::WeightVecLengthIsLow
         );
 
@@ -834,7 +836,7 @@ impl<T: Config> Pallet<T> {
 
         // --- 15. Ensure the weights are max weight limited
         ensure!(
-            Self::max_weight_limited(netuid, neuron_uid, &uids, &max_upscaled_weights),
+            Self::max_weight_limited(netuid, mecid, neuron_uid, &uids, &max_upscaled_weights),
             Error::<T>::MaxWeightExceeded
         );
 
@@ -846,6 +848,7 @@ impl<T: Config> Pallet<T> {
 
         // --- 17. Set weights under netuid_index (sub-subnet), uid double map entry.
         Weights::<T>::insert(netuid_index, neuron_uid, zipped_weights);
+        Self::stop_following_weights(netuid, &hotkey);
 
         // --- 18. Set the activity for the weights on this network.
         if !Self::get_commit_reveal_weights_enabled(netuid) {
@@ -1171,9 +1174,17 @@ impl<T: Config> Pallet<T> {
         Self::get_validator_permit_for_uid(netuid, uid)
     }
     /// Returns True if the uids and weights are have a valid length for uid on network.
-    pub fn check_length(netuid: NetUid, uid: u16, uids: &[u16], weights: &[u16]) -> bool {
+    pub fn check_length(
+        netuid: NetUid,
+        mecid: MechId,
+        uid: u16,
+        uids: &[u16],
+        weights: &[u16],
+    ) -> bool {
+        let netuid_index = Self::get_mechanism_storage_index(netuid, mecid);
         let subnet_n: usize = Self::get_subnetwork_n(netuid) as usize;
-        let min_allowed_length: usize = Self::get_min_allowed_weights(netuid) as usize;
+        let min_allowed_length: usize =
+            Self::get_mechanism_min_allowed_weights(netuid_index) as usize;
         let min_allowed: usize = {
             if subnet_n < min_allowed_length {
                 subnet_n
@@ -1209,14 +1220,21 @@ impl<T: Config> Pallet<T> {
     }
 
     /// Returns False if the weights exceed the configured max weight limit for this network.
-    pub fn max_weight_limited(netuid: NetUid, uid: u16, uids: &[u16], weights: &[u16]) -> bool {
+    pub fn max_weight_limited(
+        netuid: NetUid,
+        mecid: MechId,
+        uid: u16,
+        uids: &[u16],
+        weights: &[u16],
+    ) -> bool {
         // Allow self weights to exceed max weight limit.
         if Self::is_self_weight(uid, uids, weights) {
             return true;
         }
 
-        // If the max weight limit it u16 max, return true (current constant).
-        let max_weight_limit: u16 = Self::get_max_weight_limit(netuid);
+        // If the max weight limit it u16 max, return true (subnet default).
+        let max_weight_limit: u16 =
+            Self::get_mechanism_max_weight_limit(Self::get_mechanism_storage_index(netuid, mecid));
         if max_weight_limit == u16::MAX {
             return true;
         }
@@ -1252,9 +1270,9 @@ impl<T: Config> Pallet<T> {
     /// `WeightCommits` tuple field 1). The current epoch uses the look-ahead value
     /// so a reveal submitted on a fire-block is judged against the about-to-fire
     /// epoch, consistent with how the commit was tagged.
-    pub fn is_reveal_block_range(netuid: NetUid, commit_epoch: u64) -> bool {
-        let current_epoch: u64 = Self::current_epoch_with_lookahead(netuid);
-        let reveal_period: u64 = Self::get_reveal_period(netuid);
+    pub fn is_reveal_block_range(netuid_index: NetUidStorageIndex, commit_epoch: u64) -> bool {
+        let current_epoch: u64 = Self::current_epoch_with_lookahead(Self::get_netuid(netuid_index));
+        let reveal_period: u64 = Self::get_mechanism_reveal_period(netuid_index);
 
         current_epoch == commit_epoch.saturating_add(reveal_period)
     }
@@ -1284,9 +1302,9 @@ impl<T: Config> Pallet<T> {
 
     /// True once the current epoch has moved past the commit's reveal epoch
     /// (`commit_epoch + reveal_period`). `commit_epoch` is the stored counter value.
-    pub fn is_commit_expired(netuid: NetUid, commit_epoch: u64) -> bool {
-        let current_epoch: u64 = Self::current_epoch_with_lookahead(netuid);
-        let reveal_period: u64 = Self::get_reveal_period(netuid);
+    pub fn is_commit_expired(netuid_index: NetUidStorageIndex, commit_epoch: u64) -> bool {
+        let current_epoch: u64 = Self::current_epoch_with_lookahead(Self::get_netuid(netuid_index));
+        let reveal_period: u64 = Self::get_mechanism_reveal_period(netuid_index);
 
         current_epoch > commit_epoch.saturating_add(reveal_period)
     }
@@ -1350,6 +1368,193 @@ impl<T: Config> Pallet<T> {
     pub fn is_batch_reveal_epoch_range(netuid: NetUid, commit_epochs: Vec<u64>) -> bool {
         commit_epochs
             .iter()
-            .all(|epoch| Self::is_reveal_block_range(netuid, *epoch))
+            .all(|epoch| Self::is_reveal_block_range(NetUidStorageIndex::from(netuid), *epoch))
+    }
+
+    /// ---- The implementation for the extrinsic follow_weights.
+    ///
+    /// # Args:
+    /// * `origin`: (`<T as frame_system::Config>::RuntimeOrigin`):
+    ///   - The signature of the following hotkey.
+    ///
+    /// * `netuid` (`NetUid`):
+    ///   - The subnet on which the weights are followed.
+    ///
+    /// * `leader` (`T::AccountId`):
+    ///   - The validator whose weights are followed.
+    ///
+    /// * `lag_epochs` (`u16`):
+    ///   - Number of epochs the followed row lags behind the leader's row.
+    ///
+    /// At each epoch of every mechanism, the weight row of the follower is replaced by the row
+    /// the leader had `lag_epochs` epochs before, with the leader's last update. Setting or
+    /// revealing weights stops following. With commit-reveal enabled, the lag must cover the
+    /// reveal period so that followers cannot copy a row before it is revealed to everyone.
+    ///
+    /// # Raises:
+    /// * `CanNotSetRootNetworkWeights`:
+    ///   - Attempting to follow weights on the root network.
+    ///
+    /// * `SubnetNotExists`:
+    ///   - The subnet does not exist.
+    ///
+    /// * `CannotFollowOwnWeights`:
+    ///   - The leader is the calling hotkey.
+    ///
+    /// * `WeightsFollowLagTooHigh`:
+    ///   - `lag_epochs` is above `MAX_WEIGHTS_FOLLOW_LAG_EPOCHS`.
+    ///
+    /// * `WeightsFollowLagBelowRevealPeriod`:
+    ///   - Commit-reveal is enabled and `lag_epochs` is below the reveal period of a mechanism.
+    ///
+    /// * `HotKeyNotRegisteredInSubNet`:
+    ///   - The follower or the leader is not registered on the subnet.
+    ///
+    /// * `NotEnoughStakeToSetWeights`:
+    ///   - The follower does not have enough stake to set weights.
+    ///
+    /// * `WeightsLeaderIsFollowing`:
+    ///   - The leader follows the weights of another validator.
+    ///
+    /// * `TooManyWeightsFollowers`:
+    ///   - `MAX_WEIGHTS_FOLLOWERS_PER_SUBNET` validators already follow weights on the subnet.
+    ///
+    pub fn do_follow_weights(
+        origin: OriginFor<T>,
+        netuid: NetUid,
+        leader: T::AccountId,
+        lag_epochs: u16,
+    ) -> DispatchResult {
+        let hotkey = ensure_signed(origin)?;
+
+        ensure!(!netuid.is_root(), Error::<T>::CanNotSetRootNetworkWeights);
+        ensure!(Self::if_subnet_exist(netuid), Error::<T>::SubnetNotExists);
+        ensure!(hotkey != leader, Error::<T>::CannotFollowOwnWeights);
+        ensure!(
+            lag_epochs <= MAX_WEIGHTS_FOLLOW_LAG_EPOCHS,
+            Error::<T>::WeightsFollowLagTooHigh
+        );
+        ensure!(
+            Self::is_weights_follow_lag_allowed(netuid, lag_epochs),
+            Error::<T>::WeightsFollowLagBelowRevealPeriod
+        );
+        ensure!(
+            Self::is_hotkey_registered_on_network(netuid, &hotkey)
+                && Self::is_hotkey_registered_on_network(netuid, &leader),
+            Error::<T>::HotKeyNotRegisteredInSubNet
+        );
+        ensure!(
+            Self::check_weights_min_stake(&hotkey, netuid),
+            Error::<T>::NotEnoughStakeToSetWeights
+        );
+        ensure!(
+            !WeightsLeader::<T>::contains_key(netuid, &leader),
+            Error::<T>::WeightsLeaderIsFollowing
+        );
+        ensure!(
+            WeightsLeader::<T>::contains_key(netuid, &hotkey)
+                || WeightsLeader::<T>::iter_key_prefix(netuid).count()
+                    < MAX_WEIGHTS_FOLLOWERS_PER_SUBNET as usize,
+            Error::<T>::TooManyWeightsFollowers
+        );
+
+        WeightsLeader::<T>::insert(netuid, &hotkey, (leader.clone(), lag_epochs));
+        Self::clear_followed_weight_rows(netuid, &hotkey);
+
+        Self::deposit_event(Event::WeightsFollowed {
+            netuid,
+            hotkey,
+            leader,
+            lag_epochs,
+        });
+        Ok(())
+    }
+
+    /// Stops `hotkey` from following the weights of another validator on `netuid`.
+    pub fn stop_following_weights(netuid: NetUid, hotkey: &T::AccountId) {
+        if WeightsLeader::<T>::contains_key(netuid, hotkey) {
+            WeightsLeader::<T>::remove(netuid, hotkey);
+            Self::clear_followed_weight_rows(netuid, hotkey);
+            Self::deposit_event(Event::WeightsUnfollowed {
+                netuid,
+                hotkey: hotkey.clone(),
+            });
+        }
+    }
+
+    /// Whether followed rows lagging `lag_epochs` behind the leader are revealed to everyone
+    /// by the time they are copied, on every mechanism of the subnet.
+    fn is_weights_follow_lag_allowed(netuid: NetUid, lag_epochs: u16) -> bool {
+        !Self::get_commit_reveal_weights_enabled(netuid)
+            || (0..u8::from(MechanismCountCurrent::<T>::get(netuid))).all(|mecid| {
+                let netuid_index = Self::get_mechanism_storage_index(netuid, mecid.into());
+                u64::from(lag_epochs) >= Self::get_mechanism_reveal_period(netuid_index)
+            })
+    }
+
+    fn clear_followed_weight_rows(netuid: NetUid, hotkey: &T::AccountId) {
+        for mecid in 0..u8::from(MechanismCountCurrent::<T>::get(netuid)) {
+            let netuid_index = Self::get_mechanism_storage_index(netuid, mecid.into());
+            FollowedWeightRows::<T>::remove(netuid_index, hotkey);
+        }
+    }
+
+    /// Writes the weight rows of the validators following a leader on a subnet mechanism.
+    ///
+    /// The leader's row is queued at every epoch, and the follower gets the row queued
+    /// `lag_epochs` epochs ago once the queue is long enough. Followers that are no longer
+    /// registered, or whose leader is not, stop following, as do followers whose lag fell below
+    /// the reveal period. Followers of a leader that started following itself keep their
+    /// current row.
+    pub(crate) fn apply_followed_weights(netuid: NetUid, mecid: MechId) {
+        let netuid_index = Self::get_mechanism_storage_index(netuid, mecid);
+        let follows: Vec<(T::AccountId, (T::AccountId, u16))> =
+            WeightsLeader::<T>::iter_prefix(netuid).collect();
+        if follows.is_empty() {
+            return;
+        }
+        let last_update = Self::get_last_update(netuid_index);
+
+        for (follower, (leader, lag_epochs)) in follows {
+            let (Ok(follower_uid), Ok(leader_uid)) = (
+                Self::get_uid_for_net_and_hotkey(netuid, &follower),
+                Self::get_uid_for_net_and_hotkey(netuid, &leader),
+            ) else {
+                Self::stop_following_weights(netuid, &follower);
+                continue;
+            };
+            if !Self::is_weights_follow_lag_allowed(netuid, lag_epochs) {
+                Self::stop_following_weights(netuid, &follower);
+                continue;
+            }
+            if WeightsLeader::<T>::contains_key(netuid, &leader) {
+                continue;
+            }
+
+            let mut rows = FollowedWeightRows::<T>::get(netuid_index, &follower).into_inner();
+            rows.push((
+                last_update
+                    .get(usize::from(leader_uid))
+                    .copied()
+                    .unwrap_or_default(),
+                Weights::<T>::get(netuid_index, leader_uid),
+            ));
+            let stale = rows
+                .len()
+                .saturating_sub(usize::from(lag_epochs).saturating_add(1));
+            rows.drain(..stale);
+
+            if rows.len() > usize::from(lag_epochs) {
+                if let Some((leader_update, row)) = rows.first() {
+                    Weights::<T>::insert(netuid_index, follower_uid, row.clone());
+                    Self::set_last_update_for_uid(netuid_index, follower_uid, *leader_update);
+                }
+            }
+            FollowedWeightRows::<T>::insert(
+                netuid_index,
+                &follower,
+                BoundedVec::truncate_from(rows),
+            );
+        }
     }
 }
@@ -33,6 +33,8 @@ impl<T: Config> Pallet<T> {
             Self::transfer_coldkey_stake(netuid, old_coldkey, new_coldkey);
         }
         Self::transfer_staking_hotkeys(old_coldkey, new_coldkey);
+        Self::transfer_recurring_stakes(old_coldkey, new_coldkey);
+        Self::transfer_cost_basis(old_coldkey, new_coldkey);
         Self::transfer_hotkeys_ownership(old_coldkey, new_coldkey)?;
 
         // Transfer stake locks
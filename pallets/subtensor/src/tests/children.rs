@@ -4683,3 +4683,171 @@ fn test_register_network_schedules_root_validators_auto_parent_delegation_flag()
         ));
     });
 }
+
+#[test]
+fn test_get_childkey_graph() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let parent = U256::from(2);
+        let child = U256::from(3);
+        let next_child = U256::from(4);
+        let netuid = NetUid::from(1);
+        let stake = 1_000_000_000_u64;
+        let emission = 1_000_000_u64;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, parent, coldkey, 0);
+        register_ok_neuron(netuid, child, U256::from(5), 1);
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &parent,
+            &coldkey,
+            netuid,
+            stake.into(),
+        );
+        mock_set_children(&coldkey, &parent, netuid, &[(u64::MAX / 2, child)]);
+        PendingChildKeys::<Test>::insert(netuid, parent, (vec![(u64::MAX, next_child)], 100));
+
+        // The child was paid validator dividends only in the last epoch.
+        CKBurn::<Test>::put(0);
+        SubtensorModule::set_tao_weight(0);
+        ChildkeyTake::<Test>::insert(child, netuid, u16::MAX / 10);
+        Emission::<Test>::insert(netuid, vec![AlphaBalance::ZERO, emission.into()]);
+        Dividends::<Test>::insert(netuid, vec![0, u16::MAX]);
+        Incentive::<Test>::insert(NetUidStorageIndex::from(netuid), vec![0, 0]);
+
+        assert!(SubtensorModule::get_childkey_graph(NetUid::from(99)).is_none());
+        let graph = SubtensorModule::get_childkey_graph(netuid).unwrap();
+
+        assert_eq!(graph.edges.len(), 1);
+        let edge = &graph.edges[0];
+        assert_eq!((edge.parent, edge.child), (parent, child));
+        close(
+            u64::from(AlphaBalance::from(edge.alpha)),
+            stake / 2,
+            1,
+            "half the parent stake is lent",
+        );
+
+        let node = |hotkey: U256| {
+            graph
+                .nodes
+                .iter()
+                .find(|node| node.hotkey == hotkey)
+                .unwrap()
+                .clone()
+        };
+        let parent_node = node(parent);
+        let child_node = node(child);
+        assert_eq!(u64::from(AlphaBalance::from(parent_node.own_alpha)), stake);
+        assert_eq!(parent_node.alpha_to_children, edge.alpha);
+        assert_eq!(child_node.alpha_from_parents, edge.alpha);
+        assert_eq!(u16::from(child_node.childkey_take), u16::MAX / 10);
+
+        // With no stake of its own, the child keeps its take and the rest flows to the parent.
+        assert_eq!(
+            u64::from(AlphaBalance::from(child_node.last_epoch_dividends)),
+            emission
+        );
+        let child_take = u64::from(AlphaBalance::from(edge.child_take));
+        close(child_take, emission / 10, 1, "child take");
+        close(
+            u64::from(AlphaBalance::from(child_node.retained_dividends)),
+            child_take,
+            1,
+            "child keeps its take",
+        );
+        close(
+            u64::from(AlphaBalance::from(edge.dividends)),
+            emission - child_take,
+            1,
+            "rest flows to the parent",
+        );
+        assert!(AlphaBalance::from(edge.burned).is_zero());
+
+        // Scheduled children are listed with their activation block.
+        assert_eq!(graph.pending.len(), 1);
+        assert_eq!(graph.pending[0].parent, parent);
+        assert_eq!(u64::from(graph.pending[0].activation_block), 100);
+        assert!(AlphaBalance::from(node(next_child).own_alpha).is_zero());
+    });
+}
+
+// Splitting out `split_parent_child_dividends` must not change what the coinbase pays and recycles.
+#[test]
+fn test_parent_child_dividends_distribution_with_ck_burn_and_several_parents() {
+    new_test_ext(1).execute_with(|| {
+        let child_coldkey = U256::from(10);
+        let parent_coldkeys = [U256::from(11), U256::from(12), child_coldkey];
+        let child = U256::from(1);
+        let parents = [U256::from(2), U256::from(3), U256::from(4)];
+        let netuid = NetUid::from(1);
+        let dividends = 1_000_000_000_u64;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, child, child_coldkey, 0);
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &child,
+            &child_coldkey,
+            netuid,
+            1_000_000_000_u64.into(),
+        );
+        // Parents with 6, 2 and 1 alpha lend all of it to the child; the last one shares its owner.
+        for (i, (parent, stake)) in parents
+            .iter()
+            .zip([6_000_000_000_u64, 2_000_000_000, 1_000_000_000])
+            .enumerate()
+        {
+            register_ok_neuron(netuid, *parent, parent_coldkeys[i], i as u64 + 1);
+            SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                parent,
+                &parent_coldkeys[i],
+                netuid,
+                stake.into(),
+            );
+            mock_set_children(&parent_coldkeys[i], parent, netuid, &[(u64::MAX, child)]);
+        }
+        assert_eq!(SubtensorModule::get_parents(&child, netuid).len(), 3);
+
+        // 10% burned and ~10% childkey take on the parents from other coldkeys.
+        CKBurn::<Test>::put(u64::MAX / 10);
+        ChildkeyTake::<Test>::insert(child, netuid, u16::MAX / 10);
+        SubtensorModule::set_tao_weight(0);
+        let alpha_out = AlphaBalance::from(100_000_000_000_u64);
+        SubnetAlphaOut::<Test>::insert(netuid, alpha_out);
+
+        let dividend_tuples = SubtensorModule::get_parent_child_dividends_distribution(
+            &child,
+            netuid,
+            dividends.into(),
+        );
+
+        // Contributions are 6:2:1 for the parents and 1 for the child itself.
+        let paid = |hotkey: U256| {
+            dividend_tuples
+                .iter()
+                .find(|(h, _)| *h == hotkey)
+                .map(|(_, d)| u64::from(*d))
+                .unwrap()
+        };
+        assert_eq!(dividend_tuples.len(), 4);
+        assert_eq!(dividend_tuples.last().unwrap().0, child);
+        close(paid(parents[0]), 480_003_662, 2, "first parent");
+        close(paid(parents[1]), 160_001_220, 2, "second parent");
+        close(paid(parents[2]), 100_000_000, 2, "parent of the same owner");
+        close(paid(child), 179_995_117, 2, "child");
+
+        // Only the burn on the parents from other coldkeys is recycled.
+        let recycled = u64::from(alpha_out - SubnetAlphaOut::<Test>::get(netuid));
+        close(recycled, 80_000_000, 2, "recycled");
+        close(
+            dividend_tuples
+                .iter()
+                .map(|(_, d)| u64::from(*d))
+                .sum::<u64>()
+                + recycled,
+            dividends,
+            4,
+            "nothing is lost",
+        );
+    });
+}
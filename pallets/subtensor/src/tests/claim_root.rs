@@ -5,9 +5,9 @@ use crate::tests::mock::*;
 use crate::{
     DefaultMinRootClaimAmount, Error, MAX_NUM_ROOT_CLAIMS, MAX_ROOT_CLAIM_THRESHOLD, NetworksAdded,
     NumRootClaim, NumStakingColdkeys, PendingRootAlphaDivs, RootClaimable, RootClaimableThreshold,
-    StakingColdkeys, StakingColdkeysByIndex, SubnetAlphaIn, SubnetAlphaOut, SubnetMechanism,
-    SubnetMovingPrice, SubnetProtocolFlow, SubnetRootSellTao, SubnetTAO, SubnetTaoFlow,
-    SubnetVolume, SubtokenEnabled, Tempo, TotalStake, pallet,
+    StakingColdkeys, StakingColdkeysByIndex, StakingHotkeys, SubnetAlphaIn, SubnetAlphaOut,
+    SubnetMechanism, SubnetMovingPrice, SubnetProtocolFlow, SubnetRootSellTao, SubnetTAO,
+    SubnetTaoFlow, SubnetVolume, SubtokenEnabled, Tempo, TotalStake, pallet,
 };
 use crate::{RootClaimType, RootClaimTypeEnum, RootClaimed};
 use approx::assert_abs_diff_eq;
@@ -16,8 +16,8 @@ use frame_support::pallet_prelude::Weight;
 use frame_support::traits::{Currency, Get};
 use frame_support::{assert_err, assert_noop, assert_ok};
 use sp_core::{H256, U256};
-use sp_runtime::DispatchError;
-use std::collections::BTreeSet;
+use sp_runtime::{DispatchError, Perbill};
+use std::collections::{BTreeMap, BTreeSet};
 use substrate_fixed::types::I96F32;
 use subtensor_runtime_common::{AlphaBalance, NetUid, TaoBalance, Token};
 use subtensor_swap_interface::SwapHandler;
@@ -2330,3 +2330,155 @@ fn ghsa_2026_012_staking_coldkey_index_never_decremented() {
         );
     });
 }
+
+#[test]
+fn test_claim_root_with_restake_claim_type() {
+    new_test_ext(1).execute_with(|| {
+        let owner_coldkey = U256::from(1001);
+        let hotkey = U256::from(1002);
+        let coldkey = U256::from(1003);
+        let target = U256::from(1004);
+        let netuid = add_dynamic_network(&hotkey, &owner_coldkey);
+        remove_owner_registration_stake(netuid);
+        let _ = SubtensorModule::create_account_if_non_existent(&coldkey, &target);
+
+        SubtensorModule::set_tao_weight(u64::MAX); // Set TAO weight to 1.0
+        SubnetTAO::<Test>::insert(netuid, TaoBalance::from(50_000_000_000_u64));
+        SubnetAlphaIn::<Test>::insert(netuid, AlphaBalance::from(100_000_000_000_u64));
+
+        let root_stake = 2_000_000u64;
+        mock_increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey,
+            &coldkey,
+            NetUid::ROOT,
+            root_stake.into(),
+        );
+        mock_increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey,
+            &owner_coldkey,
+            netuid,
+            10_000_000u64.into(),
+        );
+
+        // Targets must be existing hotkeys
+        assert_noop!(
+            SubtensorModule::set_root_claim_type(
+                RuntimeOrigin::signed(coldkey),
+                RootClaimTypeEnum::Restake {
+                    targets: BTreeMap::from([(netuid, U256::from(9999))]),
+                    max_slippage: Perbill::from_percent(5),
+                }
+            ),
+            Error::<Test>::HotKeyAccountNotExists
+        );
+        assert_noop!(
+            SubtensorModule::set_root_claim_type(
+                RuntimeOrigin::signed(coldkey),
+                RootClaimTypeEnum::Restake {
+                    targets: BTreeMap::new(),
+                    max_slippage: Perbill::from_percent(5),
+                }
+            ),
+            Error::<Test>::InvalidSubnetNumber
+        );
+
+        // Alpha of a subnet with a target is kept on the target hotkey
+        assert_ok!(SubtensorModule::set_root_claim_type(
+            RuntimeOrigin::signed(coldkey),
+            RootClaimTypeEnum::Restake {
+                targets: BTreeMap::from([(netuid, target)]),
+                max_slippage: Perbill::from_percent(5),
+            }
+        ));
+
+        let pending_root_alpha = 1_000_000u64;
+        SubtensorModule::distribute_emission(
+            netuid,
+            AlphaBalance::ZERO,
+            AlphaBalance::ZERO,
+            pending_root_alpha.into(),
+            AlphaBalance::ZERO,
+        );
+        assert_ok!(SubtensorModule::claim_root(
+            RuntimeOrigin::signed(coldkey),
+            BTreeSet::from([netuid])
+        ));
+
+        let validator_take_percent = 0.18f64;
+        let estimated_stake = (pending_root_alpha as f64) * (1f64 - validator_take_percent);
+        assert_abs_diff_eq!(
+            u64::from(SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &target, &coldkey, netuid,
+            )),
+            estimated_stake as u64,
+            epsilon = 100u64,
+        );
+        assert!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid)
+                .is_zero()
+        );
+        assert!(StakingHotkeys::<Test>::get(coldkey).contains(&target));
+
+        // Alpha of other subnets is swapped for TAO and staked on the root target
+        assert_ok!(SubtensorModule::set_root_claim_type(
+            RuntimeOrigin::signed(coldkey),
+            RootClaimTypeEnum::Restake {
+                targets: BTreeMap::from([(NetUid::ROOT, target)]),
+                max_slippage: Perbill::from_percent(50),
+            }
+        ));
+        SubtensorModule::distribute_emission(
+            netuid,
+            AlphaBalance::ZERO,
+            AlphaBalance::ZERO,
+            pending_root_alpha.into(),
+            AlphaBalance::ZERO,
+        );
+        assert_ok!(SubtensorModule::claim_root(
+            RuntimeOrigin::signed(coldkey),
+            BTreeSet::from([netuid])
+        ));
+        let target_root_stake = SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+            &target,
+            &coldkey,
+            NetUid::ROOT,
+        );
+        assert!(!target_root_stake.is_zero());
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &hotkey,
+                &coldkey,
+                NetUid::ROOT
+            ),
+            root_stake.into()
+        );
+
+        // A swap below the price limit fails and leaves the claim in place
+        assert_ok!(SubtensorModule::set_root_claim_type(
+            RuntimeOrigin::signed(coldkey),
+            RootClaimTypeEnum::Restake {
+                targets: BTreeMap::from([(NetUid::ROOT, target)]),
+                max_slippage: Perbill::zero(),
+            }
+        ));
+        SubtensorModule::distribute_emission(
+            netuid,
+            AlphaBalance::ZERO,
+            AlphaBalance::ZERO,
+            pending_root_alpha.into(),
+            AlphaBalance::ZERO,
+        );
+        assert!(
+            SubtensorModule::claim_root(RuntimeOrigin::signed(coldkey), BTreeSet::from([netuid]))
+                .is_err()
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &target,
+                &coldkey,
+                NetUid::ROOT
+            ),
+            target_root_stake
+        );
+    });
+}
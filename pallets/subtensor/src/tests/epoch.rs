@@ -12,7 +12,7 @@ use frame_support::{assert_err, assert_ok};
 use rand::{RngExt, SeedableRng, distr::Uniform, rngs::StdRng, seq::SliceRandom};
 use sp_core::{Get, U256};
 use substrate_fixed::types::I32F32;
-use subtensor_runtime_common::{AlphaBalance, NetUidStorageIndex, TaoBalance};
+use subtensor_runtime_common::{AlphaBalance, MechId, NetUidStorageIndex, TaoBalance};
 use subtensor_swap_interface::SwapHandler;
 
 use super::mock::*;
@@ -3573,6 +3573,232 @@ fn test_yuma_3_bonds_reset() {
     })
 }
 
+fn check_consensus_algorithm_epoch(
+    algorithm: ConsensusAlgorithm,
+    target_incentive: &[f32],
+    target_dividends: &[f32],
+) {
+    new_test_ext(1).execute_with(|| {
+        let n: u16 = 5; // 3 validators, 2 servers
+        let netuid = NetUid::from(1);
+        let stakes: Vec<u64> = vec![8, 1, 1, 0, 0];
+
+        setup_yuma_3_scenario(netuid, n, true, 8, stakes);
+        SubtensorModule::set_consensus_algorithm(netuid, algorithm);
+
+        // Validator A -> Server 2
+        // Validator B -> Server 1
+        // Validator C -> Server 1
+        set_yuma_3_weights(
+            netuid,
+            vec![vec![0, u16::MAX], vec![u16::MAX, 0], vec![u16::MAX, 0]],
+            vec![3, 4],
+        );
+        run_epoch(netuid, true);
+
+        let epsilon = I32F32::from_num(1e-3);
+        let incentive = SubtensorModule::get_incentive(netuid.into());
+        for (incentive, target) in incentive.iter().zip(target_incentive.iter()) {
+            assert_approx_eq(u16_proportion_to_fixed(*incentive), fixed(*target), epsilon);
+        }
+        let dividends = SubtensorModule::get_dividends(netuid);
+        for (dividend, target) in dividends.iter().zip(target_dividends.iter()) {
+            assert_approx_eq(u16_proportion_to_fixed(*dividend), fixed(*target), epsilon);
+        }
+
+        // Neither algorithm keeps bonds
+        for bond in SubtensorModule::get_bonds(netuid.into()) {
+            assert!(bond.iter().all(|b| *b == I32F32::from_num(0)));
+        }
+    });
+}
+
+#[test]
+fn test_median_clipped_consensus_without_bonds() {
+    // The big validator holds the median, so the weights of the small validators are clipped
+    check_consensus_algorithm_epoch(
+        ConsensusAlgorithm::MedianClipped,
+        &[0.0, 0.0, 0.0, 0.0, 1.0],
+        &[1.0, 0.0, 0.0, 0.0, 0.0],
+    );
+}
+
+#[test]
+fn test_stake_weighted_consensus_without_bonds() {
+    // Incentive is the stake-weighted average of weights, dividends follow stake
+    check_consensus_algorithm_epoch(
+        ConsensusAlgorithm::StakeWeighted,
+        &[0.0, 0.0, 0.0, 0.2, 0.8],
+        &[0.8, 0.1, 0.1, 0.0, 0.0],
+    );
+}
+
+#[test]
+fn test_simulate_epoch_matches_epoch_without_persisting() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let netuid_index = NetUidStorageIndex::from(netuid);
+        setup_yuma_3_scenario(netuid, 5, true, 8, vec![8, 1, 1, 0, 0]);
+        set_yuma_3_weights(
+            netuid,
+            vec![vec![0, u16::MAX], vec![u16::MAX, 0], vec![u16::MAX, 0]],
+            vec![3, 4],
+        );
+        next_block_no_epoch(netuid);
+
+        let incentive_before = SubtensorModule::get_incentive(netuid_index);
+        let dividends_before = SubtensorModule::get_dividends(netuid);
+        let bonds_before = SubtensorModule::get_bonds(netuid_index);
+
+        let simulation =
+            SubtensorModule::simulate_epoch(netuid, MechId::MAIN, None).expect("subnet exists");
+        assert_eq!(simulation.hotkeys.len(), 5);
+        assert_eq!(simulation.clipped_weights.len(), 5);
+
+        // Nothing is persisted
+        assert_eq!(
+            SubtensorModule::get_incentive(netuid_index),
+            incentive_before
+        );
+        assert_eq!(SubtensorModule::get_dividends(netuid), dividends_before);
+        assert_eq!(SubtensorModule::get_bonds(netuid_index), bonds_before);
+
+        // The epoch run at the same block persists what was simulated
+        SubtensorModule::epoch(netuid, 1_000_000_000.into());
+        let unpack =
+            |values: &[codec::Compact<u16>]| values.iter().map(|v| v.0).collect::<Vec<_>>();
+        assert_eq!(
+            unpack(&simulation.incentive),
+            SubtensorModule::get_incentive(netuid_index)
+        );
+        assert_eq!(
+            unpack(&simulation.dividends),
+            SubtensorModule::get_dividends(netuid)
+        );
+        assert_eq!(
+            unpack(&simulation.consensus),
+            SubtensorModule::get_consensus(netuid)
+        );
+
+        assert!(SubtensorModule::simulate_epoch(NetUid::from(99), MechId::MAIN, None).is_none());
+    });
+}
+
+#[test]
+fn test_simulate_epoch_with_override_weights() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let netuid_index = NetUidStorageIndex::from(netuid);
+        setup_yuma_3_scenario(netuid, 5, true, 8, vec![8, 1, 1, 0, 0]);
+        set_yuma_3_weights(netuid, vec![vec![u16::MAX, 0]; 3], vec![3, 4]);
+        next_block_no_epoch(netuid);
+
+        let weights_before = Weights::<Test>::get(netuid_index, 0);
+        let last_update_before = SubtensorModule::get_last_update(netuid_index);
+
+        let simulation = SubtensorModule::simulate_epoch(netuid, MechId::MAIN, None).unwrap();
+        assert_eq!(simulation.incentive[4].0, 0);
+
+        // The big validator moves its weight to the second server
+        let simulation = SubtensorModule::simulate_epoch(
+            netuid,
+            MechId::MAIN,
+            Some(vec![(0, vec![(4, u16::MAX)]), (42, vec![(3, u16::MAX)])]),
+        )
+        .unwrap();
+        assert_eq!(simulation.incentive[3].0, 0);
+        assert_eq!(simulation.incentive[4].0, u16::MAX);
+        assert!(simulation.clipped_weights[1].is_empty());
+
+        assert_eq!(Weights::<Test>::get(netuid_index, 0), weights_before);
+        assert_eq!(
+            SubtensorModule::get_last_update(netuid_index),
+            last_update_before
+        );
+    });
+}
+
+#[test]
+fn test_get_validator_agreement() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        setup_yuma_3_scenario(netuid, 5, true, 8, vec![8, 1, 1, 0, 0]);
+        SubtensorModule::set_bonds_penalty(netuid, u16::MAX / 2);
+        set_yuma_3_weights(
+            netuid,
+            vec![vec![0, u16::MAX], vec![u16::MAX, 0], vec![u16::MAX, 0]],
+            vec![3, 4],
+        );
+        next_block_no_epoch(netuid);
+
+        let report = SubtensorModule::get_validator_agreement(netuid, MechId::MAIN, u16::MAX - 100)
+            .expect("subnet exists");
+        assert_eq!(report.validators.len(), 3);
+
+        // The big validator sets consensus, the two small ones agree with each other only
+        let big = &report.validators[0];
+        assert_eq!(big.uid.0, 0);
+        assert!(big.consensus_similarity.0 > u16::MAX - 100);
+        assert_eq!(big.clipped_mass.0, 0);
+        for small in &report.validators[1..] {
+            assert_eq!(small.consensus_similarity.0, 0);
+            assert!(small.clipped_mass.0 > u16::MAX - 100);
+            assert!(small.bond_penalty.0.abs_diff(u16::MAX / 2) < 100);
+        }
+        assert_eq!(
+            report.clusters,
+            vec![vec![codec::Compact(1_u16), codec::Compact(2_u16)]]
+        );
+
+        assert!(
+            SubtensorModule::get_validator_agreement(NetUid::from(99), MechId::MAIN, 0).is_none()
+        );
+    });
+}
+
+#[test]
+fn test_epoch_history_is_bounded_and_ordered() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let netuid_index = NetUidStorageIndex::from(netuid);
+        setup_yuma_3_scenario(netuid, 5, true, 8, vec![8, 1, 1, 0, 0]);
+        set_yuma_3_weights(netuid, vec![vec![u16::MAX, 0]; 3], vec![3, 4]);
+        assert!(SubtensorModule::get_epoch_history(netuid, MechId::MAIN).is_empty());
+
+        let length = <Test as Config>::EpochHistoryLength::get();
+        let epochs = length + 5;
+        for _ in 0..epochs {
+            next_block_no_epoch(netuid);
+            SubtensorModule::epoch(netuid, 1_000_000_000.into());
+        }
+
+        let history = SubtensorModule::get_epoch_history(netuid, MechId::MAIN);
+        assert_eq!(history.len() as u32, length);
+        assert_eq!(EpochHistoryCount::<Test>::get(netuid_index), epochs as u64);
+        assert_eq!(
+            EpochHistory::<Test>::iter_prefix(netuid_index).count() as u32,
+            length
+        );
+        assert!(history.windows(2).all(|w| w[0].block < w[1].block));
+
+        // The newest record is the last persisted epoch
+        let last = history.last().unwrap();
+        assert_eq!(last.block, SubtensorModule::get_current_block_as_u64());
+        let hotkeys: Vec<U256> = (0..SubnetworkN::<Test>::get(netuid))
+            .map(|uid| Keys::<Test>::get(netuid, uid))
+            .collect();
+        assert_eq!(last.hotkeys, hotkeys);
+        assert_eq!(last.incentive, SubtensorModule::get_incentive(netuid_index));
+        assert_eq!(last.dividend, SubtensorModule::get_dividends(netuid));
+        assert_eq!(last.consensus, SubtensorModule::get_consensus(netuid));
+        assert_eq!(last.emission, SubtensorModule::get_emission(netuid));
+
+        SubtensorModule::clear_epoch_history(netuid_index);
+        assert!(SubtensorModule::get_epoch_history(netuid, MechId::MAIN).is_empty());
+        assert_eq!(EpochHistory::<Test>::iter_prefix(netuid_index).count(), 0);
+    });
+}
+
 #[test]
 fn test_liquid_alpha_equal_values_against_itself() {
     new_test_ext(1).execute_with(|| {
@@ -16,7 +16,7 @@ use subtensor_runtime_common::{AlphaBalance, NetUidStorageIndex, TaoBalance};
 use subtensor_swap_interface::SwapHandler;
 
 use super::mock::*;
-use crate::staking::lock::{ConvictionModel, LockState};
+use crate::staking::lock::{ConvictionModel, LockAction, LockState};
 use crate::*;
 
 // ---------------------------------------------------------------------------
@@ -845,6 +845,106 @@ fn test_get_coldkey_lock_no_lock() {
     });
 }
 
+#[test]
+fn test_project_coldkey_lock_matches_rolled_lock() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let netuid = setup_subnet_with_stake(coldkey, hotkey, 100_000_000_000);
+        UnlockRate::<Test>::put(2_000u64);
+        MaturityRate::<Test>::put(3_000u64);
+
+        assert_ok!(SubtensorModule::do_lock_stake(
+            &coldkey,
+            netuid,
+            &hotkey,
+            5_000u64.into(),
+        ));
+        let now = SubtensorModule::get_current_block_as_u64();
+        let total = SubtensorModule::total_coldkey_alpha_on_subnet(&coldkey, netuid);
+
+        let projections = SubtensorModule::project_coldkey_lock(
+            &coldkey,
+            netuid,
+            vec![0, now + 1_000, now + 5_000],
+            None,
+        )
+        .unwrap();
+
+        // Past blocks are projected to the current block
+        assert_eq!(projections[0].block, now);
+        assert_eq!(projections[0].locked_mass, 5_000u64.into());
+        assert!(projections[1].locked_mass < projections[0].locked_mass);
+        assert!(projections[2].locked_mass < projections[1].locked_mass);
+        for projection in &projections {
+            assert_eq!(
+                projection.available_to_unstake,
+                total.saturating_sub(projection.locked_mass)
+            );
+        }
+
+        System::set_block_number(now + 1_000);
+        let rolled =
+            SubtensorModule::get_coldkey_lock(&coldkey, netuid).expect("coldkey lock should exist");
+        assert_eq!(projections[1].locked_mass, rolled.locked_mass);
+        assert_eq!(projections[1].conviction, rolled.conviction);
+    });
+}
+
+#[test]
+fn test_project_coldkey_lock_simulates_actions() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let other_hotkey = U256::from(3);
+        let netuid = setup_subnet_with_stake(coldkey, hotkey, 100_000_000_000);
+        register_ok_neuron(netuid, other_hotkey, U256::from(4), 0);
+        let now = SubtensorModule::get_current_block_as_u64();
+        let amount: AlphaBalance = 5_000u64.into();
+
+        // No lock yet: moving fails, locking is simulated without being persisted
+        assert_noop!(
+            SubtensorModule::project_coldkey_lock(
+                &coldkey,
+                netuid,
+                vec![now],
+                Some(LockAction::MoveLock {
+                    destination_hotkey: other_hotkey
+                }),
+            ),
+            Error::<Test>::NoExistingLock
+        );
+        let projections = SubtensorModule::project_coldkey_lock(
+            &coldkey,
+            netuid,
+            vec![now, now + 1_000],
+            Some(LockAction::LockStake { hotkey, amount }),
+        )
+        .unwrap();
+        assert_eq!(projections[0].locked_mass, amount);
+        assert_eq!(projections[0].conviction, U64F64::from_num(0));
+        assert!(projections[1].conviction > U64F64::from_num(0));
+        assert!(SubtensorModule::get_coldkey_lock(&coldkey, netuid).is_none());
+
+        // Moving to a hotkey of another coldkey resets the conviction
+        assert_ok!(SubtensorModule::do_lock_stake(
+            &coldkey, netuid, &hotkey, amount
+        ));
+        System::set_block_number(now + 1_000);
+        let projections = SubtensorModule::project_coldkey_lock(
+            &coldkey,
+            netuid,
+            vec![now + 1_000],
+            Some(LockAction::MoveLock {
+                destination_hotkey: other_hotkey,
+            }),
+        )
+        .unwrap();
+        assert_eq!(projections[0].conviction, U64F64::from_num(0));
+        assert!(Lock::<Test>::contains_key((coldkey, netuid, hotkey)));
+    });
+}
+
 #[test]
 fn test_available_to_unstake_no_lock() {
     new_test_ext(1).execute_with(|| {
@@ -2143,6 +2243,38 @@ fn test_unstake_blocked_by_lock() {
     });
 }
 
+#[test]
+fn test_locked_alpha_cannot_fund_liquidity() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let netuid = setup_subnet_with_stake(coldkey, hotkey, 100_000_000_000);
+
+        let total = SubtensorModule::total_coldkey_alpha_on_subnet(&coldkey, netuid);
+        let locked = total / 2.into();
+        assert_ok!(SubtensorModule::do_lock_stake(
+            &coldkey, netuid, &hotkey, locked
+        ));
+
+        // Liquidity positions take their alpha through BalanceOps
+        let alpha = get_alpha(&hotkey, &coldkey, netuid);
+        assert_noop!(
+            <SubtensorModule as subtensor_runtime_common::BalanceOps<U256>>::decrease_stake(
+                &coldkey, &hotkey, netuid, alpha,
+            ),
+            Error::<Test>::StakeUnavailable
+        );
+        assert_ok!(<SubtensorModule as subtensor_runtime_common::BalanceOps<
+            U256,
+        >>::decrease_stake(
+            &coldkey,
+            &hotkey,
+            netuid,
+            alpha.saturating_sub(locked),
+        ));
+    });
+}
+
 // =========================================================================
 // GROUP 7: Move/transfer invariant enforcement
 // =========================================================================
@@ -2342,6 +2474,218 @@ fn test_do_transfer_stake_rejects_locked_alpha_to_flagged_destination() {
     });
 }
 
+#[test]
+fn test_transfer_locked_stake_moves_locked_mass_conviction_and_stake() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey_sender = U256::from(1);
+        let coldkey_receiver = U256::from(5);
+        let hotkey = U256::from(2);
+        let netuid = setup_subnet_with_stake(coldkey_sender, hotkey, 100_000_000_000);
+        DecayingLock::<Test>::insert(coldkey_receiver, netuid, false);
+        SubtensorModule::set_accept_locked_alpha(&coldkey_receiver, true);
+
+        let total = SubtensorModule::total_coldkey_alpha_on_subnet(&coldkey_sender, netuid);
+        let lock_half = total / 2.into();
+        assert_ok!(SubtensorModule::do_lock_stake(
+            &coldkey_sender,
+            netuid,
+            &hotkey,
+            lock_half,
+        ));
+
+        step_block(100);
+
+        let now = SubtensorModule::get_current_block_as_u64();
+        let sender_lock_before = roll_forward_lock(
+            Lock::<Test>::get((coldkey_sender, netuid, hotkey)).unwrap(),
+            now,
+            false,
+            true,
+        );
+        let hotkey_conviction_before = SubtensorModule::hotkey_conviction(&hotkey, netuid);
+        assert!(sender_lock_before.conviction > U64F64::from_num(0));
+
+        let transfer_amount = sender_lock_before.locked_mass / 4.into();
+        assert_ok!(SubtensorModule::transfer_locked_stake(
+            RuntimeOrigin::signed(coldkey_sender),
+            coldkey_receiver,
+            netuid,
+            transfer_amount,
+        ));
+
+        let sender_lock = Lock::<Test>::get((coldkey_sender, netuid, hotkey)).unwrap();
+        let receiver_lock = Lock::<Test>::get((coldkey_receiver, netuid, hotkey)).unwrap();
+        assert_eq!(
+            sender_lock.locked_mass,
+            sender_lock_before.locked_mass - transfer_amount
+        );
+        assert_eq!(receiver_lock.locked_mass, transfer_amount);
+        assert_abs_diff_eq!(
+            receiver_lock.conviction.to_num::<f64>(),
+            sender_lock_before.conviction.to_num::<f64>() / 4.,
+            epsilon = 1.
+        );
+        assert_abs_diff_eq!(
+            (sender_lock.conviction + receiver_lock.conviction).to_num::<f64>(),
+            sender_lock_before.conviction.to_num::<f64>(),
+            epsilon = 1.
+        );
+
+        // The stake backing the lock moved with it, and none of it is available to unstake
+        assert_eq!(
+            get_alpha(&hotkey, &coldkey_receiver, netuid),
+            transfer_amount
+        );
+        assert_eq!(
+            SubtensorModule::total_coldkey_alpha_on_subnet(&coldkey_sender, netuid),
+            total - transfer_amount
+        );
+        assert_eq!(
+            SubtensorModule::available_to_unstake(&coldkey_receiver, netuid),
+            AlphaBalance::ZERO
+        );
+
+        // Aggregates are unchanged because both coldkeys are locked to the same hotkey
+        let hotkey_lock = HotkeyLock::<Test>::get(netuid, hotkey).unwrap();
+        assert_eq!(
+            hotkey_lock.locked_mass,
+            sender_lock.locked_mass + receiver_lock.locked_mass
+        );
+        assert_abs_diff_eq!(
+            SubtensorModule::hotkey_conviction(&hotkey, netuid).to_num::<f64>(),
+            hotkey_conviction_before.to_num::<f64>(),
+            epsilon = 1.
+        );
+
+        System::assert_last_event(
+            Event::LockTransferred {
+                origin_coldkey: coldkey_sender,
+                destination_coldkey: coldkey_receiver,
+                hotkey,
+                netuid,
+                amount: transfer_amount,
+                conviction: receiver_lock.conviction,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn test_transfer_locked_stake_rejected_by_flagged_destination() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey_sender = U256::from(1);
+        let coldkey_receiver = U256::from(5);
+        let hotkey = U256::from(2);
+        let netuid = setup_subnet_with_stake(coldkey_sender, hotkey, 100_000_000_000);
+
+        let total = SubtensorModule::total_coldkey_alpha_on_subnet(&coldkey_sender, netuid);
+        assert_ok!(SubtensorModule::do_lock_stake(
+            &coldkey_sender,
+            netuid,
+            &hotkey,
+            total / 2.into(),
+        ));
+
+        assert_noop!(
+            SubtensorModule::transfer_locked_stake(
+                RuntimeOrigin::signed(coldkey_sender),
+                coldkey_receiver,
+                netuid,
+                total / 4.into(),
+            ),
+            Error::<Test>::AccountRejectsLockedAlpha
+        );
+    });
+}
+
+#[test]
+fn test_transfer_locked_stake_validates_lock_and_destination() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey_sender = U256::from(1);
+        let coldkey_receiver = U256::from(5);
+        let hotkey = U256::from(2);
+        let other_hotkey = U256::from(3);
+        let netuid = setup_subnet_with_stake(coldkey_sender, hotkey, 100_000_000_000);
+        SubtensorModule::set_accept_locked_alpha(&coldkey_receiver, true);
+
+        let total = SubtensorModule::total_coldkey_alpha_on_subnet(&coldkey_sender, netuid);
+        let lock_half = total / 2.into();
+
+        assert_noop!(
+            SubtensorModule::transfer_locked_stake(
+                RuntimeOrigin::signed(coldkey_sender),
+                coldkey_receiver,
+                netuid,
+                lock_half,
+            ),
+            Error::<Test>::NoExistingLock
+        );
+
+        assert_ok!(SubtensorModule::do_lock_stake(
+            &coldkey_sender,
+            netuid,
+            &hotkey,
+            lock_half,
+        ));
+
+        assert_noop!(
+            SubtensorModule::transfer_locked_stake(
+                RuntimeOrigin::signed(coldkey_sender),
+                coldkey_sender,
+                netuid,
+                lock_half,
+            ),
+            Error::<Test>::LockTransferToSameColdkey
+        );
+        assert_noop!(
+            SubtensorModule::transfer_locked_stake(
+                RuntimeOrigin::signed(coldkey_sender),
+                coldkey_receiver,
+                netuid,
+                AlphaBalance::ZERO,
+            ),
+            Error::<Test>::AmountTooLow
+        );
+        assert_noop!(
+            SubtensorModule::transfer_locked_stake(
+                RuntimeOrigin::signed(coldkey_sender),
+                coldkey_receiver,
+                netuid,
+                lock_half + 1.into(),
+            ),
+            Error::<Test>::InsufficientLockedAlpha
+        );
+
+        // The receiver is already locked to another hotkey on this subnet
+        assert_ok!(SubtensorModule::create_account_if_non_existent(
+            &coldkey_receiver,
+            &other_hotkey
+        ));
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &other_hotkey,
+            &coldkey_receiver,
+            netuid,
+            1_000.into(),
+        );
+        assert_ok!(SubtensorModule::do_lock_stake(
+            &coldkey_receiver,
+            netuid,
+            &other_hotkey,
+            1_000.into(),
+        ));
+        assert_noop!(
+            SubtensorModule::transfer_locked_stake(
+                RuntimeOrigin::signed(coldkey_sender),
+                coldkey_receiver,
+                netuid,
+                lock_half,
+            ),
+            Error::<Test>::LockHotkeyMismatch
+        );
+    });
+}
+
 #[test]
 fn test_do_transfer_stake_allows_unlocked_alpha_to_flagged_destination() {
     new_test_ext(1).execute_with(|| {
@@ -2599,3 +2599,32 @@ fn test_mat_ema_alpha_mismatched_dimensions() {
     let result = mat_ema_alpha(&new, &old, &alpha);
     assert_eq!(result[0][0], old[0][0])
 }
+
+#[test]
+fn test_math_cosine_similarity_sparse() {
+    let epsilon = I32F32::from_num(0.0001);
+    let sparse = |v: &[(u16, f32)]| -> Vec<(u16, I32F32)> {
+        v.iter().map(|(i, x)| (*i, I32F32::from_num(*x))).collect()
+    };
+
+    let a = sparse(&[(0, 0.5), (2, 0.5)]);
+    let b = sparse(&[(0, 0.1), (2, 0.1)]);
+    assert_float_compare(
+        cosine_similarity_sparse(&a, &b),
+        I32F32::from_num(1),
+        epsilon,
+    );
+
+    let b = sparse(&[(1, 0.5), (3, 0.5)]);
+    assert_eq!(cosine_similarity_sparse(&a, &b), I32F32::from_num(0));
+
+    // cos = 0.5 / (sqrt(0.5) * 1)
+    let b = sparse(&[(2, 1.0)]);
+    assert_float_compare(
+        cosine_similarity_sparse(&a, &b),
+        I32F32::from_num(0.70710678),
+        epsilon,
+    );
+
+    assert_eq!(cosine_similarity_sparse(&a, &[]), I32F32::from_num(0));
+}
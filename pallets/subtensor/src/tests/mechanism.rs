@@ -35,6 +35,7 @@
 //   - [x] Miner with no weights on any mechanism receives no reward
 //   - [x] MechanismEmissionSplit is reset on mechanism count increase
 //   - [x] MechanismEmissionSplit is reset on mechanism count decrease
+//   - [x] Mechanism hyperparameters override the subnet value and are cleared on count decrease
 
 use super::mock::*;
 use crate::coinbase::reveal_commits::WeightsTlockPayload;
@@ -1586,3 +1587,97 @@ fn epoch_mechanism_emergency_mode_distributes_by_stake() {
         );
     });
 }
+
+#[test]
+fn test_mechanism_hyperparams_override_and_fallback() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        add_network(netuid, 10, 0);
+        MechanismCountCurrent::<Test>::insert(netuid, MechId::from(2u8));
+        SubtensorModule::set_kappa(netuid, 32_767);
+        SubtensorModule::set_min_allowed_weights(netuid, 1);
+        let mecid = MechId::from(1u8);
+        let idx0 = SubtensorModule::get_mechanism_storage_index(netuid, MechId::MAIN);
+        let idx1 = SubtensorModule::get_mechanism_storage_index(netuid, mecid);
+
+        // Without overrides every mechanism reads the subnet value
+        assert_eq!(SubtensorModule::get_mechanism_kappa(idx1), 32_767);
+        assert_eq!(SubtensorModule::get_mechanism_min_allowed_weights(idx1), 1);
+
+        assert_ok!(SubtensorModule::do_set_mechanism_hyperparams(
+            netuid,
+            mecid,
+            MechanismHyperparams {
+                kappa: Some(45_000),
+                min_allowed_weights: Some(3),
+                reveal_period: Some(3),
+                ..Default::default()
+            },
+        ));
+
+        assert_eq!(SubtensorModule::get_mechanism_kappa(idx1), 45_000);
+        assert_eq!(SubtensorModule::get_mechanism_min_allowed_weights(idx1), 3);
+        assert_eq!(SubtensorModule::get_mechanism_reveal_period(idx1), 3);
+        assert_eq!(
+            SubtensorModule::get_mechanism_bonds_moving_average(idx1),
+            SubtensorModule::get_bonds_moving_average(netuid)
+        );
+        assert_eq!(SubtensorModule::get_mechanism_kappa(idx0), 32_767);
+        assert_eq!(
+            SubtensorModule::get_mechanism_reveal_period(idx0),
+            SubtensorModule::get_reveal_period(netuid)
+        );
+
+        // The weight length check follows the mechanism override
+        for i in 0..3u16 {
+            register_ok_neuron(netuid, U256::from(i), U256::from(100 + i), 0);
+        }
+        let (uids, weights) = (vec![1u16, 2], vec![1u16, 1]);
+        assert!(SubtensorModule::check_length(
+            netuid,
+            MechId::MAIN,
+            0,
+            &uids,
+            &weights
+        ));
+        assert!(!SubtensorModule::check_length(
+            netuid, mecid, 0, &uids, &weights
+        ));
+
+        // Invalid overrides are rejected
+        assert_noop!(
+            SubtensorModule::do_set_mechanism_hyperparams(
+                netuid,
+                mecid,
+                MechanismHyperparams {
+                    alpha_values: Some((u16::MAX, 50_000)),
+                    ..Default::default()
+                },
+            ),
+            Error::<Test>::AlphaLowOutOfRange
+        );
+        assert_noop!(
+            SubtensorModule::do_set_mechanism_hyperparams(
+                netuid,
+                mecid,
+                MechanismHyperparams {
+                    reveal_period: Some(0),
+                    ..Default::default()
+                },
+            ),
+            Error::<Test>::RevealPeriodTooSmall
+        );
+        assert_noop!(
+            SubtensorModule::do_set_mechanism_hyperparams(
+                netuid,
+                MechId::from(2u8),
+                MechanismHyperparams::default(),
+            ),
+            Error::<Test>::MechanismDoesNotExist
+        );
+
+        // Reducing the mechanism count clears the overrides
+        SubtensorModule::update_mechanism_counts_if_needed(netuid, MechId::from(1u8));
+        assert!(!MechanismHyperparamOverrides::<Test>::contains_key(idx1));
+    });
+}
@@ -225,6 +225,7 @@ parameter_types! {
     pub const SubtensorPalletId: PalletId = PalletId(*b"subtensr");
     pub const BurnAccountId: PalletId = PalletId(*b"burntnsr");
     pub const MaxEpochsPerBlock: u8 = 32;
+    pub const EpochHistoryLength: u32 = 20;
 }
 
 impl crate::Config for Test {
@@ -309,6 +310,7 @@ impl crate::Config for Test {
     type SubtensorPalletId = SubtensorPalletId;
     type BurnAccountId = BurnAccountId;
     type InitialMaxEpochsPerBlock = MaxEpochsPerBlock;
+    type EpochHistoryLength = EpochHistoryLength;
     type WeightInfo = ();
 }
 
@@ -318,6 +320,9 @@ parameter_types! {
     pub const SwapMaxFeeRate: u16 = 10000; // 15.26%
     pub const SwapMinimumLiquidity: u64 = 1_000;
     pub const SwapMinimumReserve: NonZeroU64 = NonZeroU64::new(100).unwrap();
+    pub const SwapPriceCandlePeriod: u32 = 360;
+    pub const SwapMaxPriceCandles: u32 = 240;
+    pub const SwapMaxPriceSamplesPerBlock: u32 = 32;
 }
 
 impl pallet_subtensor_swap::Config for Test {
@@ -329,6 +334,9 @@ impl pallet_subtensor_swap::Config for Test {
     type MaxFeeRate = SwapMaxFeeRate;
     type MinimumLiquidity = SwapMinimumLiquidity;
     type MinimumReserve = SwapMinimumReserve;
+    type PriceCandlePeriod = SwapPriceCandlePeriod;
+    type MaxPriceCandles = SwapMaxPriceCandles;
+    type MaxPriceSamplesPerBlock = SwapMaxPriceSamplesPerBlock;
     type WeightInfo = ();
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = ();
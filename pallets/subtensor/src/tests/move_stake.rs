@@ -1564,6 +1564,67 @@ fn test_do_swap_storage_updates() {
     });
 }
 
+#[test]
+fn test_sim_swap_alpha_for_alpha_matches_swap_stake() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1400);
+        let subnet_owner_hotkey = U256::from(1401);
+        let origin_netuid = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        let destination_netuid = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        mock::setup_reserves(
+            origin_netuid,
+            TaoBalance::from(150_000_000_000_u64),
+            AlphaBalance::from(100_000_000_000_u64),
+        );
+        mock::setup_reserves(
+            destination_netuid,
+            TaoBalance::from(100_000_000_000_u64),
+            AlphaBalance::from(200_000_000_000_u64),
+        );
+
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let alpha = AlphaBalance::from(30_000_000_000_u64);
+        let _ = SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey,
+            &coldkey,
+            origin_netuid,
+            alpha,
+        );
+
+        let quote =
+            SubtensorModule::sim_swap_alpha_for_alpha(origin_netuid, destination_netuid, alpha)
+                .unwrap();
+
+        // The origin leg pays the fee, the destination leg is fee-less
+        assert_eq!(quote.alpha_in, alpha);
+        assert!(!quote.origin_fee.is_zero());
+        assert!(quote.destination_fee.is_zero());
+        // Spot value is 30 * 1.5 / 0.5 = 90 destination alpha
+        assert!(quote.alpha_out < AlphaBalance::from(90_000_000_000_u64));
+        assert!(!quote.price_impact.is_zero());
+
+        assert_ok!(SubtensorModule::do_swap_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            origin_netuid,
+            destination_netuid,
+            alpha
+        ));
+
+        assert_abs_diff_eq!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &hotkey,
+                &coldkey,
+                destination_netuid
+            ),
+            quote.alpha_out,
+            epsilon = 1000.into()
+        );
+    });
+}
+
 #[test]
 fn test_do_swap_multiple_times() {
     new_test_ext(1).execute_with(|| {
@@ -4,11 +4,16 @@
 use approx::assert_abs_diff_eq;
 use frame_support::dispatch::{DispatchClass, GetDispatchInfo, Pays};
 use frame_support::sp_runtime::DispatchError;
-use frame_support::{assert_err, assert_noop, assert_ok, traits::Currency};
+use frame_support::weights::Weight;
+use frame_support::{
+    BoundedVec, assert_err, assert_noop, assert_ok,
+    traits::{Currency, Hooks},
+};
 use frame_system::RawOrigin;
 use safe_math::FixedExt;
 use share_pool::SafeFloat;
 use sp_core::{Get, H256, U256};
+use sp_runtime::Perbill;
 use substrate_fixed::traits::FromFixed;
 use substrate_fixed::types::{I96F32, I110F18, U64F64, U96F32};
 use subtensor_runtime_common::{AlphaBalance, NetUid, NetUidStorageIndex, TaoBalance, Token};
@@ -4368,6 +4373,113 @@ fn test_move_stake_limit_partial() {
     });
 }
 
+#[test]
+fn test_swap_stake_min_out_partial() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1001);
+        let subnet_owner_hotkey = U256::from(1002);
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let stake_amount = AlphaBalance::from(150_000_000_000_u64);
+        let move_amount = AlphaBalance::from(150_000_000_000_u64);
+
+        // add network
+        let origin_netuid = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        let destination_netuid = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        register_ok_neuron(origin_netuid, hotkey, coldkey, 192213123);
+        register_ok_neuron(destination_netuid, hotkey, coldkey, 192213123);
+
+        // Give the neuron some stake to remove
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey,
+            &coldkey,
+            origin_netuid,
+            stake_amount,
+        );
+
+        // Registration now goes through the burn/swap path, which initializes swap V3 state.
+        // Clear that state first so the manual reserve fixture below actually controls price.
+        assert_ok!(
+            <Test as pallet::Config>::SwapInterface::clear_protocol_liquidity(origin_netuid)
+        );
+        assert_ok!(
+            <Test as pallet::Config>::SwapInterface::clear_protocol_liquidity(destination_netuid)
+        );
+
+        // Force-set alpha in and tao reserve to make price equal 1.5 on both origin and destination,
+        // but there's much more liquidity on destination, so its price wouldn't go up when restaked.
+        let tao_reserve = TaoBalance::from(150_000_000_000_u64);
+        let alpha_in = AlphaBalance::from(100_000_000_000_u64);
+
+        SubnetTAO::<Test>::insert(origin_netuid, tao_reserve);
+        SubnetAlphaIn::<Test>::insert(origin_netuid, alpha_in);
+
+        SubnetTAO::<Test>::insert(destination_netuid, tao_reserve * 100_000.into());
+        SubnetAlphaIn::<Test>::insert(destination_netuid, alpha_in * 100_000.into());
+
+        let origin_price =
+            <Test as pallet::Config>::SwapInterface::current_alpha_price(origin_netuid.into());
+        let destination_price =
+            <Test as pallet::Config>::SwapInterface::current_alpha_price(destination_netuid.into());
+
+        assert_eq!(origin_price, U96F32::from_num(1.5));
+        assert_eq!(destination_price, U96F32::from_num(1.5));
+
+        // The relative price between origin and destination subnets is 1, and the destination
+        // price barely moves. Selling x alpha on origin returns 100 * x / (100 + x) destination
+        // alpha, so receiving 100 alpha for 150 (2/3 on average) fills x = 50 alpha only.
+        let min_alpha_out = AlphaBalance::from(100_000_000_000_u64);
+
+        // Fill or kill fails
+        assert_noop!(
+            SubtensorModule::swap_stake_min_out(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+                origin_netuid,
+                destination_netuid,
+                move_amount,
+                min_alpha_out,
+                false,
+            ),
+            Error::<Test>::SlippageTooHigh
+        );
+
+        // Move stake with slippage safety - executes partially
+        assert_ok!(SubtensorModule::swap_stake_min_out(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            origin_netuid,
+            destination_netuid,
+            move_amount,
+            min_alpha_out,
+            true,
+        ));
+
+        let new_alpha = SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey,
+            &coldkey,
+            origin_netuid,
+        );
+        let moved_alpha = stake_amount - new_alpha;
+        let received_alpha = SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey,
+            &coldkey,
+            destination_netuid,
+        );
+
+        assert_abs_diff_eq!(
+            new_alpha,
+            AlphaBalance::from(100_000_000_000_u64),
+            epsilon = 1_000_000_000.into()
+        );
+        // The partial fill keeps the average rate
+        assert!(
+            u128::from(received_alpha.to_u64()) * u128::from(move_amount.to_u64())
+                >= u128::from(min_alpha_out.to_u64()) * u128::from(moved_alpha.to_u64())
+        );
+    });
+}
+
 /// cargo test --package pallet-subtensor --lib -- tests::staking::test_unstake_all_hits_liquidity_min --exact --show-output
 #[test]
 fn test_unstake_all_hits_liquidity_min() {
@@ -5943,3 +6055,725 @@ fn test_sharepool_dataops_try_get_value_returns_err_on_non_existing_v2() {
         assert!(maybe_actual_value.is_err());
     });
 }
+
+/************************************************************
+    staking::recurring_stake tests
+************************************************************/
+
+#[test]
+fn test_recurring_stake_executes_skips_and_ends() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(533455);
+        let coldkey = U256::from(55455);
+        let netuid = add_dynamic_network(&hotkey, &coldkey);
+
+        // Price 1.5
+        SubnetTAO::<Test>::insert(netuid, TaoBalance::from(150_000_000_000_u64));
+        SubnetAlphaIn::<Test>::insert(netuid, AlphaBalance::from(100_000_000_000_u64));
+        add_balance_to_coldkey_account(&coldkey, 100_000_000_000_u64.into());
+
+        let amount = TaoBalance::from(1_000_000_000_u64);
+        let start = System::block_number();
+        assert_ok!(SubtensorModule::schedule_recurring_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            amount,
+            10,
+            Some(TaoBalance::from(2_000_000_000_u64)),
+            start + 25,
+        ));
+        let schedules = SubtensorModule::get_recurring_stakes(coldkey);
+        assert_eq!(schedules.len(), 1);
+        let (id, schedule) = schedules[0].clone();
+        assert_eq!(schedule.next_block, start + 1);
+
+        // First execution at the next block.
+        run_to_block(start + 1);
+        SubtensorModule::on_idle(System::block_number(), Weight::MAX);
+        let stake =
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid);
+        assert!(!stake.is_zero());
+        assert_eq!(
+            RecurringStakes::<Test>::get(coldkey, id)
+                .unwrap()
+                .next_block,
+            start + 11
+        );
+
+        // The price is above the ceiling: the execution is skipped, the schedule keeps running.
+        SubnetTAO::<Test>::insert(netuid, TaoBalance::from(300_000_000_000_u64));
+        let balance = SubtensorModule::get_coldkey_balance(&coldkey);
+        run_to_block(start + 11);
+        SubtensorModule::on_idle(System::block_number(), Weight::MAX);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), balance);
+        assert!(System::events().iter().any(|record| matches!(
+            &record.event,
+            RuntimeEvent::SubtensorModule(Event::RecurringStakeSkipped { reason, .. })
+                if *reason == pallet_subtensor_swap::Error::<Test>::PriceLimitExceeded.into()
+        )));
+        assert_eq!(
+            RecurringStakes::<Test>::get(coldkey, id)
+                .unwrap()
+                .next_block,
+            start + 21
+        );
+
+        // The last execution fits before the end block, the next one does not.
+        run_to_block(start + 21);
+        SubtensorModule::on_idle(System::block_number(), Weight::MAX);
+        assert!(RecurringStakes::<Test>::get(coldkey, id).is_none());
+        assert!(SubtensorModule::get_recurring_stakes(coldkey).is_empty());
+    });
+}
+
+#[test]
+fn test_recurring_stake_skips_missed_intervals_and_cancels() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(533456);
+        let coldkey = U256::from(55456);
+        let netuid = add_dynamic_network(&hotkey, &coldkey);
+        SubnetTAO::<Test>::insert(netuid, TaoBalance::from(150_000_000_000_u64));
+        SubnetAlphaIn::<Test>::insert(netuid, AlphaBalance::from(100_000_000_000_u64));
+        add_balance_to_coldkey_account(&coldkey, 100_000_000_000_u64.into());
+
+        let start = System::block_number();
+        assert_noop!(
+            SubtensorModule::schedule_recurring_stake(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+                netuid,
+                1_000_000_000_u64.into(),
+                0,
+                None,
+                start + 100,
+            ),
+            Error::<Test>::InvalidRecurringStakeInterval
+        );
+        assert_ok!(SubtensorModule::schedule_recurring_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            1_000_000_000_u64.into(),
+            10,
+            None,
+            start + 100,
+        ));
+        let (id, _) = SubtensorModule::get_recurring_stakes(coldkey)[0].clone();
+
+        // No idle weight until block start + 35: the backlog is executed once.
+        run_to_block(start + 35);
+        SubtensorModule::on_idle(System::block_number(), Weight::MAX);
+        let executed = System::events()
+            .iter()
+            .filter(|record| {
+                matches!(
+                    record.event,
+                    RuntimeEvent::SubtensorModule(Event::RecurringStakeExecuted { .. })
+                )
+            })
+            .count();
+        assert_eq!(executed, 1);
+        assert_eq!(
+            RecurringStakes::<Test>::get(coldkey, id)
+                .unwrap()
+                .next_block,
+            start + 41
+        );
+        assert_eq!(RecurringStakeCursor::<Test>::get(), start + 36);
+
+        assert_noop!(
+            SubtensorModule::cancel_recurring_stake(RuntimeOrigin::signed(hotkey), id),
+            Error::<Test>::RecurringStakeNotFound
+        );
+        assert_ok!(SubtensorModule::cancel_recurring_stake(
+            RuntimeOrigin::signed(coldkey),
+            id
+        ));
+        assert!(RecurringStakes::<Test>::get(coldkey, id).is_none());
+
+        // The due entry of the cancelled stake is skipped.
+        run_to_block(start + 41);
+        let balance = SubtensorModule::get_coldkey_balance(&coldkey);
+        SubtensorModule::on_idle(System::block_number(), Weight::MAX);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), balance);
+    });
+}
+
+#[test]
+fn test_recurring_stake_skipped_while_coldkey_swap_announced() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(533459);
+        let coldkey = U256::from(55459);
+        let netuid = add_dynamic_network(&hotkey, &coldkey);
+        SubnetTAO::<Test>::insert(netuid, TaoBalance::from(150_000_000_000_u64));
+        SubnetAlphaIn::<Test>::insert(netuid, AlphaBalance::from(100_000_000_000_u64));
+        add_balance_to_coldkey_account(&coldkey, 100_000_000_000_u64.into());
+
+        let start = System::block_number();
+        assert_ok!(SubtensorModule::schedule_recurring_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            1_000_000_000_u64.into(),
+            10,
+            None,
+            start + 100,
+        ));
+        let (id, _) = SubtensorModule::get_recurring_stakes(coldkey)[0].clone();
+
+        // The coldkey swap guard blocks the execution like it blocks a signed `add_stake_limit`.
+        ColdkeySwapAnnouncements::<Test>::insert(coldkey, (start, H256::zero()));
+        let balance = SubtensorModule::get_coldkey_balance(&coldkey);
+        run_to_block(start + 1);
+        SubtensorModule::on_idle(System::block_number(), Weight::MAX);
+
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), balance);
+        assert!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid)
+                .is_zero()
+        );
+        assert!(System::events().iter().any(|record| matches!(
+            &record.event,
+            RuntimeEvent::SubtensorModule(Event::RecurringStakeSkipped { reason, .. })
+                if *reason == Error::<Test>::ColdkeySwapAnnounced.into()
+        )));
+        assert_eq!(
+            RecurringStakes::<Test>::get(coldkey, id)
+                .unwrap()
+                .next_block,
+            start + 11
+        );
+    });
+}
+
+#[test]
+fn test_recurring_stakes_due_spill_into_next_block() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(533461);
+        let coldkey = U256::from(55462);
+        let netuid = add_dynamic_network(&hotkey, &coldkey);
+
+        let start = System::block_number();
+        RecurringStakesDue::<Test>::insert(
+            start + 1,
+            BoundedVec::truncate_from(vec![
+                (U256::from(1), u64::MAX);
+                MAX_RECURRING_STAKES_DUE_PER_BLOCK as usize
+            ]),
+        );
+
+        // The next block is full: the first execution moves to the block after.
+        assert_ok!(SubtensorModule::schedule_recurring_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            1_000_000_000_u64.into(),
+            10,
+            None,
+            start + 100,
+        ));
+        let (id, schedule) = SubtensorModule::get_recurring_stakes(coldkey)[0].clone();
+        assert_eq!(schedule.next_block, start + 2);
+        assert_eq!(
+            RecurringStakesDue::<Test>::get(start + 1).len(),
+            MAX_RECURRING_STAKES_DUE_PER_BLOCK as usize
+        );
+        assert_eq!(
+            RecurringStakesDue::<Test>::get(start + 2).into_inner(),
+            vec![(coldkey, id)]
+        );
+    });
+}
+
+#[test]
+fn test_transfer_recurring_stakes_bounded_per_coldkey() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(533460);
+        let old_coldkey = U256::from(55460);
+        let new_coldkey = U256::from(55461);
+        let netuid = add_dynamic_network(&hotkey, &old_coldkey);
+
+        let start = System::block_number();
+        for coldkey in [old_coldkey, new_coldkey] {
+            for _ in 0..10 {
+                assert_ok!(SubtensorModule::schedule_recurring_stake(
+                    RuntimeOrigin::signed(coldkey),
+                    hotkey,
+                    netuid,
+                    1_000_000_000_u64.into(),
+                    10,
+                    None,
+                    start + 100,
+                ));
+            }
+        }
+        let old_ids: Vec<u64> = SubtensorModule::get_recurring_stakes(old_coldkey)
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+
+        System::reset_events();
+        SubtensorModule::transfer_recurring_stakes(&old_coldkey, &new_coldkey);
+
+        // The new coldkey fills up to the limit with the lowest ids, the rest are ended.
+        let (moved, ended) = old_ids.split_at(6);
+        let new_ids: Vec<u64> = SubtensorModule::get_recurring_stakes(new_coldkey)
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(new_ids.len(), MAX_RECURRING_STAKES_PER_COLDKEY as usize);
+        assert!(moved.iter().all(|id| new_ids.contains(id)));
+        assert!(SubtensorModule::get_recurring_stakes(old_coldkey).is_empty());
+        for id in ended {
+            assert!(System::events().iter().any(|record| record.event
+                == RuntimeEvent::SubtensorModule(Event::RecurringStakeEnded {
+                    coldkey: old_coldkey,
+                    id: *id,
+                })));
+        }
+    });
+}
+
+/************************************************************
+    staking::rebalance tests
+************************************************************/
+
+fn rebalance_legs() -> Vec<crate::staking::rebalance::RebalanceLeg<U256>> {
+    System::events()
+        .iter()
+        .find_map(|record| match &record.event {
+            RuntimeEvent::SubtensorModule(Event::PortfolioRebalanced { legs, .. }) => {
+                Some(legs.clone())
+            }
+            _ => None,
+        })
+        .unwrap()
+}
+
+#[test]
+fn test_rebalance_portfolio_sells_before_buys() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(55457);
+        let hotkey = U256::from(533457);
+        let netuid1 = add_dynamic_network(&hotkey, &coldkey);
+        let netuid2 = add_dynamic_network(&U256::from(533458), &U256::from(55458));
+        for netuid in [netuid1, netuid2] {
+            SubnetTAO::<Test>::insert(netuid, TaoBalance::from(1_000_000_000_000_u64));
+            SubnetAlphaIn::<Test>::insert(netuid, AlphaBalance::from(1_000_000_000_000_u64));
+        }
+        add_balance_to_coldkey_account(&coldkey, 100_000_000_000_u64.into());
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid1,
+            100_000_000_000_u64.into(),
+        ));
+        let alpha_before =
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid1);
+        let balance_before = SubtensorModule::get_coldkey_balance(&coldkey);
+
+        System::reset_events();
+        assert_ok!(SubtensorModule::rebalance_portfolio(
+            RuntimeOrigin::signed(coldkey),
+            vec![
+                (netuid1, hotkey, Perbill::from_percent(50)),
+                (netuid2, hotkey, Perbill::from_percent(50)),
+            ],
+            Perbill::from_percent(10),
+        ));
+
+        let legs = rebalance_legs();
+        assert_eq!(legs.len(), 2);
+        let crate::staking::rebalance::RebalanceLeg::Sell {
+            netuid, tao: sold, ..
+        } = legs[0].clone()
+        else {
+            panic!("expected a sell first");
+        };
+        assert_eq!(netuid, netuid1);
+        assert!(matches!(
+            legs[1],
+            crate::staking::rebalance::RebalanceLeg::Buy { netuid, tao, .. }
+                if netuid == netuid2 && tao <= sold
+        ));
+
+        let alpha1 =
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid1);
+        let alpha2 =
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid2);
+        assert!(alpha1 < alpha_before && !alpha1.is_zero());
+        assert!(!alpha2.is_zero());
+        // The buys are paid by the sells only.
+        assert!(SubtensorModule::get_coldkey_balance(&coldkey) >= balance_before);
+    });
+}
+
+#[test]
+fn test_rebalance_portfolio_moves_within_subnet_without_swap() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(55459);
+        let hotkey1 = U256::from(533459);
+        let hotkey2 = U256::from(533460);
+        let netuid = add_dynamic_network(&hotkey1, &coldkey);
+        let _ = SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey2);
+        SubnetTAO::<Test>::insert(netuid, TaoBalance::from(1_000_000_000_000_u64));
+        SubnetAlphaIn::<Test>::insert(netuid, AlphaBalance::from(1_000_000_000_000_u64));
+        add_balance_to_coldkey_account(&coldkey, 100_000_000_000_u64.into());
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey1,
+            netuid,
+            100_000_000_000_u64.into(),
+        ));
+        let alpha =
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey1, &coldkey, netuid);
+        let subnet_tao = SubnetTAO::<Test>::get(netuid);
+
+        System::reset_events();
+        assert_ok!(SubtensorModule::rebalance_portfolio(
+            RuntimeOrigin::signed(coldkey),
+            vec![
+                (netuid, hotkey1, Perbill::from_percent(50)),
+                (netuid, hotkey2, Perbill::from_percent(50)),
+            ],
+            Perbill::zero(),
+        ));
+
+        let legs = rebalance_legs();
+        assert_eq!(legs.len(), 1);
+        assert!(matches!(
+            legs[0],
+            crate::staking::rebalance::RebalanceLeg::Move { from_hotkey, to_hotkey, .. }
+                if from_hotkey == hotkey1 && to_hotkey == hotkey2
+        ));
+        assert_eq!(SubnetTAO::<Test>::get(netuid), subnet_tao);
+        let alpha1 =
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey1, &coldkey, netuid);
+        let alpha2 =
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey2, &coldkey, netuid);
+        assert_abs_diff_eq!(u64::from(alpha1), u64::from(alpha) / 2, epsilon = 10);
+        assert_abs_diff_eq!(u64::from(alpha2), u64::from(alpha) / 2, epsilon = 10);
+    });
+}
+
+#[test]
+fn test_rebalance_portfolio_reverts_on_slippage_and_bad_targets() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(55461);
+        let hotkey = U256::from(533461);
+        let netuid1 = add_dynamic_network(&hotkey, &coldkey);
+        let netuid2 = add_dynamic_network(&U256::from(533462), &U256::from(55462));
+        for netuid in [netuid1, netuid2] {
+            SubnetTAO::<Test>::insert(netuid, TaoBalance::from(1_000_000_000_000_u64));
+            SubnetAlphaIn::<Test>::insert(netuid, AlphaBalance::from(1_000_000_000_000_u64));
+        }
+        add_balance_to_coldkey_account(&coldkey, 100_000_000_000_u64.into());
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid1,
+            100_000_000_000_u64.into(),
+        ));
+
+        assert_noop!(
+            SubtensorModule::rebalance_portfolio(
+                RuntimeOrigin::signed(coldkey),
+                vec![
+                    (netuid1, hotkey, Perbill::from_percent(60)),
+                    (netuid2, hotkey, Perbill::from_percent(60)),
+                ],
+                Perbill::from_percent(10),
+            ),
+            Error::<Test>::InvalidRebalanceTargets
+        );
+        assert_noop!(
+            SubtensorModule::rebalance_portfolio(
+                RuntimeOrigin::signed(coldkey),
+                vec![
+                    (netuid1, hotkey, Perbill::from_percent(20)),
+                    (netuid1, hotkey, Perbill::from_percent(20)),
+                ],
+                Perbill::from_percent(10),
+            ),
+            Error::<Test>::InvalidRebalanceTargets
+        );
+
+        // Selling half of the position moves the price by far more than 0.01%.
+        assert_noop!(
+            SubtensorModule::rebalance_portfolio(
+                RuntimeOrigin::signed(coldkey),
+                vec![
+                    (netuid1, hotkey, Perbill::from_percent(50)),
+                    (netuid2, hotkey, Perbill::from_percent(50)),
+                ],
+                Perbill::from_parts(100_000),
+            ),
+            Error::<Test>::SlippageTooHigh
+        );
+    });
+}
+
+#[test]
+fn test_rebalance_portfolio_buy_within_swap_limit() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(55463);
+        let hotkey = U256::from(533463);
+        let netuid1 = add_dynamic_network(&hotkey, &coldkey);
+        let netuid2 = add_dynamic_network(&U256::from(533464), &U256::from(55464));
+        SubnetTAO::<Test>::insert(netuid1, TaoBalance::from(1_000_000_000_000_u64));
+        SubnetAlphaIn::<Test>::insert(netuid1, AlphaBalance::from(1_000_000_000_000_u64));
+        SubnetTAO::<Test>::insert(netuid2, TaoBalance::from(10_000_000_u64));
+        SubnetAlphaIn::<Test>::insert(netuid2, AlphaBalance::from(10_000_000_u64));
+        add_balance_to_coldkey_account(&coldkey, 100_000_000_000_u64.into());
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid1,
+            100_000_000_000_u64.into(),
+        ));
+
+        // The buy leg is above the input the pool of the second subnet supports.
+        assert_noop!(
+            SubtensorModule::rebalance_portfolio(
+                RuntimeOrigin::signed(coldkey),
+                vec![
+                    (netuid1, hotkey, Perbill::from_percent(50)),
+                    (netuid2, hotkey, Perbill::from_percent(50)),
+                ],
+                Perbill::one(),
+            ),
+            Error::<Test>::InsufficientLiquidity
+        );
+    });
+}
+
+fn cost_basis_on(
+    coldkey: U256,
+    netuid: NetUid,
+) -> crate::rpc_info::stake_info::StakeInfoWithCostBasis {
+    SubtensorModule::get_stake_info_with_cost_basis(coldkey)
+        .into_iter()
+        .find(|info| info.netuid() == netuid)
+        .unwrap()
+}
+
+#[test]
+fn test_cost_basis_tracks_stake_and_realized_pnl() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(55461);
+        let hotkey = U256::from(533461);
+        let netuid = add_dynamic_network(&hotkey, &coldkey);
+        SubnetTAO::<Test>::insert(netuid, TaoBalance::from(1_000_000_000_000_u64));
+        SubnetAlphaIn::<Test>::insert(netuid, AlphaBalance::from(1_000_000_000_000_u64));
+        add_balance_to_coldkey_account(&coldkey, 100_000_000_000_u64.into());
+        assert_ok!(SubtensorModule::stake_into_subnet(
+            &hotkey,
+            &coldkey,
+            netuid,
+            10_000_000_000_u64.into(),
+            <Test as Config>::SwapInterface::max_price(),
+            false,
+        ));
+        assert!(SubtensorModule::get_stake_info_with_cost_basis(coldkey).is_empty());
+
+        // Alpha held before tracking enters at its market value.
+        assert_ok!(SubtensorModule::set_cost_basis_tracking(
+            RuntimeOrigin::signed(coldkey),
+            true
+        ));
+        let info = cost_basis_on(coldkey, netuid);
+        assert_eq!(info.cost_basis(), info.value());
+        assert_eq!(info.realized_pnl(), 0);
+
+        let cost_before = u64::from(info.cost_basis());
+        assert_ok!(SubtensorModule::stake_into_subnet(
+            &hotkey,
+            &coldkey,
+            netuid,
+            10_000_000_000_u64.into(),
+            <Test as Config>::SwapInterface::max_price(),
+            false,
+        ));
+        let info = cost_basis_on(coldkey, netuid);
+        let cost = u64::from(info.cost_basis());
+        assert_abs_diff_eq!(cost, cost_before + 10_000_000_000, epsilon = 1_000);
+        assert_abs_diff_eq!(
+            info.average_entry_price(),
+            (u128::from(cost) * 1_000_000_000 / u128::from(u64::from(info.stake()))) as u64,
+            epsilon = 1
+        );
+
+        // Selling half realizes the proceeds against half the cost.
+        let alpha = AlphaBalance::from(u64::from(info.stake()) / 2);
+        let tao = SubtensorModule::unstake_from_subnet(
+            &hotkey,
+            &coldkey,
+            &coldkey,
+            netuid,
+            alpha,
+            <Test as Config>::SwapInterface::min_price(),
+            false,
+        )
+        .unwrap();
+        let info = cost_basis_on(coldkey, netuid);
+        assert_abs_diff_eq!(u64::from(info.cost_basis()), cost / 2, epsilon = 10);
+        assert_abs_diff_eq!(
+            info.realized_pnl(),
+            u64::from(tao) as i64 - (cost / 2) as i64,
+            epsilon = 10
+        );
+        assert_eq!(
+            info.unrealized_pnl(),
+            u64::from(info.value()) as i64 - u64::from(info.cost_basis()) as i64
+        );
+
+        // Turning tracking off clears the ledger.
+        assert_ok!(SubtensorModule::set_cost_basis_tracking(
+            RuntimeOrigin::signed(coldkey),
+            false
+        ));
+        assert!(SubtensorModule::get_stake_info_with_cost_basis(coldkey).is_empty());
+        assert!(!StakeCostBasis::<Test>::contains_key(netuid, coldkey));
+    });
+}
+
+#[test]
+fn test_cost_basis_follows_liquidity_positions() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(55464);
+        let hotkey = U256::from(533464);
+        let netuid = add_dynamic_network(&hotkey, &coldkey);
+        SubnetTAO::<Test>::insert(netuid, TaoBalance::from(1_000_000_000_000_u64));
+        SubnetAlphaIn::<Test>::insert(netuid, AlphaBalance::from(1_000_000_000_000_u64));
+        add_balance_to_coldkey_account(&coldkey, 100_000_000_000_u64.into());
+        assert_ok!(SubtensorModule::set_cost_basis_tracking(
+            RuntimeOrigin::signed(coldkey),
+            true
+        ));
+        assert_ok!(SubtensorModule::stake_into_subnet(
+            &hotkey,
+            &coldkey,
+            netuid,
+            10_000_000_000_u64.into(),
+            <Test as Config>::SwapInterface::max_price(),
+            false,
+        ));
+        let info = cost_basis_on(coldkey, netuid);
+        let cost = u64::from(info.cost_basis());
+
+        // Alpha moved into a liquidity position is disposed of at its market value.
+        let alpha = AlphaBalance::from(u64::from(info.stake()) / 2);
+        let value = SubtensorModule::cost_basis_market_value(netuid, alpha);
+        assert_ok!(<SubtensorModule as subtensor_runtime_common::BalanceOps<
+            U256,
+        >>::decrease_stake(&coldkey, &hotkey, netuid, alpha,));
+        let info = cost_basis_on(coldkey, netuid);
+        assert_abs_diff_eq!(u64::from(info.cost_basis()), cost / 2, epsilon = 10);
+        assert_abs_diff_eq!(
+            info.realized_pnl(),
+            u64::from(value) as i64 - (cost / 2) as i64,
+            epsilon = 10
+        );
+
+        // Alpha coming back from the position enters at its market value.
+        let cost_before = u64::from(info.cost_basis());
+        assert_ok!(<SubtensorModule as subtensor_runtime_common::BalanceOps<
+            U256,
+        >>::increase_stake(&coldkey, &hotkey, netuid, alpha,));
+        assert_eq!(
+            u64::from(cost_basis_on(coldkey, netuid).cost_basis()),
+            cost_before + u64::from(value)
+        );
+    });
+}
+
+#[test]
+fn test_cost_basis_tracking_bounded_by_staking_hotkeys() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(55465);
+        let netuid = add_dynamic_network(&U256::from(533465), &coldkey);
+        for i in 0..=MAX_COST_BASIS_STAKING_HOTKEYS {
+            SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                &U256::from(600_000 + i),
+                &coldkey,
+                netuid,
+                1_000_u64.into(),
+            );
+        }
+
+        assert_noop!(
+            SubtensorModule::set_cost_basis_tracking(RuntimeOrigin::signed(coldkey), true),
+            Error::<Test>::TooManyStakingHotkeys
+        );
+        // Turning tracking off is always possible.
+        assert_ok!(SubtensorModule::set_cost_basis_tracking(
+            RuntimeOrigin::signed(coldkey),
+            false
+        ));
+    });
+}
+
+#[test]
+fn test_cost_basis_moves_with_transferred_stake() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(55462);
+        let destination = U256::from(55463);
+        let hotkey = U256::from(533462);
+        let netuid = add_dynamic_network(&hotkey, &coldkey);
+        SubnetTAO::<Test>::insert(netuid, TaoBalance::from(1_000_000_000_000_u64));
+        SubnetAlphaIn::<Test>::insert(netuid, AlphaBalance::from(1_000_000_000_000_u64));
+        add_balance_to_coldkey_account(&coldkey, 100_000_000_000_u64.into());
+        for key in [coldkey, destination] {
+            assert_ok!(SubtensorModule::set_cost_basis_tracking(
+                RuntimeOrigin::signed(key),
+                true
+            ));
+        }
+        assert_ok!(SubtensorModule::stake_into_subnet(
+            &hotkey,
+            &coldkey,
+            netuid,
+            10_000_000_000_u64.into(),
+            <Test as Config>::SwapInterface::max_price(),
+            false,
+        ));
+        let cost = u64::from(cost_basis_on(coldkey, netuid).cost_basis());
+
+        let alpha =
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid);
+        assert_ok!(SubtensorModule::do_transfer_stake(
+            RuntimeOrigin::signed(coldkey),
+            destination,
+            hotkey,
+            netuid,
+            netuid,
+            AlphaBalance::from(u64::from(alpha) / 2),
+        ));
+        let origin_cost = u64::from(cost_basis_on(coldkey, netuid).cost_basis());
+        let destination_info = cost_basis_on(destination, netuid);
+        assert_abs_diff_eq!(origin_cost, cost / 2, epsilon = 10);
+        assert_eq!(origin_cost + u64::from(destination_info.cost_basis()), cost);
+        assert_eq!(destination_info.realized_pnl(), 0);
+        assert_eq!(cost_basis_on(coldkey, netuid).realized_pnl(), 0);
+
+        // Recycled alpha is disposed of for nothing.
+        let recycled = SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey,
+            &destination,
+            netuid,
+        );
+        assert_ok!(SubtensorModule::do_recycle_alpha(
+            RuntimeOrigin::signed(destination),
+            hotkey,
+            recycled,
+            netuid,
+        ));
+        let destination_info = cost_basis_on(destination, netuid);
+        assert!(destination_info.cost_basis().is_zero());
+        assert_eq!(
+            destination_info.realized_pnl(),
+            -((cost - origin_cost) as i64)
+        );
+    });
+}
@@ -1,8 +1,9 @@
 #![allow(clippy::expect_used, clippy::unwrap_used)]
 use super::mock::*;
 use crate::subnets::symbols::{DEFAULT_SYMBOL, SYMBOLS};
+use crate::utils::rate_limiting::{Hyperparameter, TransactionType};
 use crate::*;
-use frame_support::{assert_err, assert_noop, assert_ok};
+use frame_support::{BoundedVec, assert_err, assert_noop, assert_ok};
 use frame_system::Config;
 use sp_core::U256;
 use std::collections::BTreeSet;
@@ -989,3 +990,275 @@ fn test_register_network_gives_owner_no_initial_alpha_distribution() {
         );
     });
 }
+
+#[test]
+fn test_scheduled_hyperparameter_change_applied_at_block() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        // Epochs run at blocks 10, 20, ...
+        add_network(netuid, 10, 0);
+        SubtensorModule::set_admin_freeze_window(1);
+        SubtensorModule::set_kappa(netuid, 32_767);
+        SubtensorModule::set_immunity_period(netuid, 100);
+
+        assert_ok!(SubtensorModule::do_schedule_hyperparameter_change(
+            netuid,
+            Hyperparameter::Kappa,
+            40_000,
+            15,
+            false
+        ));
+        assert_ok!(SubtensorModule::do_schedule_hyperparameter_change(
+            netuid,
+            Hyperparameter::ImmunityPeriod,
+            200,
+            18,
+            false
+        ));
+        // Scheduling again replaces the pending change
+        assert_ok!(SubtensorModule::do_schedule_hyperparameter_change(
+            netuid,
+            Hyperparameter::ImmunityPeriod,
+            300,
+            16,
+            false
+        ));
+        assert!(HyperparameterChangesDue::<Test>::get(18).is_empty());
+        assert_eq!(
+            SubtensorModule::get_scheduled_hyperparameter_changes(netuid),
+            vec![
+                (
+                    Hyperparameter::Kappa,
+                    ScheduledHyperparameterChange {
+                        value: 40_000,
+                        at_block: 15,
+                        by_owner: false,
+                    }
+                ),
+                (
+                    Hyperparameter::ImmunityPeriod,
+                    ScheduledHyperparameterChange {
+                        value: 300,
+                        at_block: 16,
+                        by_owner: false,
+                    }
+                ),
+            ]
+        );
+
+        run_to_block(14);
+        assert_eq!(SubtensorModule::get_kappa(netuid), 32_767);
+        run_to_block(15);
+        assert_eq!(SubtensorModule::get_kappa(netuid), 40_000);
+        assert_eq!(SubtensorModule::get_immunity_period(netuid), 100);
+        run_to_block(16);
+        assert_eq!(SubtensorModule::get_immunity_period(netuid), 300);
+        assert!(SubtensorModule::get_scheduled_hyperparameter_changes(netuid).is_empty());
+        assert!(!HyperparameterChangesDue::<Test>::contains_key(16));
+    });
+}
+
+#[test]
+fn test_schedule_hyperparameter_change_rejects_invalid() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        // Epochs run at blocks 10, 20, ...
+        add_network(netuid, 10, 0);
+        SubtensorModule::set_admin_freeze_window(1);
+
+        assert_noop!(
+            SubtensorModule::do_schedule_hyperparameter_change(
+                netuid,
+                Hyperparameter::Kappa,
+                40_000,
+                1,
+                false
+            ),
+            Error::<Test>::ScheduledBlockInPast
+        );
+        // Less than a tempo of notice
+        assert_noop!(
+            SubtensorModule::do_schedule_hyperparameter_change(
+                netuid,
+                Hyperparameter::Kappa,
+                40_000,
+                5,
+                false
+            ),
+            Error::<Test>::HyperparameterChangeNoticeTooShort
+        );
+        // The block of an epoch is in the freeze window
+        assert_noop!(
+            SubtensorModule::do_schedule_hyperparameter_change(
+                netuid,
+                Hyperparameter::Kappa,
+                40_000,
+                20,
+                false
+            ),
+            Error::<Test>::AdminActionProhibitedDuringWeightsWindow
+        );
+        assert_noop!(
+            SubtensorModule::do_schedule_hyperparameter_change(
+                netuid,
+                Hyperparameter::Kappa,
+                u64::from(u16::MAX) + 1,
+                15,
+                false
+            ),
+            Error::<Test>::HyperparameterValueOutOfRange
+        );
+        assert_noop!(
+            SubtensorModule::do_schedule_hyperparameter_change(
+                netuid,
+                Hyperparameter::Yuma3Enabled,
+                2,
+                15,
+                false
+            ),
+            Error::<Test>::HyperparameterValueOutOfRange
+        );
+        assert_noop!(
+            SubtensorModule::do_schedule_hyperparameter_change(
+                netuid,
+                Hyperparameter::MaxAllowedUids,
+                64,
+                15,
+                false
+            ),
+            Error::<Test>::HyperparameterNotSchedulable
+        );
+        assert_noop!(
+            SubtensorModule::do_schedule_hyperparameter_change(
+                NetUid::from(2),
+                Hyperparameter::Kappa,
+                40_000,
+                15,
+                false
+            ),
+            Error::<Test>::SubnetNotExists
+        );
+    });
+}
+
+#[test]
+fn test_hyperparameter_changes_due_bounded_per_block() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        // Epochs run at blocks 10, 20, ...
+        add_network(netuid, 10, 0);
+        SubtensorModule::set_admin_freeze_window(1);
+
+        let empty_weight = SubtensorModule::scheduled_hyperparameter_changes_weight(15);
+        HyperparameterChangesDue::<Test>::insert(
+            15,
+            BoundedVec::truncate_from(vec![
+                (NetUid::from(2), Hyperparameter::Rho);
+                MAX_HYPERPARAMETER_CHANGES_DUE_PER_BLOCK as usize
+            ]),
+        );
+
+        // The weight charged in on_initialize grows with the changes due
+        assert!(
+            SubtensorModule::scheduled_hyperparameter_changes_weight(15).any_gt(
+                empty_weight.saturating_mul(u64::from(MAX_HYPERPARAMETER_CHANGES_DUE_PER_BLOCK))
+            )
+        );
+
+        assert_noop!(
+            SubtensorModule::do_schedule_hyperparameter_change(
+                netuid,
+                Hyperparameter::Kappa,
+                40_000,
+                15,
+                false
+            ),
+            Error::<Test>::TooManyHyperparameterChangesDue
+        );
+        assert_ok!(SubtensorModule::do_schedule_hyperparameter_change(
+            netuid,
+            Hyperparameter::Kappa,
+            40_000,
+            16,
+            false
+        ));
+    });
+}
+
+#[test]
+fn test_scheduled_hyperparameter_change_checked_when_applied() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let owner = U256::from(1);
+        // Epochs run at blocks 10, 20, ...
+        add_network(netuid, 10, 0);
+        SubnetOwner::<Test>::insert(netuid, owner);
+        SubtensorModule::set_admin_freeze_window(1);
+        SubtensorModule::set_immunity_period(netuid, 100);
+        SubtensorModule::set_rho(netuid, 10);
+        SubtensorModule::set_bonds_penalty(netuid, 0);
+
+        assert_ok!(SubtensorModule::do_schedule_hyperparameter_change(
+            netuid,
+            Hyperparameter::ImmunityPeriod,
+            200,
+            15,
+            true
+        ));
+        assert_ok!(SubtensorModule::do_schedule_hyperparameter_change(
+            netuid,
+            Hyperparameter::Rho,
+            20,
+            16,
+            true
+        ));
+        assert_ok!(SubtensorModule::do_schedule_hyperparameter_change(
+            netuid,
+            Hyperparameter::BondsPenalty,
+            1_000,
+            17,
+            false
+        ));
+
+        // The owner sets the immunity period directly in the meantime
+        run_to_block(12);
+        SubtensorModule::record_owner_rl(
+            Some(owner),
+            netuid,
+            &[Hyperparameter::ImmunityPeriod.into()],
+        );
+
+        // Dropped: the owner rate limit of two tempos has not elapsed
+        run_to_block(15);
+        assert_eq!(SubtensorModule::get_immunity_period(netuid), 100);
+        System::assert_has_event(
+            Event::ScheduledHyperparameterChangeFailed {
+                netuid,
+                hyperparameter: Hyperparameter::ImmunityPeriod,
+                error: Error::<Test>::TxRateLimitExceeded.into(),
+            }
+            .into(),
+        );
+
+        // Applied, and the owner rate limit counts from now
+        run_to_block(16);
+        assert_eq!(SubtensorModule::get_rho(netuid), 20);
+        assert!(
+            !TransactionType::from(Hyperparameter::Rho)
+                .passes_rate_limit_on_subnet::<Test>(&owner, netuid)
+        );
+
+        // Dropped: the freeze window widened over the block
+        SubtensorModule::set_admin_freeze_window(5);
+        run_to_block(17);
+        assert_eq!(SubtensorModule::get_bonds_penalty(netuid), 0);
+        System::assert_has_event(
+            Event::ScheduledHyperparameterChangeFailed {
+                netuid,
+                hyperparameter: Hyperparameter::BondsPenalty,
+                error: Error::<Test>::AdminActionProhibitedDuringWeightsWindow.into(),
+            }
+            .into(),
+        );
+    });
+}
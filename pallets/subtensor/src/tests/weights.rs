@@ -19,7 +19,7 @@ use sp_runtime::{
 };
 use sp_std::collections::vec_deque::VecDeque;
 use substrate_fixed::types::I32F32;
-use subtensor_runtime_common::NetUidStorageIndex;
+use subtensor_runtime_common::{MechId, NetUidStorageIndex};
 use tle::{
     curves::drand::TinyBLS381,
     ibe::fullident::Identity,
@@ -30,6 +30,7 @@ use w3f_bls::EngineBLS;
 
 use super::mock::*;
 use crate::coinbase::reveal_commits::{LegacyWeightsTlockPayload, WeightsTlockPayload};
+use crate::rpc_info::weight_commits::WeightCommitState;
 use crate::*;
 /***************************
   pub fn set_weights() tests
@@ -719,7 +720,7 @@ fn test_check_length_allows_singleton() {
         let weights: Vec<u16> = Vec::from_iter((0..max_allowed).map(|id| id + 1));
 
         let expected = true;
-        let result = SubtensorModule::check_length(netuid, uid, &uids, &weights);
+        let result = SubtensorModule::check_length(netuid, MechId::MAIN, uid, &uids, &weights);
 
         assert_eq!(expected, result, "Failed get expected result");
     });
@@ -742,7 +743,7 @@ fn test_check_length_weights_length_exceeds_min_allowed() {
         let weights: Vec<u16> = Vec::from_iter((0..max_allowed).map(|id| id + 1));
 
         let expected = true;
-        let result = SubtensorModule::check_length(netuid, uid, &uids, &weights);
+        let result = SubtensorModule::check_length(netuid, MechId::MAIN, uid, &uids, &weights);
 
         assert_eq!(expected, result, "Failed get expected result");
     });
@@ -775,7 +776,7 @@ fn test_check_length_to_few_weights() {
         let uid: u16 = uids[0];
 
         let expected = false;
-        let result = SubtensorModule::check_length(netuid, uid, &uids, &weights);
+        let result = SubtensorModule::check_length(netuid, MechId::MAIN, uid, &uids, &weights);
 
         assert_eq!(expected, result, "Failed get expected result");
     });
@@ -829,7 +830,8 @@ fn test_max_weight_limited_allow_self_weights_to_exceed_max_weight_limit() {
         let weights: Vec<u16> = vec![0];
 
         let expected = true;
-        let result = SubtensorModule::max_weight_limited(netuid, uid, &uids, &weights);
+        let result =
+            SubtensorModule::max_weight_limited(netuid, MechId::MAIN, uid, &uids, &weights);
 
         assert_eq!(
             expected, result,
@@ -851,7 +853,8 @@ fn test_max_weight_limited_when_weight_limit_is_u16_max() {
         let weights: Vec<u16> = Vec::from_iter((0..max_allowed).map(|_id| u16::MAX));
 
         let expected = true;
-        let result = SubtensorModule::max_weight_limited(netuid, uid, &uids, &weights);
+        let result =
+            SubtensorModule::max_weight_limited(netuid, MechId::MAIN, uid, &uids, &weights);
 
         assert_eq!(
             expected, result,
@@ -6246,3 +6249,364 @@ fn test_batch_set_weights_item_failure_event_includes_netuid() {
         );
     });
 }
+
+fn setup_weights_follow(netuid: NetUid) -> (U256, U256) {
+    let leader = U256::from(1);
+    let follower = U256::from(2);
+    add_network_disable_commit_reveal(netuid, 13, 0);
+    SubtensorModule::set_min_allowed_weights(netuid, 0);
+    SubtensorModule::set_max_allowed_uids(netuid, 4);
+    SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+    SubtensorModule::set_stake_threshold(0);
+    register_ok_neuron(netuid, leader, U256::from(11), 0);
+    register_ok_neuron(netuid, follower, U256::from(12), 65555);
+    register_ok_neuron(netuid, U256::from(3), U256::from(13), 75555);
+    register_ok_neuron(netuid, U256::from(4), U256::from(14), 85555);
+    SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
+    SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
+    (leader, follower)
+}
+
+#[test]
+fn test_follow_weights_copies_leader_row() {
+    new_test_ext(0).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let netuid_index = NetUidStorageIndex::from(netuid);
+        let (leader, follower) = setup_weights_follow(netuid);
+
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(leader),
+            netuid,
+            vec![2, 3],
+            vec![1, 2],
+            0
+        ));
+        assert_ok!(SubtensorModule::follow_weights(
+            RuntimeOrigin::signed(follower),
+            netuid,
+            leader,
+            0
+        ));
+        assert_eq!(
+            WeightsLeader::<Test>::get(netuid, follower),
+            Some((leader, 0))
+        );
+        System::assert_last_event(RuntimeEvent::SubtensorModule(Event::WeightsFollowed {
+            netuid,
+            hotkey: follower,
+            leader,
+            lag_epochs: 0,
+        }));
+
+        step_block(1);
+        SubtensorModule::apply_followed_weights(netuid, MechId::MAIN);
+        assert_eq!(
+            Weights::<Test>::get(netuid_index, 1),
+            Weights::<Test>::get(netuid_index, 0)
+        );
+        let last_update = SubtensorModule::get_last_update(netuid_index);
+        assert_eq!(last_update[1], last_update[0]);
+
+        // Setting its own weights stops following
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(follower),
+            netuid,
+            vec![3],
+            vec![1],
+            0
+        ));
+        assert!(WeightsLeader::<Test>::get(netuid, follower).is_none());
+        assert!(FollowedWeightRows::<Test>::get(netuid_index, follower).is_empty());
+        SubtensorModule::apply_followed_weights(netuid, MechId::MAIN);
+        assert_eq!(Weights::<Test>::get(netuid_index, 1), vec![(3, u16::MAX)]);
+    });
+}
+
+#[test]
+fn test_follow_weights_lags_behind_leader() {
+    new_test_ext(0).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let netuid_index = NetUidStorageIndex::from(netuid);
+        let (leader, follower) = setup_weights_follow(netuid);
+
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(leader),
+            netuid,
+            vec![2],
+            vec![1],
+            0
+        ));
+        let first_row = Weights::<Test>::get(netuid_index, 0);
+        assert_ok!(SubtensorModule::follow_weights(
+            RuntimeOrigin::signed(follower),
+            netuid,
+            leader,
+            1
+        ));
+
+        // Not enough epochs yet for the lagged row
+        SubtensorModule::apply_followed_weights(netuid, MechId::MAIN);
+        assert!(Weights::<Test>::get(netuid_index, 1).is_empty());
+
+        step_block(1);
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(leader),
+            netuid,
+            vec![3],
+            vec![1],
+            0
+        ));
+        SubtensorModule::apply_followed_weights(netuid, MechId::MAIN);
+        assert_eq!(Weights::<Test>::get(netuid_index, 1), first_row);
+        assert_eq!(
+            FollowedWeightRows::<Test>::get(netuid_index, follower).len(),
+            2
+        );
+
+        SubtensorModule::apply_followed_weights(netuid, MechId::MAIN);
+        assert_eq!(
+            Weights::<Test>::get(netuid_index, 1),
+            Weights::<Test>::get(netuid_index, 0)
+        );
+    });
+}
+
+#[test]
+fn test_follow_weights_validation() {
+    new_test_ext(0).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let (leader, follower) = setup_weights_follow(netuid);
+
+        assert_err!(
+            SubtensorModule::follow_weights(RuntimeOrigin::signed(follower), netuid, follower, 0),
+            Error::<Test>::CannotFollowOwnWeights
+        );
+        assert_err!(
+            SubtensorModule::follow_weights(
+                RuntimeOrigin::signed(follower),
+                netuid,
+                leader,
+                MAX_WEIGHTS_FOLLOW_LAG_EPOCHS + 1
+            ),
+            Error::<Test>::WeightsFollowLagTooHigh
+        );
+        assert_err!(
+            SubtensorModule::follow_weights(
+                RuntimeOrigin::signed(follower),
+                netuid,
+                U256::from(99),
+                0
+            ),
+            Error::<Test>::HotKeyNotRegisteredInSubNet
+        );
+        assert_err!(
+            SubtensorModule::follow_weights(
+                RuntimeOrigin::signed(leader),
+                NetUid::ROOT,
+                follower,
+                0
+            ),
+            Error::<Test>::CanNotSetRootNetworkWeights
+        );
+
+        // No chains of followers
+        assert_ok!(SubtensorModule::follow_weights(
+            RuntimeOrigin::signed(follower),
+            netuid,
+            leader,
+            0
+        ));
+        assert_err!(
+            SubtensorModule::follow_weights(
+                RuntimeOrigin::signed(U256::from(3)),
+                netuid,
+                follower,
+                0
+            ),
+            Error::<Test>::WeightsLeaderIsFollowing
+        );
+    });
+}
+
+#[test]
+fn test_follow_weights_lag_covers_reveal_period() {
+    new_test_ext(0).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let (leader, follower) = setup_weights_follow(netuid);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+        assert_ok!(SubtensorModule::set_reveal_period(netuid, 2));
+
+        assert_err!(
+            SubtensorModule::follow_weights(RuntimeOrigin::signed(follower), netuid, leader, 1),
+            Error::<Test>::WeightsFollowLagBelowRevealPeriod
+        );
+        assert_ok!(SubtensorModule::follow_weights(
+            RuntimeOrigin::signed(follower),
+            netuid,
+            leader,
+            2
+        ));
+
+        // Raising the reveal period above the lag stops following at the next epoch
+        assert_ok!(SubtensorModule::set_reveal_period(netuid, 3));
+        SubtensorModule::apply_followed_weights(netuid, MechId::MAIN);
+        assert!(WeightsLeader::<Test>::get(netuid, follower).is_none());
+        assert!(Weights::<Test>::get(NetUidStorageIndex::from(netuid), 1).is_empty());
+    });
+}
+
+#[test]
+fn test_follow_weights_lag_covers_mechanism_reveal_period() {
+    new_test_ext(0).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let (leader, follower) = setup_weights_follow(netuid);
+        MechanismCountCurrent::<Test>::insert(netuid, MechId::from(2u8));
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+        assert_ok!(SubtensorModule::set_reveal_period(netuid, 1));
+        assert_ok!(SubtensorModule::do_set_mechanism_hyperparams(
+            netuid,
+            MechId::from(1u8),
+            MechanismHyperparams {
+                reveal_period: Some(3),
+                ..Default::default()
+            },
+        ));
+
+        // The lag covers the subnet reveal period but not the override of mechanism 1
+        assert_err!(
+            SubtensorModule::follow_weights(RuntimeOrigin::signed(follower), netuid, leader, 2),
+            Error::<Test>::WeightsFollowLagBelowRevealPeriod
+        );
+        assert_ok!(SubtensorModule::follow_weights(
+            RuntimeOrigin::signed(follower),
+            netuid,
+            leader,
+            3
+        ));
+
+        // Raising the override above the lag stops following, even on the main mechanism
+        assert_ok!(SubtensorModule::do_set_mechanism_hyperparams(
+            netuid,
+            MechId::from(1u8),
+            MechanismHyperparams {
+                reveal_period: Some(4),
+                ..Default::default()
+            },
+        ));
+        SubtensorModule::apply_followed_weights(netuid, MechId::MAIN);
+        assert!(WeightsLeader::<Test>::get(netuid, follower).is_none());
+    });
+}
+
+#[test]
+fn test_follow_weights_followers_per_subnet_bounded() {
+    new_test_ext(0).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let (leader, follower) = setup_weights_follow(netuid);
+        for i in 1..MAX_WEIGHTS_FOLLOWERS_PER_SUBNET {
+            WeightsLeader::<Test>::insert(netuid, U256::from(1_000 + i), (leader, 0));
+        }
+        assert_ok!(SubtensorModule::follow_weights(
+            RuntimeOrigin::signed(follower),
+            netuid,
+            leader,
+            0
+        ));
+
+        // A new follower is rejected, while an existing one can change its lag
+        assert_err!(
+            SubtensorModule::follow_weights(
+                RuntimeOrigin::signed(U256::from(3)),
+                netuid,
+                leader,
+                0
+            ),
+            Error::<Test>::TooManyWeightsFollowers
+        );
+        assert_ok!(SubtensorModule::follow_weights(
+            RuntimeOrigin::signed(follower),
+            netuid,
+            leader,
+            MAX_WEIGHTS_FOLLOW_LAG_EPOCHS
+        ));
+
+        // The queue of a follower never exceeds the maximum lag
+        for _ in 0..MAX_FOLLOWED_WEIGHT_ROWS + 3 {
+            SubtensorModule::apply_followed_weights(netuid, MechId::MAIN);
+        }
+        assert_eq!(
+            FollowedWeightRows::<Test>::get(NetUidStorageIndex::from(netuid), follower).len(),
+            MAX_FOLLOWED_WEIGHT_ROWS as usize
+        );
+    });
+}
+
+#[test]
+fn test_get_weight_commit_status_reports_reveal_window() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let hotkey = U256::from(1);
+        let other = U256::from(2);
+        let netuid_index = NetUidStorageIndex::from(netuid);
+        add_network_disable_commit_reveal(netuid, 100, 0);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+        assert_ok!(SubtensorModule::set_reveal_period(netuid, 1));
+
+        // Pin the scheduler: epoch 10 ran at block 1000, the next one is due at block 1100.
+        System::set_block_number(1050);
+        SubnetEpochIndex::<Test>::insert(netuid, 10);
+        LastEpochBlock::<Test>::insert(netuid, 1000);
+        PendingEpochAt::<Test>::insert(netuid, 0);
+
+        let commit_hash = H256::repeat_byte(1);
+        WeightCommits::<Test>::insert(
+            netuid_index,
+            hotkey,
+            VecDeque::from(vec![(commit_hash, 10, 1010, 1110)]),
+        );
+        TimelockedWeightCommits::<Test>::insert(
+            netuid_index,
+            10,
+            VecDeque::from(vec![
+                (hotkey, 1020, BoundedVec::default(), 42),
+                (other, 1020, BoundedVec::default(), 42),
+            ]),
+        );
+
+        let statuses = SubtensorModule::get_weight_commit_status(hotkey, netuid);
+        assert_eq!(statuses.len(), 2);
+        assert_eq!(statuses[0].commit_hash, Some(commit_hash));
+        assert_eq!(statuses[0].reveal_round, None);
+        assert_eq!(statuses[1].commit_hash, None);
+        assert_eq!(statuses[1].reveal_round, Some(Compact(42)));
+        assert_eq!(statuses[1].commit_block, Compact(1020));
+        for status in &statuses {
+            assert_eq!(status.reveal_epoch, Compact(11));
+            assert_eq!(status.state, WeightCommitState::Waiting);
+            assert_eq!(status.reveal_window_start, Some(Compact(1100)));
+            assert_eq!(status.reveal_window_end, Some(Compact(1199)));
+        }
+
+        // Epoch 11 ran at block 1100: the commits are in their reveal epoch.
+        System::set_block_number(1150);
+        SubnetEpochIndex::<Test>::insert(netuid, 11);
+        LastEpochBlock::<Test>::insert(netuid, 1100);
+        let statuses = SubtensorModule::get_weight_commit_status(hotkey, netuid);
+        for status in &statuses {
+            assert_eq!(status.state, WeightCommitState::Revealable);
+            assert_eq!(status.reveal_window_start, Some(Compact(1100)));
+            assert_eq!(status.reveal_window_end, Some(Compact(1199)));
+        }
+
+        // Epoch 12 ran at block 1200: the commits can no longer be revealed.
+        System::set_block_number(1250);
+        SubnetEpochIndex::<Test>::insert(netuid, 12);
+        LastEpochBlock::<Test>::insert(netuid, 1200);
+        let statuses = SubtensorModule::get_weight_commit_status(hotkey, netuid);
+        for status in &statuses {
+            assert_eq!(status.state, WeightCommitState::Expired);
+        }
+
+        assert!(SubtensorModule::get_weight_commit_status(U256::from(3), netuid).is_empty());
+    });
+}
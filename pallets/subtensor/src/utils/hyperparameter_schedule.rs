@@ -0,0 +1,268 @@
+use super::*;
+use crate::utils::rate_limiting::{Hyperparameter, TransactionType};
+use sp_runtime::DispatchError;
+use subtensor_runtime_common::NetUid;
+
+impl<T: Config> Pallet<T> {
+    /// Announces that `hyperparameter` of `netuid` is set to `value` at `at_block`.
+    ///
+    /// The change must be announced at least one tempo ahead and cannot land in the admin freeze
+    /// window, nor in a block that already has `MAX_HYPERPARAMETER_CHANGES_DUE_PER_BLOCK` changes
+    /// scheduled. A change already scheduled for the same hyperparameter is replaced. The value is
+    /// checked here so that the announcement only carries values that can be applied, and
+    /// checked again when the block is reached, along with the freeze window and, for changes
+    /// scheduled by the subnet owner, the owner rate limit.
+    pub fn do_schedule_hyperparameter_change(
+        netuid: NetUid,
+        hyperparameter: Hyperparameter,
+        value: u64,
+        at_block: u64,
+        by_owner: bool,
+    ) -> DispatchResult {
+        ensure!(Self::if_subnet_exist(netuid), Error::<T>::SubnetNotExists);
+        let now = Self::get_current_block_as_u64();
+        ensure!(at_block > now, Error::<T>::ScheduledBlockInPast);
+        ensure!(
+            at_block.saturating_sub(now) >= u64::from(Self::get_tempo(netuid)),
+            Error::<T>::HyperparameterChangeNoticeTooShort
+        );
+        ensure!(
+            !Self::is_in_projected_admin_freeze_window(netuid, at_block),
+            Error::<T>::AdminActionProhibitedDuringWeightsWindow
+        );
+        Self::ensure_hyperparameter_value(hyperparameter, value)?;
+
+        if let Some(previous) = ScheduledHyperparameterChanges::<T>::get(netuid, hyperparameter) {
+            HyperparameterChangesDue::<T>::mutate_exists(previous.at_block, |maybe_due| {
+                if let Some(due) = maybe_due {
+                    due.retain(|entry| *entry != (netuid, hyperparameter));
+                    if due.is_empty() {
+                        *maybe_due = None;
+                    }
+                }
+            });
+        }
+
+        HyperparameterChangesDue::<T>::try_append(at_block, (netuid, hyperparameter))
+            .map_err(|_| Error::<T>::TooManyHyperparameterChangesDue)?;
+        ScheduledHyperparameterChanges::<T>::insert(
+            netuid,
+            hyperparameter,
+            ScheduledHyperparameterChange {
+                value,
+                at_block,
+                by_owner,
+            },
+        );
+
+        Self::deposit_event(Event::HyperparameterChangeScheduled {
+            netuid,
+            hyperparameter,
+            value,
+            at_block,
+        });
+        Ok(())
+    }
+
+    /// Weight of applying the hyperparameter changes scheduled for `block`: per change, the
+    /// schedule, the subnet, its admin window and owner rate limit are read, then the schedule,
+    /// the hyperparameter and the rate limit are written.
+    pub fn scheduled_hyperparameter_changes_weight(block: u64) -> Weight {
+        let due = HyperparameterChangesDue::<T>::decode_len(block).unwrap_or_default();
+        T::DbWeight::get()
+            .reads_writes(9, 3)
+            .saturating_mul(due as u64)
+            .saturating_add(T::DbWeight::get().reads_writes(1, 1))
+    }
+
+    /// Applies the hyperparameter changes scheduled for `block`. Changes that no longer pass
+    /// validation are dropped with a `ScheduledHyperparameterChangeFailed` event.
+    pub fn apply_scheduled_hyperparameter_changes(block: u64) {
+        for (netuid, hyperparameter) in HyperparameterChangesDue::<T>::take(block) {
+            let Some(change) = ScheduledHyperparameterChanges::<T>::get(netuid, hyperparameter)
+            else {
+                continue;
+            };
+            if change.at_block != block {
+                continue;
+            }
+            ScheduledHyperparameterChanges::<T>::remove(netuid, hyperparameter);
+
+            match Self::apply_scheduled_hyperparameter_change(netuid, hyperparameter, change) {
+                Ok(()) => Self::deposit_event(Event::ScheduledHyperparameterChangeApplied {
+                    netuid,
+                    hyperparameter,
+                    value: change.value,
+                }),
+                Err(error) => {
+                    log::debug!(
+                        "ScheduledHyperparameterChangeFailed( netuid: {netuid:?}, hyperparameter: {hyperparameter:?}, error: {error:?} )"
+                    );
+                    Self::deposit_event(Event::ScheduledHyperparameterChangeFailed {
+                        netuid,
+                        hyperparameter,
+                        error,
+                    })
+                }
+            }
+        }
+    }
+
+    /// Pending hyperparameter changes of a subnet, ordered by the block they apply at.
+    pub fn get_scheduled_hyperparameter_changes(
+        netuid: NetUid,
+    ) -> Vec<(Hyperparameter, ScheduledHyperparameterChange)> {
+        let mut changes: Vec<_> =
+            ScheduledHyperparameterChanges::<T>::iter_prefix(netuid).collect();
+        changes.sort_by_key(|(_, change)| change.at_block);
+        changes
+    }
+
+    /// Checks that `value` fits `hyperparameter` and that the hyperparameter can be scheduled.
+    fn ensure_hyperparameter_value(hyperparameter: Hyperparameter, value: u64) -> DispatchResult {
+        match hyperparameter {
+            Hyperparameter::Kappa
+            | Hyperparameter::Rho
+            | Hyperparameter::ImmunityPeriod
+            | Hyperparameter::MinAllowedWeights
+            | Hyperparameter::BondsPenalty => {
+                Self::hyperparameter_u16(value)?;
+            }
+            Hyperparameter::ActivityCutoff => {
+                ensure!(
+                    Self::hyperparameter_u16(value)? >= MinActivityCutoff::<T>::get(),
+                    Error::<T>::ActivityCutoffTooLow
+                );
+            }
+            Hyperparameter::WeightCommitInterval => {
+                ensure!(
+                    value <= MAX_COMMIT_REVEAL_PEROIDS,
+                    Error::<T>::RevealPeriodTooLarge
+                );
+                ensure!(
+                    value >= MIN_COMMIT_REVEAL_PEROIDS,
+                    Error::<T>::RevealPeriodTooSmall
+                );
+            }
+            Hyperparameter::ServingRateLimit
+            | Hyperparameter::MaxDifficulty
+            | Hyperparameter::AdjustmentAlpha
+            | Hyperparameter::BondsMovingAverage => {}
+            Hyperparameter::CommitRevealEnabled
+            | Hyperparameter::LiquidAlphaEnabled
+            | Hyperparameter::TransferEnabled
+            | Hyperparameter::Yuma3Enabled
+            | Hyperparameter::BondsResetEnabled => {
+                Self::hyperparameter_bool(value)?;
+            }
+            _ => return Err(Error::<T>::HyperparameterNotSchedulable.into()),
+        }
+        Ok(())
+    }
+
+    /// Applies a scheduled change under the checks the owner would face setting it now: the
+    /// admin freeze window and, for changes scheduled by the owner, the owner rate limit.
+    fn apply_scheduled_hyperparameter_change(
+        netuid: NetUid,
+        hyperparameter: Hyperparameter,
+        change: ScheduledHyperparameterChange,
+    ) -> DispatchResult {
+        ensure!(Self::if_subnet_exist(netuid), Error::<T>::SubnetNotExists);
+        Self::ensure_admin_window_open(netuid)?;
+        let owner = SubnetOwner::<T>::get(netuid);
+        let rate_limit = TransactionType::from(hyperparameter);
+        ensure!(
+            !change.by_owner || rate_limit.passes_rate_limit_on_subnet::<T>(&owner, netuid),
+            Error::<T>::TxRateLimitExceeded
+        );
+
+        Self::apply_hyperparameter_value(netuid, hyperparameter, change.value)?;
+
+        if change.by_owner {
+            Self::record_owner_rl(Some(owner), netuid, &[rate_limit]);
+        }
+        Ok(())
+    }
+
+    /// Whether `block` falls in the admin freeze window, projecting the epochs of the subnet
+    /// from the last one at the current tempo.
+    fn is_in_projected_admin_freeze_window(netuid: NetUid, block: u64) -> bool {
+        let tempo = u64::from(Self::get_tempo(netuid));
+        if tempo == 0 {
+            return false;
+        }
+        let pending = PendingEpochAt::<T>::get(netuid);
+        if pending > 0 && pending > block {
+            return true;
+        }
+        let elapsed = block
+            .saturating_sub(LastEpochBlock::<T>::get(netuid))
+            .checked_rem(tempo)
+            .unwrap_or_default();
+        let remaining = if elapsed == 0 {
+            0
+        } else {
+            tempo.saturating_sub(elapsed)
+        };
+        remaining < u64::from(AdminFreezeWindow::<T>::get())
+    }
+
+    fn apply_hyperparameter_value(
+        netuid: NetUid,
+        hyperparameter: Hyperparameter,
+        value: u64,
+    ) -> DispatchResult {
+        Self::ensure_hyperparameter_value(hyperparameter, value)?;
+
+        match hyperparameter {
+            Hyperparameter::Kappa => Self::set_kappa(netuid, Self::hyperparameter_u16(value)?),
+            Hyperparameter::Rho => Self::set_rho(netuid, Self::hyperparameter_u16(value)?),
+            Hyperparameter::ImmunityPeriod => {
+                Self::set_immunity_period(netuid, Self::hyperparameter_u16(value)?)
+            }
+            Hyperparameter::MinAllowedWeights => {
+                Self::set_min_allowed_weights(netuid, Self::hyperparameter_u16(value)?)
+            }
+            Hyperparameter::BondsPenalty => {
+                Self::set_bonds_penalty(netuid, Self::hyperparameter_u16(value)?)
+            }
+            Hyperparameter::ActivityCutoff => {
+                Self::set_activity_cutoff(netuid, Self::hyperparameter_u16(value)?)
+            }
+            Hyperparameter::WeightCommitInterval => Self::set_reveal_period(netuid, value)?,
+            Hyperparameter::ServingRateLimit => Self::set_serving_rate_limit(netuid, value),
+            Hyperparameter::MaxDifficulty => Self::set_max_difficulty(netuid, value),
+            Hyperparameter::AdjustmentAlpha => Self::set_adjustment_alpha(netuid, value),
+            Hyperparameter::BondsMovingAverage => Self::set_bonds_moving_average(netuid, value),
+            Hyperparameter::CommitRevealEnabled => {
+                Self::set_commit_reveal_weights_enabled(netuid, Self::hyperparameter_bool(value)?)
+            }
+            Hyperparameter::LiquidAlphaEnabled => {
+                Self::set_liquid_alpha_enabled(netuid, Self::hyperparameter_bool(value)?)
+            }
+            Hyperparameter::TransferEnabled => {
+                Self::toggle_transfer(netuid, Self::hyperparameter_bool(value)?)?
+            }
+            Hyperparameter::Yuma3Enabled => {
+                Self::set_yuma3_enabled(netuid, Self::hyperparameter_bool(value)?)
+            }
+            Hyperparameter::BondsResetEnabled => {
+                Self::set_bonds_reset(netuid, Self::hyperparameter_bool(value)?)
+            }
+            _ => return Err(Error::<T>::HyperparameterNotSchedulable.into()),
+        }
+        Ok(())
+    }
+
+    fn hyperparameter_u16(value: u64) -> Result<u16, DispatchError> {
+        u16::try_from(value).map_err(|_| Error::<T>::HyperparameterValueOutOfRange.into())
+    }
+
+    fn hyperparameter_bool(value: u64) -> Result<bool, DispatchError> {
+        match value {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(Error::<T>::HyperparameterValueOutOfRange.into()),
+        }
+    }
+}
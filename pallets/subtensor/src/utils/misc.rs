@@ -850,6 +850,14 @@ impl<T: Config> Pallet<T> {
         Yuma3On::<T>::get(netuid)
     }
 
+    pub fn set_consensus_algorithm(netuid: NetUid, algorithm: ConsensusAlgorithm) {
+        SubnetConsensusAlgorithm::<T>::insert(netuid, algorithm);
+    }
+
+    pub fn get_consensus_algorithm(netuid: NetUid) -> ConsensusAlgorithm {
+        SubnetConsensusAlgorithm::<T>::get(netuid)
+    }
+
     pub fn get_subtoken_enabled(netuid: NetUid) -> bool {
         SubtokenEnabled::<T>::get(netuid)
     }
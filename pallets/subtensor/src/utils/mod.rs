@@ -1,5 +1,6 @@
 use super::*;
 pub mod evm;
+pub mod hyperparameter_schedule;
 pub mod identity;
 pub mod misc;
 pub mod rate_limiting;
@@ -1,3 +1,4 @@
+use codec::DecodeWithMemTracking;
 use subtensor_runtime_common::NetUid;
 
 use super::*;
@@ -177,7 +178,7 @@ impl From<Hyperparameter> for TransactionType {
     }
 }
 
-#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo)]
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, Debug, TypeInfo)]
 #[non_exhaustive]
 pub enum Hyperparameter {
     Unknown = 0,
@@ -212,6 +213,8 @@ pub enum Hyperparameter {
     MinChildkeyTake = 29,
     ActivityCutoffFactorMilli = 30,
     TriggerEpoch = 31,
+    ConsensusAlgorithm = 32,
+    MechanismHyperparams = 33,
 }
 
 impl<T: Config> Pallet<T> {
@@ -10,10 +10,10 @@ use jsonrpsee::{
 };
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
-use sp_runtime::traits::Block as BlockT;
+use sp_runtime::{AccountId32, traits::Block as BlockT};
 use subtensor_runtime_common::{AlphaBalance, NetUid, TaoBalance};
 
-pub use pallet_subtensor_swap_runtime_api::{SubnetPrice, SwapRuntimeApi};
+pub use pallet_subtensor_swap_runtime_api::{LiquidityPositionInfo, SubnetPrice, SwapRuntimeApi};
 
 #[rpc(client, server)]
 pub trait SwapRpcApi<BlockHash> {
@@ -35,6 +35,47 @@ pub trait SwapRpcApi<BlockHash> {
         alpha: AlphaBalance,
         at: Option<BlockHash>,
     ) -> RpcResult<Vec<u8>>;
+    #[method(name = "swap_simSwapAlphaForAlpha")]
+    fn sim_swap_alpha_for_alpha(
+        &self,
+        origin_netuid: NetUid,
+        destination_netuid: NetUid,
+        alpha: AlphaBalance,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "swap_simSwapAlphaForAlphaLimit")]
+    fn sim_swap_alpha_for_alpha_limit(
+        &self,
+        origin_netuid: NetUid,
+        destination_netuid: NetUid,
+        alpha: AlphaBalance,
+        min_alpha_out: AlphaBalance,
+        allow_partial: bool,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "swap_liquidityPositions")]
+    fn liquidity_positions(
+        &self,
+        netuid: NetUid,
+        coldkey: AccountId32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "swap_priceCandles")]
+    fn price_candles(
+        &self,
+        netuid: NetUid,
+        count: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "swap_currentPriceCandle")]
+    fn current_price_candle(&self, netuid: NetUid, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "swap_twapPrice")]
+    fn twap_price(
+        &self,
+        netuid: NetUid,
+        candles: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<u64>>;
 }
 
 /// Error type of this RPC api.
@@ -141,4 +182,115 @@ where
             .into()),
         }
     }
+
+    fn sim_swap_alpha_for_alpha(
+        &self,
+        origin_netuid: NetUid,
+        destination_netuid: NetUid,
+        alpha: AlphaBalance,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.sim_swap_alpha_for_alpha(at, origin_netuid, destination_netuid, alpha) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!(
+                "Unable to simulate alpha -> alpha swap: {e:?}"
+            ))
+            .into()),
+        }
+    }
+
+    fn sim_swap_alpha_for_alpha_limit(
+        &self,
+        origin_netuid: NetUid,
+        destination_netuid: NetUid,
+        alpha: AlphaBalance,
+        min_alpha_out: AlphaBalance,
+        allow_partial: bool,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.sim_swap_alpha_for_alpha_limit(
+            at,
+            origin_netuid,
+            destination_netuid,
+            alpha,
+            min_alpha_out,
+            allow_partial,
+        ) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!(
+                "Unable to simulate alpha -> alpha swap with limit: {e:?}"
+            ))
+            .into()),
+        }
+    }
+
+    fn liquidity_positions(
+        &self,
+        netuid: NetUid,
+        coldkey: AccountId32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.liquidity_positions(at, netuid, coldkey) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get liquidity positions: {e:?}")).into())
+            }
+        }
+    }
+
+    fn price_candles(
+        &self,
+        netuid: NetUid,
+        count: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.price_candles(at, netuid, count) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get price candles: {e:?}")).into())
+            }
+        }
+    }
+
+    fn current_price_candle(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.current_price_candle(at, netuid) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!(
+                "Unable to get current price candle: {e:?}"
+            ))
+            .into()),
+        }
+    }
+
+    fn twap_price(
+        &self,
+        netuid: NetUid,
+        candles: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<u64>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.twap_price(at, netuid, candles)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get TWAP price: {e:?}")).into())
+    }
 }
@@ -2,9 +2,11 @@
 
 use frame_support::pallet_prelude::*;
 use serde::{Deserialize, Serialize};
+use sp_runtime::AccountId32;
 use sp_std::vec::Vec;
 use subtensor_macros::freeze_struct;
 use subtensor_runtime_common::{AlphaBalance, NetUid, TaoBalance};
+pub use subtensor_swap_interface::{AlphaSwapResult, PriceCandle};
 
 #[freeze_struct("8e70f7cc0b118c6")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
@@ -24,11 +26,34 @@ pub struct SubnetPrice {
     pub price: u64,
 }
 
+/// A user liquidity position valued at the current reserves.
+#[freeze_struct("5f2b282f89391ba3")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct LiquidityPositionInfo<AccountId> {
+    pub position_id: u128,
+    pub hotkey: AccountId,
+    pub liquidity: u64,
+    /// TAO paid out if the position were closed now
+    pub tao: TaoBalance,
+    /// Alpha paid out if the position were closed now
+    pub alpha: AlphaBalance,
+    /// TAO fees accrued since the last payout
+    pub fees_tao: TaoBalance,
+    /// Alpha fees accrued since the last payout
+    pub fees_alpha: AlphaBalance,
+}
+
 sp_api::decl_runtime_apis! {
     pub trait SwapRuntimeApi {
         fn current_alpha_price(netuid: NetUid) -> u64;
         fn current_alpha_price_all() -> Vec<SubnetPrice>;
         fn sim_swap_tao_for_alpha(netuid: NetUid, tao: TaoBalance) -> SimSwapResult;
         fn sim_swap_alpha_for_tao(netuid: NetUid, alpha: AlphaBalance) -> SimSwapResult;
+        fn sim_swap_alpha_for_alpha(origin_netuid: NetUid, destination_netuid: NetUid, alpha: AlphaBalance) -> AlphaSwapResult;
+        fn sim_swap_alpha_for_alpha_limit(origin_netuid: NetUid, destination_netuid: NetUid, alpha: AlphaBalance, min_alpha_out: AlphaBalance, allow_partial: bool) -> AlphaSwapResult;
+        fn liquidity_positions(netuid: NetUid, coldkey: AccountId32) -> Vec<LiquidityPositionInfo<AccountId32>>;
+        fn price_candles(netuid: NetUid, count: u32) -> Vec<PriceCandle>;
+        fn current_price_candle(netuid: NetUid) -> Option<PriceCandle>;
+        fn twap_price(netuid: NetUid, candles: u32) -> Option<u64>;
     }
 }
@@ -3,10 +3,42 @@
 #![allow(clippy::multiple_bound_locations)]
 
 use frame_benchmarking::v2::*;
+use frame_support::traits::Get;
 use frame_system::RawOrigin;
-use subtensor_runtime_common::NetUid;
+use subtensor_runtime_common::{AlphaBalance, NetUid, TaoBalance};
 
-use crate::pallet::{Call, Config, Pallet};
+use crate::pallet::{
+    BenchmarkHelper, Call, Config, CurrentPriceCandle, LiquidityPositions, PalSwapInitialized,
+    Pallet, PositionId, PriceCandleCount, UserLiquidityEnabled,
+};
+use subtensor_swap_interface::PriceCandle;
+
+/// Creates a liquidity provider with a position of 1 TAO and 1 alpha on `netuid`.
+fn setup_position<T: Config>(netuid: NetUid) -> (T::AccountId, T::AccountId, PositionId) {
+    let coldkey: T::AccountId = account("coldkey", 0, 0);
+    let hotkey: T::AccountId = account("hotkey", 0, 0);
+    let amount = 1_000_000_000_u64;
+    T::BenchmarkHelper::setup_liquidity_provider(
+        &coldkey,
+        &hotkey,
+        netuid,
+        TaoBalance::from(amount.saturating_mul(2)),
+        AlphaBalance::from(amount.saturating_mul(2)),
+    );
+    UserLiquidityEnabled::<T>::insert(netuid, true);
+    Pallet::<T>::add_liquidity(
+        RawOrigin::Signed(coldkey.clone()).into(),
+        hotkey.clone(),
+        netuid,
+        TaoBalance::from(amount),
+        AlphaBalance::from(amount),
+    )
+    .unwrap();
+    let position_id = LiquidityPositions::<T>::iter_key_prefix((netuid, coldkey.clone()))
+        .next()
+        .unwrap();
+    (coldkey, hotkey, position_id)
+}
 
 #[benchmarks(where T: Config)]
 mod benchmarks {
@@ -21,5 +53,100 @@ mod benchmarks {
         _(RawOrigin::Root, netuid, rate);
     }
 
+    #[benchmark]
+    fn toggle_user_liquidity() {
+        let netuid = NetUid::from(1);
+        T::BenchmarkHelper::setup_subnet(netuid);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, netuid, true);
+
+        assert!(UserLiquidityEnabled::<T>::get(netuid));
+    }
+
+    #[benchmark]
+    fn add_liquidity() {
+        let netuid = NetUid::from(1);
+        let coldkey: T::AccountId = account("coldkey", 0, 0);
+        let hotkey: T::AccountId = account("hotkey", 0, 0);
+        let amount = 1_000_000_000_u64;
+        T::BenchmarkHelper::setup_liquidity_provider(
+            &coldkey,
+            &hotkey,
+            netuid,
+            TaoBalance::from(amount),
+            AlphaBalance::from(amount),
+        );
+        UserLiquidityEnabled::<T>::insert(netuid, true);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(coldkey.clone()),
+            hotkey,
+            netuid,
+            TaoBalance::from(amount),
+            AlphaBalance::from(amount),
+        );
+
+        assert_eq!(
+            LiquidityPositions::<T>::iter_key_prefix((netuid, coldkey.clone())).count(),
+            1
+        );
+    }
+
+    #[benchmark]
+    fn remove_liquidity() {
+        let netuid = NetUid::from(1);
+        let (coldkey, hotkey, position_id) = setup_position::<T>(netuid);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(coldkey.clone()),
+            hotkey,
+            netuid,
+            position_id,
+        );
+
+        assert!(!LiquidityPositions::<T>::contains_key((
+            netuid,
+            &coldkey,
+            position_id
+        )));
+    }
+
+    #[benchmark]
+    fn modify_position() {
+        let netuid = NetUid::from(1);
+        let (coldkey, hotkey, position_id) = setup_position::<T>(netuid);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(coldkey),
+            hotkey,
+            netuid,
+            position_id,
+            100_000_i64,
+        );
+    }
+
+    #[benchmark]
+    fn sample_price_candles(n: Linear<1, 64>) {
+        // Every pool has a candle of the previous period open, so each sample closes one
+        for i in 0..n {
+            let netuid = NetUid::from(u16::try_from(i.saturating_add(1)).unwrap());
+            T::BenchmarkHelper::setup_subnet(netuid);
+            PalSwapInitialized::<T>::insert(netuid, true);
+            CurrentPriceCandle::<T>::insert(netuid, PriceCandle::default());
+        }
+        let block = u64::from(T::PriceCandlePeriod::get());
+
+        #[block]
+        {
+            Pallet::<T>::sample_price_candles(block);
+        }
+
+        assert_eq!(PriceCandleCount::<T>::get(NetUid::from(1)), 1);
+    }
+
     impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -67,6 +67,9 @@ parameter_types! {
     pub const MaxFeeRate: u16 = 10000; // 15.26%
     pub const MinimumLiquidity: u64 = 1_000;
     pub const MinimumReserves: NonZeroU64 = NonZeroU64::new(1).unwrap();
+    pub const PriceCandlePeriod: u32 = 10;
+    pub const MaxPriceCandles: u32 = 4;
+    pub const MaxPriceSamplesPerBlock: u32 = 2;
 }
 
 thread_local! {
@@ -76,6 +79,11 @@ thread_local! {
     // maps netuid -> mocked alpha reserve
     static MOCK_ALPHA_RESERVES: RefCell<HashMap<NetUid, AlphaBalance>> =
         RefCell::new(HashMap::new());
+    // maps coldkey -> net TAO moved by MockBalanceOps
+    static MOCK_TAO_DELTAS: RefCell<HashMap<AccountId, i128>> = RefCell::new(HashMap::new());
+    // maps (coldkey, hotkey) -> net alpha moved by MockBalanceOps
+    static MOCK_ALPHA_DELTAS: RefCell<HashMap<(AccountId, AccountId), i128>> =
+        RefCell::new(HashMap::new());
 }
 
 #[derive(Clone)]
@@ -105,8 +113,13 @@ impl TokenReserve<TaoBalance> for TaoReserve {
         .into()
     }
 
-    fn increase_provided(_: NetUid, _: TaoBalance) {}
-    fn decrease_provided(_: NetUid, _: TaoBalance) {}
+    fn increase_provided(netuid: NetUid, tao: TaoBalance) {
+        Self::set_mock_reserve(netuid, Self::reserve(netuid).saturating_add(tao));
+    }
+
+    fn decrease_provided(netuid: NetUid, tao: TaoBalance) {
+        Self::set_mock_reserve(netuid, Self::reserve(netuid).saturating_sub(tao));
+    }
 }
 
 #[derive(Clone)]
@@ -135,8 +148,13 @@ impl TokenReserve<AlphaBalance> for AlphaReserve {
         }
     }
 
-    fn increase_provided(_: NetUid, _: AlphaBalance) {}
-    fn decrease_provided(_: NetUid, _: AlphaBalance) {}
+    fn increase_provided(netuid: NetUid, alpha: AlphaBalance) {
+        Self::set_mock_reserve(netuid, Self::reserve(netuid).saturating_add(alpha));
+    }
+
+    fn decrease_provided(netuid: NetUid, alpha: AlphaBalance) {
+        Self::set_mock_reserve(netuid, Self::reserve(netuid).saturating_sub(alpha));
+    }
 }
 
 pub type GetAlphaForTao = subtensor_swap_interface::GetAlphaForTao<TaoReserve, AlphaReserve>;
@@ -219,6 +237,39 @@ impl SubnetInfo<AccountId> for MockLiquidityProvider {
 
 pub struct MockBalanceOps;
 
+impl MockBalanceOps {
+    /// Net TAO moved to (positive) or from (negative) the coldkey balance
+    pub fn tao_delta(coldkey: AccountId) -> i128 {
+        MOCK_TAO_DELTAS.with(|m| m.borrow().get(&coldkey).copied().unwrap_or_default())
+    }
+
+    /// Net alpha moved to (positive) or from (negative) the stake
+    pub fn alpha_delta(coldkey: AccountId, hotkey: AccountId) -> i128 {
+        MOCK_ALPHA_DELTAS.with(|m| {
+            m.borrow()
+                .get(&(coldkey, hotkey))
+                .copied()
+                .unwrap_or_default()
+        })
+    }
+
+    fn add_tao_delta(coldkey: AccountId, delta: i128) {
+        MOCK_TAO_DELTAS.with(|m| {
+            let mut m = m.borrow_mut();
+            let total = m.entry(coldkey).or_default();
+            *total = total.saturating_add(delta);
+        });
+    }
+
+    fn add_alpha_delta(coldkey: AccountId, hotkey: AccountId, delta: i128) {
+        MOCK_ALPHA_DELTAS.with(|m| {
+            let mut m = m.borrow_mut();
+            let total = m.entry((coldkey, hotkey)).or_default();
+            *total = total.saturating_add(delta);
+        });
+    }
+}
+
 impl BalanceOps<AccountId> for MockBalanceOps {
     fn tao_balance(account_id: &AccountId) -> TaoBalance {
         match *account_id {
@@ -227,6 +278,7 @@ impl BalanceOps<AccountId> for MockBalanceOps {
             OK_COLDKEY_ACCOUNT_ID_RICH => 900_000_000_000_000_000_u64,
             _ => 1_000_000_000,
         }
+        .saturating_add_signed(Self::tao_delta(*account_id) as i64)
         .into()
     }
 
@@ -243,24 +295,49 @@ impl BalanceOps<AccountId> for MockBalanceOps {
             }
             _ => 1_000_000_000,
         }
+        .saturating_add_signed(Self::alpha_delta(*coldkey_account_id, *hotkey_account_id) as i64)
         .into()
     }
 
     fn increase_stake(
-        _coldkey: &AccountId,
-        _hotkey: &AccountId,
+        coldkey: &AccountId,
+        hotkey: &AccountId,
         _netuid: NetUid,
-        _alpha: AlphaBalance,
+        alpha: AlphaBalance,
     ) -> Result<(), DispatchError> {
+        Self::add_alpha_delta(*coldkey, *hotkey, u64::from(alpha).into());
         Ok(())
     }
 
     fn decrease_stake(
-        _coldkey: &AccountId,
-        _hotkey: &AccountId,
+        coldkey: &AccountId,
+        hotkey: &AccountId,
+        _netuid: NetUid,
+        alpha: AlphaBalance,
+    ) -> Result<(), DispatchError> {
+        Self::add_alpha_delta(
+            *coldkey,
+            *hotkey,
+            0_i128.saturating_sub(u64::from(alpha).into()),
+        );
+        Ok(())
+    }
+
+    fn deposit_tao_to_subnet(
+        coldkey: &AccountId,
+        _netuid: NetUid,
+        tao: TaoBalance,
+    ) -> Result<(), DispatchError> {
+        Self::add_tao_delta(*coldkey, 0_i128.saturating_sub(u64::from(tao).into()));
+        Ok(())
+    }
+
+    fn withdraw_tao_from_subnet(
+        coldkey: &AccountId,
         _netuid: NetUid,
-        _alpha: AlphaBalance,
+        tao: TaoBalance,
     ) -> Result<(), DispatchError> {
+        Self::add_tao_delta(*coldkey, u64::from(tao).into());
         Ok(())
     }
 }
@@ -274,6 +351,9 @@ impl crate::pallet::Config for Test {
     type MaxFeeRate = MaxFeeRate;
     type MinimumLiquidity = MinimumLiquidity;
     type MinimumReserve = MinimumReserves;
+    type PriceCandlePeriod = PriceCandlePeriod;
+    type MaxPriceCandles = MaxPriceCandles;
+    type MaxPriceSamplesPerBlock = MaxPriceSamplesPerBlock;
     type WeightInfo = ();
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = ();
@@ -282,6 +362,10 @@ impl crate::pallet::Config for Test {
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
     sp_tracing::try_init_simple();
+    MOCK_TAO_RESERVES.with(|m| m.borrow_mut().clear());
+    MOCK_ALPHA_RESERVES.with(|m| m.borrow_mut().clear());
+    MOCK_TAO_DELTAS.with(|m| m.borrow_mut().clear());
+    MOCK_ALPHA_DELTAS.with(|m| m.borrow_mut().clear());
     let storage = system::GenesisConfig::<Test>::default()
         .build_storage()
         .unwrap();
@@ -5,7 +5,7 @@ mod hooks {
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
-            Weight::from_parts(0, 0)
+            Self::sample_price_candles(block_number.saturated_into::<u64>())
         }
 
         fn on_finalize(_block_number: BlockNumberFor<T>) {}
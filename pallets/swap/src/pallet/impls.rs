@@ -89,6 +89,7 @@ impl<T: Config> Pallet<T> {
         // Get reserves
         let alpha_reserve = T::AlphaReserve::reserve(netuid.into());
         let tao_reserve = T::TaoReserve::reserve(netuid.into());
+        let price = Self::current_price(netuid);
         let mut balancer = SwapBalancer::<T>::get(netuid);
 
         // Update weights and log errors if they go out of range
@@ -112,6 +113,12 @@ impl<T: Config> Pallet<T> {
             (TaoBalance::ZERO, AlphaBalance::ZERO)
         } else {
             SwapBalancer::<T>::insert(netuid, balancer);
+            Self::mint_protocol_liquidity(
+                netuid,
+                price,
+                (tao_reserve, alpha_reserve),
+                (tao_delta, alpha_delta),
+            );
             (tao_delta, alpha_delta)
         }
     }
@@ -245,6 +252,7 @@ impl<T: Config> Pallet<T> {
             amount_paid_out: swap_result.delta_out,
             fee_paid: swap_result.fee_paid,
             fee_to_block_author: swap_result.fee_to_block_author,
+            fee_to_liquidity_providers: swap_result.fee_to_liquidity_providers,
         })
     }
 
@@ -299,6 +307,18 @@ impl<T: Config> Pallet<T> {
         FeeRate::<T>::remove(netuid);
         SwapBalancer::<T>::remove(netuid);
 
+        // User liquidity has been returned by `do_dissolve_user_liquidity` by now,
+        // only the share accounting is left.
+        UserLiquidityEnabled::<T>::remove(netuid);
+        TotalLiquidityShares::<T>::remove(netuid);
+        UserLiquidityShares::<T>::remove(netuid);
+        LpFeePerShareTao::<T>::remove(netuid);
+        LpFeePerShareAlpha::<T>::remove(netuid);
+
+        CurrentPriceCandle::<T>::remove(netuid);
+        let _ = PriceCandles::<T>::clear_prefix(netuid, u32::MAX, None);
+        PriceCandleCount::<T>::remove(netuid);
+
         log::debug!(
             "clear_protocol_liquidity: netuid={netuid:?}, protocol_burned: τ={burned_tao:?}, α={burned_alpha:?}; state cleared"
         );
@@ -387,6 +407,7 @@ impl<T: Config> SwapHandler for Pallet<T> {
                     amount_paid_out: actual_amount.to_u64().into(),
                     fee_paid: 0.into(),
                     fee_to_block_author: 0.into(),
+                    fee_to_liquidity_providers: 0.into(),
                 })
             }
         }
@@ -420,6 +441,10 @@ impl<T: Config> SwapHandler for Pallet<T> {
         Self::do_clear_protocol_liquidity(netuid)
     }
 
+    fn dissolve_user_liquidity(netuid: NetUid) -> DispatchResult {
+        Self::do_dissolve_user_liquidity(netuid)
+    }
+
     fn init_swap(netuid: NetUid, maybe_price: Option<U64F64>) {
         Self::maybe_initialize_palswap(netuid, maybe_price).unwrap_or_default();
     }
@@ -0,0 +1,421 @@
+// User liquidity positions
+//
+// The pool is a single weighted curve, so user liquidity is not concentrated in price ranges.
+// Instead, the pool is split into liquidity shares, held by the protocol and by user positions.
+// Shares are valued in TAO at the current price:
+//
+//   V = y + p * x
+//
+// Adding ∆y TAO and ∆x alpha (in any proportion) mints L * (∆y + p * ∆x) / V shares, where L is
+// the total number of shares, and the balancer weights are updated so the price doesn't move.
+// When the first user adds liquidity, the protocol is given L = V shares for the existing
+// reserves. Protocol injections (emissions) mint shares for the protocol the same way, so that
+// they don't dilute into user positions.
+//
+// Removing l shares pays out the l / L part of both reserves, which keeps the price unchanged.
+//
+// Swap fees are split pro-rata: the user-held part of the fee (by share count) is accumulated
+// per user share in LpFeePerShareTao / LpFeePerShareAlpha, and the rest goes to the block
+// author as before. Positions keep a checkpoint of the accumulators, and fees are paid out
+// whenever the position is modified.
+
+use frame_support::{ensure, pallet_prelude::DispatchError, traits::Get};
+use pallet_subtensor_swap_runtime_api::LiquidityPositionInfo;
+use safe_math::*;
+use sp_runtime::DispatchResult;
+use sp_std::vec::Vec;
+use substrate_fixed::types::U64F64;
+use subtensor_runtime_common::{
+    AlphaBalance, BalanceOps, NetUid, SubnetInfo, TaoBalance, Token, TokenReserve,
+};
+
+use super::pallet::*;
+use super::{LiquidityPosition, PositionId};
+
+impl<T: Config> Pallet<T> {
+    /// Adds `tao` and `alpha` to the pool and credits the minted shares to a
+    /// new position or to `position_id` if given.
+    pub(super) fn do_add_liquidity(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        netuid: NetUid,
+        position_id: Option<PositionId>,
+        tao: TaoBalance,
+        alpha: AlphaBalance,
+    ) -> Result<PositionId, DispatchError> {
+        ensure!(
+            T::SubnetInfo::exists(netuid) && T::SubnetInfo::mechanism(netuid) == 1,
+            Error::<T>::MechanismDoesNotExist
+        );
+        ensure!(
+            T::SubnetInfo::is_subtoken_enabled(netuid),
+            Error::<T>::SubtokenDisabled
+        );
+        ensure!(
+            UserLiquidityEnabled::<T>::get(netuid),
+            Error::<T>::UserLiquidityDisabled
+        );
+        ensure!(
+            !tao.is_zero() || !alpha.is_zero(),
+            Error::<T>::InvalidLiquidityValue
+        );
+        ensure!(
+            T::BalanceOps::tao_balance(coldkey) >= tao
+                && T::BalanceOps::alpha_balance(netuid, coldkey, hotkey) >= alpha,
+            Error::<T>::InsufficientBalance
+        );
+
+        let tao_reserve = T::TaoReserve::reserve(netuid);
+        let alpha_reserve = T::AlphaReserve::reserve(netuid);
+        let minimum_reserve = T::MinimumReserve::get().get();
+        ensure!(
+            tao_reserve.to_u64() >= minimum_reserve && alpha_reserve.to_u64() >= minimum_reserve,
+            Error::<T>::ReservesTooLow
+        );
+
+        Self::maybe_initialize_palswap(netuid, None)?;
+
+        // Value the deposit against the pool at the current price
+        let price = Self::current_price(netuid);
+        let pool_value = Self::tao_value(price, tao_reserve, alpha_reserve);
+        let total_shares = Self::total_liquidity_shares(netuid, pool_value);
+        let liquidity = Self::tao_value(price, tao, alpha)
+            .safe_div(pool_value)
+            .saturating_mul(U64F64::saturating_from_num(total_shares))
+            .saturating_to_num::<u64>();
+        ensure!(
+            liquidity >= T::MinimumLiquidity::get(),
+            Error::<T>::InvalidLiquidityValue
+        );
+
+        // Rebalance weights so that the price doesn't change
+        let mut balancer = SwapBalancer::<T>::get(netuid);
+        balancer
+            .update_weights_for_added_liquidity(
+                tao_reserve.into(),
+                alpha_reserve.into(),
+                tao.into(),
+                alpha.into(),
+            )
+            .map_err(|_| Error::<T>::ReservesOutOfBalance)?;
+
+        let mut position = match position_id {
+            Some(id) => {
+                let mut position = LiquidityPositions::<T>::get((netuid, coldkey, id))
+                    .ok_or(Error::<T>::LiquidityNotFound)?;
+                ensure!(position.hotkey == *hotkey, Error::<T>::HotkeyMismatch);
+                Self::settle_lp_fees(coldkey, hotkey, &mut position)?;
+                position
+            }
+            None => {
+                let id = PositionId::from(NextPositionId::<T>::mutate(|next| {
+                    let id = *next;
+                    *next = next.saturating_add(1);
+                    id
+                }));
+                LiquidityPosition {
+                    id,
+                    netuid,
+                    hotkey: hotkey.clone(),
+                    liquidity: 0,
+                    fees_tao: LpFeePerShareTao::<T>::get(netuid),
+                    fees_alpha: LpFeePerShareAlpha::<T>::get(netuid),
+                }
+            }
+        };
+
+        // Move the funds into the pool
+        if !alpha.is_zero() {
+            T::BalanceOps::decrease_stake(coldkey, hotkey, netuid, alpha)?;
+        }
+        if !tao.is_zero() {
+            T::BalanceOps::deposit_tao_to_subnet(coldkey, netuid, tao)?;
+        }
+        T::TaoReserve::increase_provided(netuid, tao);
+        T::AlphaReserve::increase_provided(netuid, alpha);
+        SwapBalancer::<T>::insert(netuid, balancer);
+
+        TotalLiquidityShares::<T>::insert(netuid, total_shares.saturating_add(liquidity));
+        UserLiquidityShares::<T>::mutate(netuid, |shares| {
+            *shares = shares.saturating_add(liquidity)
+        });
+        position.liquidity = position.liquidity.saturating_add(liquidity);
+        let id = position.id;
+        LiquidityPositions::<T>::insert((netuid, coldkey, id), position);
+
+        Self::deposit_event(Event::LiquidityAdded {
+            coldkey: coldkey.clone(),
+            hotkey: hotkey.clone(),
+            netuid,
+            position_id: id,
+            liquidity,
+            tao,
+            alpha,
+        });
+
+        Ok(id)
+    }
+
+    /// Pays out `liquidity` shares of a position and its accrued fees,
+    /// closing the position when no shares remain.
+    pub(super) fn do_remove_liquidity(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        netuid: NetUid,
+        position_id: PositionId,
+        liquidity: u64,
+    ) -> DispatchResult {
+        Self::withdraw_liquidity(coldkey, hotkey, netuid, position_id, liquidity, true)
+    }
+
+    fn withdraw_liquidity(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        netuid: NetUid,
+        position_id: PositionId,
+        liquidity: u64,
+        keep_minimum_reserve: bool,
+    ) -> DispatchResult {
+        let mut position = LiquidityPositions::<T>::get((netuid, coldkey, position_id))
+            .ok_or(Error::<T>::LiquidityNotFound)?;
+        ensure!(position.hotkey == *hotkey, Error::<T>::HotkeyMismatch);
+        ensure!(
+            liquidity > 0 && liquidity <= position.liquidity,
+            Error::<T>::InsufficientLiquidity
+        );
+
+        Self::settle_lp_fees(coldkey, hotkey, &mut position)?;
+
+        let (tao, alpha) = Self::reserves_for_liquidity(netuid, liquidity, false);
+        if keep_minimum_reserve {
+            let minimum_reserve = T::MinimumReserve::get().get();
+            ensure!(
+                T::TaoReserve::reserve(netuid).saturating_sub(tao).to_u64() >= minimum_reserve
+                    && T::AlphaReserve::reserve(netuid)
+                        .saturating_sub(alpha)
+                        .to_u64()
+                        >= minimum_reserve,
+                Error::<T>::ReservesTooLow
+            );
+        }
+
+        // Pay out the share of both reserves. Withdrawing pro-rata keeps the price.
+        T::TaoReserve::decrease_provided(netuid, tao);
+        T::AlphaReserve::decrease_provided(netuid, alpha);
+        T::BalanceOps::withdraw_tao_from_subnet(coldkey, netuid, tao)?;
+        if !alpha.is_zero() {
+            T::BalanceOps::increase_stake(coldkey, hotkey, netuid, alpha)?;
+        }
+
+        TotalLiquidityShares::<T>::mutate(netuid, |shares| {
+            *shares = shares.saturating_sub(liquidity)
+        });
+        UserLiquidityShares::<T>::mutate(netuid, |shares| {
+            *shares = shares.saturating_sub(liquidity)
+        });
+        position.liquidity = position.liquidity.saturating_sub(liquidity);
+        if position.liquidity == 0 {
+            LiquidityPositions::<T>::remove((netuid, coldkey, position_id));
+        } else {
+            LiquidityPositions::<T>::insert((netuid, coldkey, position_id), position);
+        }
+
+        Self::deposit_event(Event::LiquidityRemoved {
+            coldkey: coldkey.clone(),
+            hotkey: hotkey.clone(),
+            netuid,
+            position_id,
+            liquidity,
+            tao,
+            alpha,
+        });
+
+        Ok(())
+    }
+
+    /// Pays out the fees accrued by a position without changing its liquidity.
+    pub(super) fn collect_lp_fees(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        netuid: NetUid,
+        position_id: PositionId,
+    ) -> DispatchResult {
+        let mut position = LiquidityPositions::<T>::get((netuid, coldkey, position_id))
+            .ok_or(Error::<T>::LiquidityNotFound)?;
+        ensure!(position.hotkey == *hotkey, Error::<T>::HotkeyMismatch);
+        Self::settle_lp_fees(coldkey, hotkey, &mut position)?;
+        LiquidityPositions::<T>::insert((netuid, coldkey, position_id), position);
+        Ok(())
+    }
+
+    /// Pays the accrued fees to the owner and moves the position checkpoints to
+    /// the current accumulators. The caller stores the position.
+    fn settle_lp_fees(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        position: &mut LiquidityPosition<T::AccountId>,
+    ) -> DispatchResult {
+        let netuid = position.netuid;
+        let (tao, alpha) = Self::accrued_lp_fees(position);
+        position.fees_tao = LpFeePerShareTao::<T>::get(netuid);
+        position.fees_alpha = LpFeePerShareAlpha::<T>::get(netuid);
+
+        if tao.is_zero() && alpha.is_zero() {
+            return Ok(());
+        }
+
+        T::BalanceOps::withdraw_tao_from_subnet(coldkey, netuid, tao)?;
+        if !alpha.is_zero() {
+            T::BalanceOps::increase_stake(coldkey, hotkey, netuid, alpha)?;
+        }
+
+        Self::deposit_event(Event::LpFeesCollected {
+            coldkey: coldkey.clone(),
+            netuid,
+            position_id: position.id,
+            tao,
+            alpha,
+        });
+
+        Ok(())
+    }
+
+    /// Fees earned by a position since its last payout
+    fn accrued_lp_fees(position: &LiquidityPosition<T::AccountId>) -> (TaoBalance, AlphaBalance) {
+        let liquidity = U64F64::saturating_from_num(position.liquidity);
+        let tao = LpFeePerShareTao::<T>::get(position.netuid)
+            .saturating_sub(position.fees_tao)
+            .saturating_mul(liquidity)
+            .saturating_to_num::<u64>();
+        let alpha = LpFeePerShareAlpha::<T>::get(position.netuid)
+            .saturating_sub(position.fees_alpha)
+            .saturating_mul(liquidity)
+            .saturating_to_num::<u64>();
+        (tao.into(), alpha.into())
+    }
+
+    /// Part of the reserves owned by `liquidity` shares, rounded up or down.
+    pub(super) fn reserves_for_liquidity(
+        netuid: NetUid,
+        liquidity: u64,
+        round_up: bool,
+    ) -> (TaoBalance, AlphaBalance) {
+        let total_shares = TotalLiquidityShares::<T>::get(netuid) as u128;
+        let share_of = |reserve: u64| -> u64 {
+            let numerator = (reserve as u128).saturating_mul(liquidity as u128);
+            let quotient = numerator.safe_div(total_shares);
+            let quotient = if round_up && quotient.saturating_mul(total_shares) < numerator {
+                quotient.saturating_add(1)
+            } else {
+                quotient
+            };
+            u64::try_from(quotient).unwrap_or(u64::MAX)
+        };
+
+        (
+            share_of(T::TaoReserve::reserve(netuid).into()).into(),
+            share_of(T::AlphaReserve::reserve(netuid).into()).into(),
+        )
+    }
+
+    /// Splits a swap fee by share ownership. Returns the part owed to user
+    /// positions and the matching increment of the per-share fee accumulator.
+    pub(super) fn lp_fee_split(netuid: NetUid, fee: u64) -> (u64, U64F64) {
+        let user_shares = UserLiquidityShares::<T>::get(netuid);
+        let total_shares = TotalLiquidityShares::<T>::get(netuid);
+        if fee == 0 || user_shares == 0 || total_shares == 0 {
+            return (0, U64F64::saturating_from_num(0));
+        }
+
+        let lp_fee = (fee as u128)
+            .saturating_mul(user_shares as u128)
+            .safe_div(total_shares as u128);
+        let lp_fee = u64::try_from(lp_fee).unwrap_or(fee).min(fee);
+        let per_share =
+            U64F64::saturating_from_num(lp_fee).safe_div(U64F64::saturating_from_num(user_shares));
+
+        (lp_fee, per_share)
+    }
+
+    /// Mints shares to the protocol for liquidity it adds to the pool, so that
+    /// user positions keep their value.
+    pub(super) fn mint_protocol_liquidity(
+        netuid: NetUid,
+        price: U64F64,
+        reserves: (TaoBalance, AlphaBalance),
+        deltas: (TaoBalance, AlphaBalance),
+    ) {
+        let total_shares = TotalLiquidityShares::<T>::get(netuid);
+        if total_shares == 0 {
+            return;
+        }
+
+        let pool_value = Self::tao_value(price, reserves.0, reserves.1);
+        let minted = Self::tao_value(price, deltas.0, deltas.1)
+            .safe_div(pool_value)
+            .saturating_mul(U64F64::saturating_from_num(total_shares))
+            .saturating_to_num::<u64>();
+        TotalLiquidityShares::<T>::insert(netuid, total_shares.saturating_add(minted));
+    }
+
+    /// Returns all user liquidity to the providers and clears the user
+    /// liquidity state of `netuid`. Used when the subnet is dissolved.
+    pub fn do_dissolve_user_liquidity(netuid: NetUid) -> DispatchResult {
+        let positions = LiquidityPositions::<T>::iter_prefix((netuid,)).collect::<Vec<_>>();
+        for ((coldkey, position_id), position) in positions {
+            Self::withdraw_liquidity(
+                &coldkey,
+                &position.hotkey,
+                netuid,
+                position_id,
+                position.liquidity,
+                false,
+            )?;
+        }
+
+        UserLiquidityEnabled::<T>::remove(netuid);
+        TotalLiquidityShares::<T>::remove(netuid);
+        UserLiquidityShares::<T>::remove(netuid);
+        LpFeePerShareTao::<T>::remove(netuid);
+        LpFeePerShareAlpha::<T>::remove(netuid);
+
+        Ok(())
+    }
+
+    /// Positions of `coldkey` on `netuid` with their underlying TAO and alpha
+    /// and the fees accrued so far.
+    pub fn liquidity_positions(
+        netuid: NetUid,
+        coldkey: &T::AccountId,
+    ) -> Vec<LiquidityPositionInfo<T::AccountId>> {
+        LiquidityPositions::<T>::iter_prefix_values((netuid, coldkey.clone()))
+            .map(|position| {
+                let (tao, alpha) = Self::reserves_for_liquidity(netuid, position.liquidity, false);
+                let (fees_tao, fees_alpha) = Self::accrued_lp_fees(&position);
+                LiquidityPositionInfo {
+                    position_id: position.id.into(),
+                    hotkey: position.hotkey,
+                    liquidity: position.liquidity,
+                    tao,
+                    alpha,
+                    fees_tao,
+                    fees_alpha,
+                }
+            })
+            .collect()
+    }
+
+    /// Total shares of the pool, bootstrapped to the pool value with all shares
+    /// owned by the protocol.
+    fn total_liquidity_shares(netuid: NetUid, pool_value: U64F64) -> u64 {
+        match TotalLiquidityShares::<T>::get(netuid) {
+            0 => pool_value.saturating_to_num::<u64>(),
+            shares => shares,
+        }
+    }
+
+    fn tao_value(price: U64F64, tao: TaoBalance, alpha: AlphaBalance) -> U64F64 {
+        U64F64::saturating_from_num(tao)
+            .saturating_add(U64F64::saturating_from_num(alpha).saturating_mul(price))
+    }
+}
@@ -2,6 +2,8 @@ use core::num::NonZeroU64;
 
 use frame_support::{PalletId, pallet_prelude::*, traits::Get};
 use frame_system::pallet_prelude::*;
+use sp_runtime::SaturatedConversion;
+use substrate_fixed::types::U64F64;
 use subtensor_runtime_common::{
     AlphaBalance, BalanceOps, NetUid, SubnetInfo, TaoBalance, TokenReserve,
 };
@@ -9,11 +11,14 @@ use subtensor_runtime_common::{
 use crate::{pallet::balancer::Balancer, weights::WeightInfo};
 pub use pallet::*;
 use subtensor_macros::freeze_struct;
+use subtensor_swap_interface::PriceCandle;
 
 mod balancer;
 mod hooks;
 mod impls;
+mod lp;
 pub mod migrations;
+mod oracle;
 mod swap_step;
 #[cfg(test)]
 mod tests;
@@ -64,6 +69,18 @@ mod pallet {
         #[pallet::constant]
         type MinimumReserve: Get<NonZeroU64>;
 
+        /// Number of blocks covered by one price candle
+        #[pallet::constant]
+        type PriceCandlePeriod: Get<u32>;
+
+        /// Number of closed price candles kept per subnet
+        #[pallet::constant]
+        type MaxPriceCandles: Get<u32>;
+
+        /// Maximum number of pools whose price is sampled in one block
+        #[pallet::constant]
+        type MaxPriceSamplesPerBlock: Get<u32>;
+
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
 
@@ -77,12 +94,27 @@ mod pallet {
     pub trait BenchmarkHelper<AccountId> {
         fn setup_subnet(netuid: NetUid);
         fn register_hotkey(hotkey: &AccountId, coldkey: &AccountId);
+        fn setup_liquidity_provider(
+            coldkey: &AccountId,
+            hotkey: &AccountId,
+            netuid: NetUid,
+            tao: TaoBalance,
+            alpha: AlphaBalance,
+        );
     }
 
     #[cfg(feature = "runtime-benchmarks")]
     impl<AccountId> BenchmarkHelper<AccountId> for () {
         fn setup_subnet(_netuid: NetUid) {}
         fn register_hotkey(_hotkey: &AccountId, _coldkey: &AccountId) {}
+        fn setup_liquidity_provider(
+            _coldkey: &AccountId,
+            _hotkey: &AccountId,
+            _netuid: NetUid,
+            _tao: TaoBalance,
+            _alpha: AlphaBalance,
+        ) {
+        }
     }
 
     /// Default fee rate if not set
@@ -122,11 +154,105 @@ mod pallet {
     #[pallet::storage]
     pub type ScrapReservoirAlpha<T> = StorageMap<_, Twox64Concat, NetUid, AlphaBalance, ValueQuery>;
 
+    ////////////////////////////////////////////////////
+    // User liquidity maps and variables
+
+    /// Whether users may add liquidity on a subnet
+    #[pallet::storage]
+    pub type UserLiquidityEnabled<T> = StorageMap<_, Twox64Concat, NetUid, bool, ValueQuery>;
+
+    /// Total liquidity shares of the pool, owned by the protocol and users
+    #[pallet::storage]
+    pub type TotalLiquidityShares<T> = StorageMap<_, Twox64Concat, NetUid, u64, ValueQuery>;
+
+    /// Liquidity shares held in user positions
+    #[pallet::storage]
+    pub type UserLiquidityShares<T> = StorageMap<_, Twox64Concat, NetUid, u64, ValueQuery>;
+
+    /// Accumulated TAO fees per user liquidity share
+    #[pallet::storage]
+    pub type LpFeePerShareTao<T> = StorageMap<_, Twox64Concat, NetUid, U64F64, ValueQuery>;
+
+    /// Accumulated alpha fees per user liquidity share
+    #[pallet::storage]
+    pub type LpFeePerShareAlpha<T> = StorageMap<_, Twox64Concat, NetUid, U64F64, ValueQuery>;
+
+    /// User liquidity positions by subnet, owner coldkey and position id
+    #[pallet::storage]
+    pub type LiquidityPositions<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Twox64Concat, NetUid>,
+            NMapKey<Blake2_128Concat, T::AccountId>,
+            NMapKey<Twox64Concat, PositionId>,
+        ),
+        LiquidityPosition<T::AccountId>,
+        OptionQuery,
+    >;
+
+    /// Id of the next liquidity position
+    #[pallet::storage]
+    pub type NextPositionId<T> = StorageValue<_, u128, ValueQuery>;
+
+    ////////////////////////////////////////////////////
+    // Price oracle
+
+    /// Candle of the current period
+    #[pallet::storage]
+    pub type CurrentPriceCandle<T> = StorageMap<_, Twox64Concat, NetUid, PriceCandle, OptionQuery>;
+
+    /// Ring buffer of closed candles, indexed by the candle count modulo `MaxPriceCandles`
+    #[pallet::storage]
+    pub type PriceCandles<T> =
+        StorageDoubleMap<_, Twox64Concat, NetUid, Twox64Concat, u32, PriceCandle, OptionQuery>;
+
+    /// Number of candles closed since the pool was initialized
+    #[pallet::storage]
+    pub type PriceCandleCount<T> = StorageMap<_, Twox64Concat, NetUid, u64, ValueQuery>;
+
+    /// Last pool sampled by the price oracle, sampling resumes after it
+    #[pallet::storage]
+    pub type PriceSampleCursor<T> = StorageValue<_, NetUid, OptionQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         /// Event emitted when the fee rate has been updated for a subnet
         FeeRateSet { netuid: NetUid, rate: u16 },
+
+        /// User liquidity has been enabled or disabled for a subnet
+        UserLiquidityToggled { netuid: NetUid, enable: bool },
+
+        /// Liquidity has been added to a position
+        LiquidityAdded {
+            coldkey: T::AccountId,
+            hotkey: T::AccountId,
+            netuid: NetUid,
+            position_id: PositionId,
+            liquidity: u64,
+            tao: TaoBalance,
+            alpha: AlphaBalance,
+        },
+
+        /// Liquidity has been removed from a position
+        LiquidityRemoved {
+            coldkey: T::AccountId,
+            hotkey: T::AccountId,
+            netuid: NetUid,
+            position_id: PositionId,
+            liquidity: u64,
+            tao: TaoBalance,
+            alpha: AlphaBalance,
+        },
+
+        /// Accrued swap fees have been paid out of a position
+        LpFeesCollected {
+            coldkey: T::AccountId,
+            netuid: NetUid,
+            position_id: PositionId,
+            tao: TaoBalance,
+            alpha: AlphaBalance,
+        },
     }
 
     #[pallet::error]
@@ -170,6 +296,15 @@ mod pallet {
 
         /// The extrinsic is deprecated
         Deprecated,
+
+        /// User liquidity is not enabled for the subnet
+        UserLiquidityDisabled,
+
+        /// The liquidity position does not exist
+        LiquidityNotFound,
+
+        /// The hotkey is not the one the liquidity position was opened with
+        HotkeyMismatch,
     }
 
     #[pallet::call]
@@ -200,55 +335,117 @@ mod pallet {
             Ok(())
         }
 
-        /// DEPRECATED
+        /// Enable or disable user liquidity on a subnet. Existing positions can
+        /// still be withdrawn while it is disabled.
+        ///
+        /// Only callable by root or the subnet owner
         #[pallet::call_index(4)]
-        #[pallet::weight(Weight::from_parts(15_000_000, 0))]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::toggle_user_liquidity())]
         pub fn toggle_user_liquidity(
-            _origin: OriginFor<T>,
-            _netuid: NetUid,
-            _enable: bool,
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            enable: bool,
         ) -> DispatchResult {
-            Err(Error::<T>::Deprecated.into())
+            if ensure_root(origin.clone()).is_err() {
+                let who = ensure_signed(origin)?;
+                ensure!(
+                    T::SubnetInfo::is_owner(&who, netuid),
+                    DispatchError::BadOrigin
+                );
+            }
+
+            ensure!(
+                T::SubnetInfo::exists(netuid),
+                Error::<T>::MechanismDoesNotExist
+            );
+
+            UserLiquidityEnabled::<T>::insert(netuid, enable);
+
+            Self::deposit_event(Event::UserLiquidityToggled { netuid, enable });
+
+            Ok(())
         }
 
-        /// DEPRECATED
+        /// Add liquidity to the subnet pool and open a new position.
+        ///
+        /// TAO is taken from the coldkey balance and alpha from its stake on
+        /// `hotkey`, in any proportion. The pool weights are rebalanced so that
+        /// the price does not move, and the position receives liquidity shares
+        /// worth the TAO value of the deposit. Shares earn a pro-rata part of
+        /// the swap fees from then on.
         #[pallet::call_index(1)]
-        #[pallet::weight(Weight::from_parts(15_000_000, 0))]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::add_liquidity())]
         pub fn add_liquidity(
-            _origin: OriginFor<T>,
-            _hotkey: T::AccountId,
-            _netuid: NetUid,
-            _tick_low: TickIndex,
-            _tick_high: TickIndex,
-            _liquidity: u64,
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: NetUid,
+            tao: TaoBalance,
+            alpha: AlphaBalance,
         ) -> DispatchResult {
-            Err(Error::<T>::Deprecated.into())
+            let coldkey = ensure_signed(origin)?;
+
+            Self::do_add_liquidity(&coldkey, &hotkey, netuid, None, tao, alpha)?;
+
+            Ok(())
         }
 
-        /// DEPRECATED
+        /// Close a position: pay out its share of both reserves and its accrued
+        /// fees. Alpha is staked back on `hotkey`, which must be the hotkey the
+        /// position was opened with.
         #[pallet::call_index(2)]
-        #[pallet::weight(Weight::from_parts(15_000_000, 0))]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::remove_liquidity())]
         pub fn remove_liquidity(
-            _origin: OriginFor<T>,
-            _hotkey: T::AccountId,
-            _netuid: NetUid,
-            _position_id: PositionId,
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: NetUid,
+            position_id: PositionId,
         ) -> DispatchResult {
-            Err(Error::<T>::Deprecated.into())
+            let coldkey = ensure_signed(origin)?;
+
+            let position = LiquidityPositions::<T>::get((netuid, &coldkey, position_id))
+                .ok_or(Error::<T>::LiquidityNotFound)?;
+            Self::do_remove_liquidity(&coldkey, &hotkey, netuid, position_id, position.liquidity)
         }
 
-        /// DEPRECATED
+        /// Change the liquidity of a position by `liquidity_delta` shares.
+        ///
+        /// A positive delta deposits TAO and alpha in the current reserve
+        /// proportion, a negative one withdraws. Accrued fees are paid out in
+        /// either case, so a zero delta only collects fees. `hotkey` must be the
+        /// hotkey the position was opened with.
         #[pallet::call_index(3)]
-        #[pallet::weight(Weight::from_parts(15_000_000, 0))]
-        #[deprecated(note = "Deprecated, user liquidity is permanently disabled")]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::modify_position())]
         pub fn modify_position(
-            _origin: OriginFor<T>,
-            _hotkey: T::AccountId,
-            _netuid: NetUid,
-            _position_id: PositionId,
-            _liquidity_delta: i64,
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: NetUid,
+            position_id: PositionId,
+            liquidity_delta: i64,
         ) -> DispatchResult {
-            Err(Error::<T>::Deprecated.into())
+            let coldkey = ensure_signed(origin)?;
+
+            ensure!(
+                LiquidityPositions::<T>::contains_key((netuid, &coldkey, position_id)),
+                Error::<T>::LiquidityNotFound
+            );
+
+            if liquidity_delta > 0 {
+                let (tao, alpha) =
+                    Self::reserves_for_liquidity(netuid, liquidity_delta.unsigned_abs(), true);
+                Self::do_add_liquidity(&coldkey, &hotkey, netuid, Some(position_id), tao, alpha)?;
+            } else if liquidity_delta < 0 {
+                Self::do_remove_liquidity(
+                    &coldkey,
+                    &hotkey,
+                    netuid,
+                    position_id,
+                    liquidity_delta.unsigned_abs(),
+                )?;
+            } else {
+                Self::collect_lp_fees(&coldkey, &hotkey, netuid, position_id)?;
+            }
+
+            Ok(())
         }
 
         /// DEPRECATED
@@ -281,7 +478,7 @@ mod pallet {
 )]
 pub struct TickIndex(i32);
 
-/// Struct representing a liquidity position ID, DEPRECATED
+/// Struct representing a liquidity position ID
 #[freeze_struct("e695cd6455c3f0cb")]
 #[derive(
     Clone,
@@ -297,3 +494,30 @@ pub struct TickIndex(i32);
     TypeInfo,
 )]
 pub struct PositionId(u128);
+
+impl From<u128> for PositionId {
+    fn from(value: u128) -> Self {
+        Self(value)
+    }
+}
+
+impl From<PositionId> for u128 {
+    fn from(value: PositionId) -> Self {
+        value.0
+    }
+}
+
+/// A user share of the pool liquidity.
+///
+/// The fee checkpoints are the per-share fee accumulators at the last payout,
+/// so the fees owed are `liquidity * (accumulator - checkpoint)`.
+#[freeze_struct("bb69e2439cb5c472")]
+#[derive(Clone, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct LiquidityPosition<AccountId> {
+    pub id: PositionId,
+    pub netuid: NetUid,
+    pub hotkey: AccountId,
+    pub liquidity: u64,
+    pub fees_tao: U64F64,
+    pub fees_alpha: U64F64,
+}
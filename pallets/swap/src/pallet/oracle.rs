@@ -0,0 +1,151 @@
+// Price oracle
+//
+// Pool prices are sampled in on_initialize, before any extrinsic of the block is applied. At most
+// MaxPriceSamplesPerBlock pools are sampled per block, round-robin over the initialized pools from
+// PriceSampleCursor, and each sample stands for every block since the previous sample of its
+// pool. A sample only sees prices held across a block boundary, but anyone who can hold a shallow
+// pool off its price for the blocks between two samples still moves the average, so consumers
+// should weigh the TWAP against the depth of the pool.
+//
+// Samples are aggregated into candles of PriceCandlePeriod blocks, aligned on block numbers.
+// The candle of the current period lives in CurrentPriceCandle and is closed into the
+// PriceCandles ring buffer (MaxPriceCandles slots per subnet) by the first sample of the next
+// period. The TAO side of every executed swap step is added to the current candle volume.
+
+use frame_support::{traits::Get, weights::Weight};
+use safe_math::*;
+use sp_std::vec::Vec;
+use substrate_fixed::types::U64F64;
+use subtensor_runtime_common::{NetUid, TaoBalance};
+use subtensor_swap_interface::PriceCandle;
+
+use super::pallet::*;
+use crate::weights::WeightInfo;
+
+impl<T: Config> Pallet<T> {
+    /// Samples the price of up to `MaxPriceSamplesPerBlock` pools into their current candle,
+    /// resuming after the last pool sampled.
+    pub(crate) fn sample_price_candles(block: u64) -> Weight {
+        let max_pools = T::MaxPriceSamplesPerBlock::get();
+        let pools = match PriceSampleCursor::<T>::get() {
+            Some(last) => {
+                PalSwapInitialized::<T>::iter_from(PalSwapInitialized::<T>::hashed_key_for(last))
+            }
+            None => PalSwapInitialized::<T>::iter(),
+        };
+
+        let mut visited = 0_u32;
+        let mut last = None;
+        for (netuid, initialized) in pools.take(max_pools as usize) {
+            visited = visited.saturating_add(1);
+            last = Some(netuid);
+            if initialized {
+                Self::sample_price_candle(netuid, block);
+            }
+        }
+
+        // Start over from the first pool once the last one has been visited
+        if visited < max_pools {
+            PriceSampleCursor::<T>::kill();
+        } else {
+            PriceSampleCursor::<T>::set(last);
+        }
+
+        T::WeightInfo::sample_price_candles(visited)
+    }
+
+    fn sample_price_candle(netuid: NetUid, block: u64) {
+        let period = u64::from(T::PriceCandlePeriod::get().max(1));
+        let price = Self::current_price(netuid)
+            .saturating_mul(U64F64::saturating_from_num(1_000_000_000_u64))
+            .saturating_to_num::<u64>();
+
+        let candle = match CurrentPriceCandle::<T>::get(netuid) {
+            Some(candle) if candle.start_block.safe_div(period) == block.safe_div(period) => {
+                // The sample stands for every block since the previous one
+                let blocks = block.saturating_sub(candle.end_block);
+                PriceCandle {
+                    end_block: block,
+                    high: candle.high.max(price),
+                    low: candle.low.min(price),
+                    close: price,
+                    price_sum: candle
+                        .price_sum
+                        .saturating_add(u128::from(price).saturating_mul(blocks.into())),
+                    ..candle
+                }
+            }
+            maybe_closed => {
+                if let Some(closed) = maybe_closed {
+                    Self::close_price_candle(netuid, closed);
+                }
+                PriceCandle {
+                    start_block: block,
+                    end_block: block,
+                    open: price,
+                    high: price,
+                    low: price,
+                    close: price,
+                    price_sum: price.into(),
+                    tao_volume: 0,
+                }
+            }
+        };
+        CurrentPriceCandle::<T>::insert(netuid, candle);
+    }
+
+    /// Adds `tao` to the volume of the current candle, if any.
+    pub(crate) fn record_tao_volume(netuid: NetUid, tao: TaoBalance) {
+        CurrentPriceCandle::<T>::mutate(netuid, |maybe_candle| {
+            if let Some(candle) = maybe_candle {
+                candle.tao_volume = candle.tao_volume.saturating_add(tao.into());
+            }
+        });
+    }
+
+    fn close_price_candle(netuid: NetUid, candle: PriceCandle) {
+        let count = PriceCandleCount::<T>::get(netuid);
+        PriceCandles::<T>::insert(netuid, Self::price_candle_slot(count), candle);
+        PriceCandleCount::<T>::insert(netuid, count.saturating_add(1));
+    }
+
+    fn price_candle_slot(index: u64) -> u32 {
+        let max = u64::from(T::MaxPriceCandles::get().max(1));
+        u32::try_from(index.checked_rem(max).unwrap_or_default()).unwrap_or_default()
+    }
+
+    /// Closed candle `age` periods back, `0` being the most recently closed one.
+    pub fn price_candle(netuid: NetUid, age: u32) -> Option<PriceCandle> {
+        let count = PriceCandleCount::<T>::get(netuid);
+        let stored = count.min(T::MaxPriceCandles::get().into());
+        if u64::from(age) >= stored {
+            return None;
+        }
+        let index = count.saturating_sub(1).saturating_sub(age.into());
+        PriceCandles::<T>::get(netuid, Self::price_candle_slot(index))
+    }
+
+    /// Up to `count` most recently closed candles, oldest first.
+    pub fn price_candles(netuid: NetUid, count: u32) -> Vec<PriceCandle> {
+        let stored = PriceCandleCount::<T>::get(netuid).min(T::MaxPriceCandles::get().into());
+        let count = count.min(u32::try_from(stored).unwrap_or(u32::MAX));
+        (0..count)
+            .rev()
+            .filter_map(|age| Self::price_candle(netuid, age))
+            .collect()
+    }
+
+    /// Time-weighted average price over the `candles` most recently closed candles, in RAO per
+    /// alpha. `None` if no candle has been closed yet.
+    pub fn twap_price(netuid: NetUid, candles: u32) -> Option<u64> {
+        let (price_sum, blocks) = (0..candles)
+            .map_while(|age| Self::price_candle(netuid, age))
+            .fold((0_u128, 0_u64), |(price_sum, blocks), candle| {
+                (
+                    price_sum.saturating_add(candle.price_sum),
+                    blocks.saturating_add(candle.blocks()),
+                )
+            });
+        (blocks > 0).then(|| u64::try_from(price_sum.safe_div(blocks.into())).unwrap_or(u64::MAX))
+    }
+}
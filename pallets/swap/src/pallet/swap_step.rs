@@ -120,11 +120,19 @@ where
         let delta_out = Self::convert_deltas(self.netuid, self.delta_in);
         log::trace!("\tDelta Out        : {delta_out}");
         let mut fee_to_block_author = 0.into();
+        let mut fee_to_liquidity_providers = 0.into();
         if !self.delta_in.is_zero() {
             ensure!(!delta_out.is_zero(), Error::<T>::ReservesTooLow);
 
-            // 100% of swap fees to to block builder
-            fee_to_block_author = self.fee;
+            // Liquidity providers get their pro-rata share of swap fees, the rest goes
+            // to block builder
+            fee_to_liquidity_providers = Self::accrue_lp_fee(self.netuid, self.fee);
+            fee_to_block_author = self.fee.saturating_sub(fee_to_liquidity_providers);
+
+            Pallet::<T>::record_tao_volume(
+                self.netuid,
+                Self::tao_volume(self.delta_in, self.fee, delta_out),
+            );
         }
 
         Ok(SwapStepResult {
@@ -132,6 +140,7 @@ where
             delta_in: self.delta_in,
             delta_out,
             fee_to_block_author,
+            fee_to_liquidity_providers,
         })
     }
 }
@@ -165,6 +174,18 @@ impl<T: Config> SwapStep<T, TaoBalance, AlphaBalance>
         price1 <= price2
     }
 
+    fn accrue_lp_fee(netuid: NetUid, fee: TaoBalance) -> TaoBalance {
+        let (lp_fee, per_share) = Pallet::<T>::lp_fee_split(netuid, fee.into());
+        if lp_fee > 0 {
+            LpFeePerShareTao::<T>::mutate(netuid, |acc| *acc = acc.saturating_add(per_share));
+        }
+        lp_fee.into()
+    }
+
+    fn tao_volume(delta_in: TaoBalance, fee: TaoBalance, _delta_out: AlphaBalance) -> TaoBalance {
+        delta_in.saturating_add(fee)
+    }
+
     fn convert_deltas(netuid: NetUid, delta_in: TaoBalance) -> AlphaBalance {
         let alpha_reserve = T::AlphaReserve::reserve(netuid.into());
         let tao_reserve = T::TaoReserve::reserve(netuid.into());
@@ -209,6 +230,22 @@ impl<T: Config> SwapStep<T, AlphaBalance, TaoBalance>
         price1 >= price2
     }
 
+    fn accrue_lp_fee(netuid: NetUid, fee: AlphaBalance) -> AlphaBalance {
+        let (lp_fee, per_share) = Pallet::<T>::lp_fee_split(netuid, fee.into());
+        if lp_fee > 0 {
+            LpFeePerShareAlpha::<T>::mutate(netuid, |acc| *acc = acc.saturating_add(per_share));
+        }
+        lp_fee.into()
+    }
+
+    fn tao_volume(
+        _delta_in: AlphaBalance,
+        _fee: AlphaBalance,
+        delta_out: TaoBalance,
+    ) -> TaoBalance {
+        delta_out
+    }
+
     fn convert_deltas(netuid: NetUid, delta_in: AlphaBalance) -> TaoBalance {
         let alpha_reserve = T::AlphaReserve::reserve(netuid.into());
         let tao_reserve = T::TaoReserve::reserve(netuid.into());
@@ -246,6 +283,13 @@ where
     /// This is the core method of the swap that tells how much output token is given for an
     /// amount of input token within one price tick.
     fn convert_deltas(netuid: NetUid, delta_in: PaidIn) -> PaidOut;
+
+    /// Credit the liquidity provider share of a swap fee to the per-share fee
+    /// accumulator of the input token and return that share.
+    fn accrue_lp_fee(netuid: NetUid, fee: PaidIn) -> PaidIn;
+
+    /// TAO side of a swap step, fees included, for the price oracle volume.
+    fn tao_volume(delta_in: PaidIn, fee: PaidIn, delta_out: PaidOut) -> TaoBalance;
 }
 
 #[derive(Debug, PartialEq)]
@@ -258,4 +302,5 @@ where
     pub(crate) delta_in: PaidIn,
     pub(crate) delta_out: PaidOut,
     pub(crate) fee_to_block_author: PaidIn,
+    pub(crate) fee_to_liquidity_providers: PaidIn,
 }
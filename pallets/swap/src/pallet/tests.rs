@@ -354,6 +354,654 @@ mod dispatchables {
     }
 }
 
+mod user_liquidity {
+    use super::*;
+
+    const COLDKEY: AccountId = OK_COLDKEY_ACCOUNT_ID;
+    const HOTKEY: AccountId = OK_HOTKEY_ACCOUNT_ID;
+
+    // Default reserves of netuid 1 are 1_000 TAO and 4_000 alpha (price 0.25), so the pool
+    // is worth 2_000 TAO and a balanced deposit of 125 TAO + 500 alpha is worth 250 TAO,
+    // which mints 1/8 of the existing shares.
+    fn setup() -> NetUid {
+        let netuid = NetUid::from(1);
+        assert_ok!(Swap::toggle_user_liquidity(
+            RuntimeOrigin::root(),
+            netuid,
+            true
+        ));
+        netuid
+    }
+
+    fn add(netuid: NetUid, tao: u64, alpha: u64) -> PositionId {
+        assert_ok!(Swap::add_liquidity(
+            RuntimeOrigin::signed(COLDKEY),
+            HOTKEY,
+            netuid,
+            tao.into(),
+            alpha.into(),
+        ));
+        LiquidityPositions::<Test>::iter_key_prefix((netuid, COLDKEY))
+            .last()
+            .unwrap()
+    }
+
+    fn assert_near(left: i128, right: i128) {
+        assert!((left - right).abs() <= 1, "{left} != {right}");
+    }
+
+    fn position(netuid: NetUid, id: PositionId) -> LiquidityPosition<AccountId> {
+        LiquidityPositions::<Test>::get((netuid, COLDKEY, id)).unwrap()
+    }
+
+    #[test]
+    fn test_toggle_user_liquidity() {
+        new_test_ext().execute_with(|| {
+            let netuid = NetUid::from(1);
+
+            assert_noop!(
+                Swap::toggle_user_liquidity(RuntimeOrigin::signed(NOT_SUBNET_OWNER), netuid, true),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(
+                Swap::toggle_user_liquidity(
+                    RuntimeOrigin::root(),
+                    NON_EXISTENT_NETUID.into(),
+                    true
+                ),
+                Error::<Test>::MechanismDoesNotExist
+            );
+
+            // Subnet owner
+            assert_ok!(Swap::toggle_user_liquidity(
+                RuntimeOrigin::signed(COLDKEY),
+                netuid,
+                true
+            ));
+            assert!(UserLiquidityEnabled::<Test>::get(netuid));
+            System::assert_last_event(
+                Event::<Test>::UserLiquidityToggled {
+                    netuid,
+                    enable: true,
+                }
+                .into(),
+            );
+
+            assert_ok!(Swap::toggle_user_liquidity(
+                RuntimeOrigin::root(),
+                netuid,
+                false
+            ));
+            assert!(!UserLiquidityEnabled::<Test>::get(netuid));
+        });
+    }
+
+    #[test]
+    fn test_add_liquidity_requires_enabled_subnet() {
+        new_test_ext().execute_with(|| {
+            let netuid = NetUid::from(1);
+
+            assert_noop!(
+                Swap::add_liquidity(
+                    RuntimeOrigin::signed(COLDKEY),
+                    HOTKEY,
+                    netuid,
+                    1_000_000.into(),
+                    1_000_000.into(),
+                ),
+                Error::<Test>::UserLiquidityDisabled
+            );
+
+            let netuid = setup();
+            assert_noop!(
+                Swap::add_liquidity(
+                    RuntimeOrigin::signed(COLDKEY),
+                    HOTKEY,
+                    netuid,
+                    0.into(),
+                    0.into(),
+                ),
+                Error::<Test>::InvalidLiquidityValue
+            );
+            assert_noop!(
+                Swap::add_liquidity(
+                    RuntimeOrigin::signed(COLDKEY),
+                    HOTKEY,
+                    netuid,
+                    200_000_000_000_000_u64.into(),
+                    0.into(),
+                ),
+                Error::<Test>::InsufficientBalance
+            );
+        });
+    }
+
+    #[test]
+    fn test_add_liquidity_mints_shares_and_keeps_price() {
+        new_test_ext().execute_with(|| {
+            let netuid = setup();
+            let price_before = Swap::current_price(netuid);
+
+            // Unbalanced deposit: all TAO
+            let id = add(netuid, 250_000_000_000, 0);
+
+            // Protocol got the pool value in shares, user the value of the deposit
+            assert_eq!(UserLiquidityShares::<Test>::get(netuid), 250_000_000_000);
+            assert_eq!(TotalLiquidityShares::<Test>::get(netuid), 2_250_000_000_000);
+            assert_eq!(position(netuid, id).liquidity, 250_000_000_000);
+
+            // Funds moved into the reserves
+            assert_eq!(MockBalanceOps::tao_delta(COLDKEY), -250_000_000_000);
+            assert_eq!(MockBalanceOps::alpha_delta(COLDKEY, HOTKEY), 0);
+            assert_eq!(
+                TaoReserve::reserve(netuid),
+                TaoBalance::from(1_250_000_000_000_u64)
+            );
+
+            // Weights were updated, price didn't move
+            assert_abs_diff_eq!(
+                Swap::current_price(netuid).to_num::<f64>(),
+                price_before.to_num::<f64>(),
+                epsilon = price_before.to_num::<f64>() / 1_000_000_000.
+            );
+        });
+    }
+
+    #[test]
+    fn test_remove_liquidity_pays_out_share_of_reserves() {
+        new_test_ext().execute_with(|| {
+            let netuid = setup();
+            let price_before = Swap::current_price(netuid);
+            let id = add(netuid, 125_000_000_000, 500_000_000_000);
+
+            assert_ok!(Swap::remove_liquidity(
+                RuntimeOrigin::signed(COLDKEY),
+                HOTKEY,
+                netuid,
+                id
+            ));
+
+            // The whole deposit comes back, up to rounding
+            assert_near(MockBalanceOps::tao_delta(COLDKEY), 0);
+            assert_near(MockBalanceOps::alpha_delta(COLDKEY, HOTKEY), 0);
+            assert!(!LiquidityPositions::<Test>::contains_key((
+                netuid, COLDKEY, id
+            )));
+            assert_eq!(UserLiquidityShares::<Test>::get(netuid), 0);
+            assert_eq!(TotalLiquidityShares::<Test>::get(netuid), 2_000_000_000_000);
+            assert_abs_diff_eq!(
+                Swap::current_price(netuid).to_num::<f64>(),
+                price_before.to_num::<f64>(),
+                epsilon = price_before.to_num::<f64>() / 1_000_000_000.
+            );
+
+            assert_noop!(
+                Swap::remove_liquidity(RuntimeOrigin::signed(COLDKEY), HOTKEY, netuid, id),
+                Error::<Test>::LiquidityNotFound
+            );
+        });
+    }
+
+    #[test]
+    fn test_modify_position() {
+        new_test_ext().execute_with(|| {
+            let netuid = setup();
+            let id = add(netuid, 125_000_000_000, 500_000_000_000);
+
+            // Withdraw half
+            assert_ok!(Swap::modify_position(
+                RuntimeOrigin::signed(COLDKEY),
+                HOTKEY,
+                netuid,
+                id,
+                -125_000_000_000
+            ));
+            assert_eq!(position(netuid, id).liquidity, 125_000_000_000);
+            assert_near(MockBalanceOps::tao_delta(COLDKEY), -62_500_000_000);
+
+            // Deposit it back in the reserve proportion
+            assert_ok!(Swap::modify_position(
+                RuntimeOrigin::signed(COLDKEY),
+                HOTKEY,
+                netuid,
+                id,
+                125_000_000_000
+            ));
+            assert_abs_diff_eq!(position(netuid, id).liquidity, 250_000_000_000, epsilon = 1);
+            assert_near(MockBalanceOps::tao_delta(COLDKEY), -125_000_000_000);
+
+            assert_noop!(
+                Swap::modify_position(
+                    RuntimeOrigin::signed(COLDKEY),
+                    HOTKEY,
+                    netuid,
+                    id,
+                    -300_000_000_000
+                ),
+                Error::<Test>::InsufficientLiquidity
+            );
+            assert_noop!(
+                Swap::modify_position(
+                    RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID_2),
+                    HOTKEY,
+                    netuid,
+                    id,
+                    0
+                ),
+                Error::<Test>::LiquidityNotFound
+            );
+        });
+    }
+
+    #[test]
+    fn test_position_bound_to_its_hotkey() {
+        new_test_ext().execute_with(|| {
+            let netuid = setup();
+            let id = add(netuid, 125_000_000_000, 500_000_000_000);
+
+            for delta in [1_000_000, -1_000_000, 0] {
+                assert_noop!(
+                    Swap::modify_position(
+                        RuntimeOrigin::signed(COLDKEY),
+                        OK_HOTKEY_ACCOUNT_ID_2,
+                        netuid,
+                        id,
+                        delta
+                    ),
+                    Error::<Test>::HotkeyMismatch
+                );
+            }
+            assert_noop!(
+                Swap::remove_liquidity(
+                    RuntimeOrigin::signed(COLDKEY),
+                    OK_HOTKEY_ACCOUNT_ID_2,
+                    netuid,
+                    id
+                ),
+                Error::<Test>::HotkeyMismatch
+            );
+
+            assert_ok!(Swap::remove_liquidity(
+                RuntimeOrigin::signed(COLDKEY),
+                HOTKEY,
+                netuid,
+                id
+            ));
+        });
+    }
+
+    #[test]
+    fn test_swap_fees_are_shared_pro_rata() {
+        new_test_ext().execute_with(|| {
+            let netuid = setup();
+            // 1/9 of the shares belong to the user
+            let id = add(netuid, 125_000_000_000, 500_000_000_000);
+            let user_share = 1. / 9.;
+
+            let buy = Pallet::<Test>::do_swap(
+                netuid,
+                GetAlphaForTao::with_amount(1_000_000_000),
+                u64::MAX.into(),
+                false,
+                false,
+            )
+            .unwrap();
+            let sell = Pallet::<Test>::do_swap(
+                netuid,
+                GetTaoForAlpha::with_amount(4_000_000_000),
+                0_u64.into(),
+                false,
+                false,
+            )
+            .unwrap();
+
+            for (fee_paid, to_lps, to_author) in [
+                (
+                    buy.fee_paid.to_u64(),
+                    buy.fee_to_liquidity_providers.to_u64(),
+                    buy.fee_to_block_author.to_u64(),
+                ),
+                (
+                    sell.fee_paid.to_u64(),
+                    sell.fee_to_liquidity_providers.to_u64(),
+                    sell.fee_to_block_author.to_u64(),
+                ),
+            ] {
+                assert!(fee_paid > 0);
+                assert_eq!(to_lps + to_author, fee_paid);
+                assert_abs_diff_eq!(to_lps as f64, fee_paid as f64 * user_share, epsilon = 1.);
+            }
+
+            // A zero delta only collects the fees
+            let tao_before = MockBalanceOps::tao_delta(COLDKEY);
+            let alpha_before = MockBalanceOps::alpha_delta(COLDKEY, HOTKEY);
+            assert_ok!(Swap::modify_position(
+                RuntimeOrigin::signed(COLDKEY),
+                HOTKEY,
+                netuid,
+                id,
+                0
+            ));
+            let tao_fees = MockBalanceOps::tao_delta(COLDKEY) - tao_before;
+            let alpha_fees = MockBalanceOps::alpha_delta(COLDKEY, HOTKEY) - alpha_before;
+            assert_near(tao_fees, buy.fee_to_liquidity_providers.to_u64() as i128);
+            assert_near(alpha_fees, sell.fee_to_liquidity_providers.to_u64() as i128);
+            assert_eq!(position(netuid, id).liquidity, 250_000_000_000);
+
+            // Nothing is left to collect
+            assert_ok!(Swap::modify_position(
+                RuntimeOrigin::signed(COLDKEY),
+                HOTKEY,
+                netuid,
+                id,
+                0
+            ));
+            assert_eq!(MockBalanceOps::tao_delta(COLDKEY) - tao_before, tao_fees);
+        });
+    }
+
+    #[test]
+    fn test_swap_fees_go_to_block_author_without_positions() {
+        new_test_ext().execute_with(|| {
+            let netuid = setup();
+
+            let result = Pallet::<Test>::do_swap(
+                netuid,
+                GetAlphaForTao::with_amount(1_000_000_000),
+                u64::MAX.into(),
+                false,
+                false,
+            )
+            .unwrap();
+
+            assert!(result.fee_paid > 0.into());
+            assert_eq!(result.fee_to_block_author, result.fee_paid);
+            assert_eq!(result.fee_to_liquidity_providers, 0.into());
+        });
+    }
+
+    #[test]
+    fn test_protocol_liquidity_does_not_dilute_positions() {
+        new_test_ext().execute_with(|| {
+            let netuid = setup();
+            let id = add(netuid, 125_000_000_000, 500_000_000_000);
+            let before = Swap::liquidity_positions(netuid, &COLDKEY);
+
+            // Emission injects 10 TAO and 80 alpha
+            let tao_delta = TaoBalance::from(10_000_000_000_u64);
+            let alpha_delta = AlphaBalance::from(80_000_000_000_u64);
+            Swap::adjust_protocol_liquidity(netuid, tao_delta, alpha_delta);
+            TaoReserve::increase_provided(netuid, tao_delta);
+            AlphaReserve::increase_provided(netuid, alpha_delta);
+
+            let after = Swap::liquidity_positions(netuid, &COLDKEY);
+            assert_eq!(after.len(), 1);
+            assert_eq!(after[0].position_id, u128::from(id));
+            assert_eq!(after[0].liquidity, before[0].liquidity);
+            assert!(TotalLiquidityShares::<Test>::get(netuid) > 2_250_000_000_000);
+
+            // The position is worth the same at the (unchanged) price
+            let price = Swap::current_price(netuid).to_num::<f64>();
+            let value = |tao: TaoBalance, alpha: AlphaBalance| {
+                tao.to_u64() as f64 + alpha.to_u64() as f64 * price
+            };
+            assert_abs_diff_eq!(
+                value(after[0].tao, after[0].alpha),
+                value(before[0].tao, before[0].alpha),
+                epsilon = 10.
+            );
+        });
+    }
+
+    #[test]
+    fn test_liquidity_positions_quote() {
+        new_test_ext().execute_with(|| {
+            let netuid = setup();
+            let id = add(netuid, 125_000_000_000, 500_000_000_000);
+            Pallet::<Test>::do_swap(
+                netuid,
+                GetAlphaForTao::with_amount(1_000_000_000),
+                u64::MAX.into(),
+                false,
+                false,
+            )
+            .unwrap();
+
+            let positions = Swap::liquidity_positions(netuid, &COLDKEY);
+            assert_eq!(positions.len(), 1);
+            let info = &positions[0];
+            assert_eq!(info.position_id, u128::from(id));
+            assert_eq!(info.hotkey, HOTKEY);
+            assert_eq!(info.liquidity, 250_000_000_000);
+            assert_eq!(info.tao, TaoBalance::from(125_000_000_000_u64));
+            assert_eq!(info.alpha, AlphaBalance::from(500_000_000_000_u64));
+            assert!(info.fees_tao > 0.into());
+            assert_eq!(info.fees_alpha, 0.into());
+
+            assert!(Swap::liquidity_positions(netuid, &OK_COLDKEY_ACCOUNT_ID_2).is_empty());
+        });
+    }
+
+    #[test]
+    fn test_dissolve_user_liquidity_returns_positions() {
+        new_test_ext().execute_with(|| {
+            let netuid = setup();
+            let id = add(netuid, 125_000_000_000, 500_000_000_000);
+            UserLiquidityEnabled::<Test>::insert(netuid, false);
+
+            assert_ok!(Swap::dissolve_user_liquidity(netuid));
+
+            assert!(!LiquidityPositions::<Test>::contains_key((
+                netuid, COLDKEY, id
+            )));
+            assert_near(MockBalanceOps::tao_delta(COLDKEY), 0);
+            assert_near(MockBalanceOps::alpha_delta(COLDKEY, HOTKEY), 0);
+            assert!(!TotalLiquidityShares::<Test>::contains_key(netuid));
+            assert!(!UserLiquidityShares::<Test>::contains_key(netuid));
+            assert!(!UserLiquidityEnabled::<Test>::contains_key(netuid));
+        });
+    }
+}
+
+mod price_oracle {
+    use frame_support::traits::Hooks;
+
+    use super::*;
+
+    // The mock closes a candle every 10 blocks and keeps the last 4 closed candles.
+    fn setup() -> NetUid {
+        let netuid = NetUid::from(1);
+        TaoReserve::set_mock_reserve(netuid, TaoBalance::from(1_000_000_000_u64));
+        AlphaReserve::set_mock_reserve(netuid, AlphaBalance::from(1_000_000_000_u64));
+        assert_ok!(Pallet::<Test>::maybe_initialize_palswap(netuid, None));
+        netuid
+    }
+
+    fn sampled_price(netuid: NetUid) -> u64 {
+        Pallet::<Test>::current_price(netuid)
+            .saturating_mul(U64F64::from_num(1_000_000_000))
+            .to_num::<u64>()
+    }
+
+    fn run_blocks(blocks: core::ops::Range<u64>) {
+        for block in blocks {
+            Swap::on_initialize(block);
+        }
+    }
+
+    #[test]
+    fn test_price_candle_sampling_and_rollover() {
+        new_test_ext().execute_with(|| {
+            let netuid = setup();
+            let low = sampled_price(netuid);
+
+            run_blocks(0..4);
+            TaoReserve::set_mock_reserve(netuid, TaoBalance::from(2_000_000_000_u64));
+            let high = sampled_price(netuid);
+            run_blocks(4..6);
+            TaoReserve::set_mock_reserve(netuid, TaoBalance::from(1_500_000_000_u64));
+            let close = sampled_price(netuid);
+            run_blocks(6..10);
+
+            assert!(low < close && close < high);
+            let current = CurrentPriceCandle::<Test>::get(netuid).unwrap();
+            assert_eq!(current.start_block, 0);
+            assert_eq!(current.end_block, 9);
+            assert_eq!(current.open, low);
+            assert_eq!(current.high, high);
+            assert_eq!(current.low, low);
+            assert_eq!(current.close, close);
+            assert_eq!(
+                current.price_sum,
+                4 * low as u128 + 2 * high as u128 + 4 * close as u128
+            );
+            assert_eq!(PriceCandleCount::<Test>::get(netuid), 0);
+            assert_eq!(Pallet::<Test>::price_candle(netuid, 0), None);
+
+            // The first sample of the next period closes the candle
+            run_blocks(10..11);
+            assert_eq!(PriceCandleCount::<Test>::get(netuid), 1);
+            assert_eq!(Pallet::<Test>::price_candle(netuid, 0), Some(current));
+            let current = CurrentPriceCandle::<Test>::get(netuid).unwrap();
+            assert_eq!(current.start_block, 10);
+            assert_eq!(current.open, close);
+        });
+    }
+
+    #[test]
+    fn test_price_sampling_bounded_per_block() {
+        new_test_ext().execute_with(|| {
+            let netuids: Vec<NetUid> = (1..=3_u16).map(NetUid::from).collect();
+            for netuid in &netuids {
+                TaoReserve::set_mock_reserve(*netuid, TaoBalance::from(1_000_000_000_u64));
+                AlphaReserve::set_mock_reserve(*netuid, AlphaBalance::from(1_000_000_000_u64));
+                assert_ok!(Pallet::<Test>::maybe_initialize_palswap(*netuid, None));
+            }
+            let price = sampled_price(netuids[0]);
+
+            // The mock samples at most 2 pools per block
+            assert_eq!(
+                Swap::on_initialize(0),
+                <Test as Config>::WeightInfo::sample_price_candles(2)
+            );
+            assert_eq!(
+                netuids
+                    .iter()
+                    .filter(|netuid| CurrentPriceCandle::<Test>::contains_key(**netuid))
+                    .count(),
+                2
+            );
+
+            // The pool left out is sampled next, and every sample stands for the blocks since
+            // the previous sample of its pool
+            run_blocks(1..10);
+            for netuid in netuids {
+                let candle = CurrentPriceCandle::<Test>::get(netuid).unwrap();
+                assert!(candle.end_block >= 8);
+                assert_eq!(candle.price_sum, price as u128 * candle.blocks() as u128);
+            }
+        });
+    }
+
+    #[test]
+    fn test_price_candles_ring_buffer() {
+        new_test_ext().execute_with(|| {
+            let netuid = setup();
+
+            run_blocks(0..61);
+
+            assert_eq!(PriceCandleCount::<Test>::get(netuid), 6);
+            let candles = Pallet::<Test>::price_candles(netuid, 10);
+            assert_eq!(
+                candles.iter().map(|c| c.start_block).collect::<Vec<_>>(),
+                vec![20, 30, 40, 50]
+            );
+            assert_eq!(Pallet::<Test>::price_candles(netuid, 2).len(), 2);
+            assert_eq!(
+                Pallet::<Test>::price_candle(netuid, 0).unwrap().start_block,
+                50
+            );
+            assert_eq!(Pallet::<Test>::price_candle(netuid, 4), None);
+        });
+    }
+
+    #[test]
+    fn test_twap_price() {
+        new_test_ext().execute_with(|| {
+            let netuid = setup();
+            let first = sampled_price(netuid);
+
+            run_blocks(0..10);
+            assert_eq!(Pallet::<Test>::twap_price(netuid, 4), None);
+
+            TaoReserve::set_mock_reserve(netuid, TaoBalance::from(3_000_000_000_u64));
+            let second = sampled_price(netuid);
+            run_blocks(10..21);
+
+            assert_eq!(Pallet::<Test>::twap_price(netuid, 1), Some(second));
+            assert_eq!(
+                Pallet::<Test>::twap_price(netuid, 4),
+                Some((first + second) / 2)
+            );
+        });
+    }
+
+    #[test]
+    fn test_price_candle_tao_volume() {
+        new_test_ext().execute_with(|| {
+            let netuid = setup();
+            run_blocks(0..1);
+
+            // Simulations are rolled back and don't count
+            assert_ok!(Pallet::<Test>::do_swap(
+                netuid,
+                GetAlphaForTao::with_amount(1_000_000),
+                u64::MAX.into(),
+                false,
+                true
+            ));
+            assert_eq!(
+                CurrentPriceCandle::<Test>::get(netuid).unwrap().tao_volume,
+                0
+            );
+
+            assert_ok!(Pallet::<Test>::do_swap(
+                netuid,
+                GetAlphaForTao::with_amount(1_000_000),
+                u64::MAX.into(),
+                false,
+                false
+            ));
+            let sell = Pallet::<Test>::do_swap(
+                netuid,
+                GetTaoForAlpha::with_amount(1_000_000),
+                U64F64::from_num(0),
+                false,
+                false,
+            )
+            .unwrap();
+            assert_eq!(
+                CurrentPriceCandle::<Test>::get(netuid).unwrap().tao_volume,
+                1_000_000 + sell.amount_paid_out.to_u64()
+            );
+        });
+    }
+
+    #[test]
+    fn test_price_candles_cleared_with_protocol_liquidity() {
+        new_test_ext().execute_with(|| {
+            let netuid = setup();
+            run_blocks(0..21);
+
+            assert_ok!(Pallet::<Test>::do_clear_protocol_liquidity(netuid));
+
+            assert!(!CurrentPriceCandle::<Test>::contains_key(netuid));
+            assert!(!PriceCandleCount::<Test>::contains_key(netuid));
+            assert_eq!(PriceCandles::<Test>::iter_prefix(netuid).count(), 0);
+        });
+    }
+}
+
 #[test]
 fn test_swap_initialization() {
     new_test_ext().execute_with(|| {
@@ -305,6 +305,11 @@ impl<F, OU> SubtensorTxFeeHandler<F, OU> {
                 origin_netuid,
                 ..
             }) => alpha_vec.push((hotkey.clone(), *origin_netuid)),
+            Some(SubtensorCall::swap_stake_min_out {
+                hotkey,
+                origin_netuid,
+                ..
+            }) => alpha_vec.push((hotkey.clone(), *origin_netuid)),
             Some(SubtensorCall::recycle_alpha {
                 hotkey,
                 amount: _,
@@ -2,6 +2,7 @@ use core::marker::PhantomData;
 
 use crate::PrecompileExt;
 use fp_evm::{ExitError, PrecompileFailure};
+use frame_support::traits::Get;
 use pallet_evm::{BalanceConverter, PrecompileHandle, SubstrateBalance};
 use precompile_utils::EvmResult;
 use sp_runtime::{SaturatedConversion, Vec};
@@ -251,6 +252,73 @@ where
 
         Ok(price_eth)
     }
+
+    #[precompile::public("getPriceCandleCount(uint16)")]
+    #[precompile::view]
+    fn get_price_candle_count(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+        handle.record_db_reads::<R>(1)?;
+        Ok(pallet_subtensor_swap::PriceCandleCount::<R>::get(
+            NetUid::from(netuid),
+        ))
+    }
+
+    /// Closed price candle `age` periods back, 0 being the most recent one.
+    /// Returns start block, end block, open, high, low, close and TAO volume.
+    #[precompile::public("getPriceCandle(uint16,uint32)")]
+    #[precompile::view]
+    fn get_price_candle(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        age: u32,
+    ) -> EvmResult<(u64, u64, U256, U256, U256, U256, u64)> {
+        // PriceCandleCount + PriceCandles reads
+        handle.record_db_reads::<R>(2)?;
+        let candle = pallet_subtensor_swap::Pallet::<R>::price_candle(netuid.into(), age).ok_or(
+            PrecompileFailure::Error {
+                exit_status: ExitError::Other("Price candle not found".into()),
+            },
+        )?;
+
+        Ok((
+            candle.start_block,
+            candle.end_block,
+            Self::price_to_evm(candle.open)?,
+            Self::price_to_evm(candle.high)?,
+            Self::price_to_evm(candle.low)?,
+            Self::price_to_evm(candle.close)?,
+            candle.tao_volume,
+        ))
+    }
+
+    /// Time-weighted average alpha price over the `candles` most recently
+    /// closed candles, or 0 if none has been closed yet.
+    #[precompile::public("getTwapAlphaPrice(uint16,uint32)")]
+    #[precompile::view]
+    fn get_twap_alpha_price(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        candles: u32,
+    ) -> EvmResult<U256> {
+        let max_candles = <R as pallet_subtensor_swap::Config>::MaxPriceCandles::get();
+        // PriceCandleCount + PriceCandles reads
+        handle.record_db_reads::<R>(u64::from(candles.min(max_candles)).saturating_mul(2))?;
+        let twap = pallet_subtensor_swap::Pallet::<R>::twap_price(netuid.into(), candles)
+            .unwrap_or_default();
+
+        Self::price_to_evm(twap)
+    }
+}
+
+impl<R> AlphaPrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config,
+{
+    fn price_to_evm(price: u64) -> EvmResult<U256> {
+        let price: SubstrateBalance = price.into();
+        <R as pallet_evm::Config>::BalanceConverter::into_evm_balance(price)
+            .map(|amount| amount.into_u256())
+            .ok_or(ExitError::InvalidRange.into())
+    }
 }
 
 #[cfg(test)]
@@ -261,11 +329,13 @@ mod tests {
     use crate::PrecompileExt;
     use crate::mock::{
         Runtime, addr_from_index, alpha_price_to_evm, assert_static_call, new_test_ext,
-        precompiles, selector_u32,
+        precompiles, selector_u32, substrate_to_evm,
     };
-    use precompile_utils::solidity::encode_with_selector;
+    use precompile_utils::solidity::{encode_return_value, encode_with_selector};
+    use precompile_utils::testing::PrecompileTesterExt;
     use substrate_fixed::types::I96F32;
     use subtensor_runtime_common::{AlphaBalance, TaoBalance};
+    use subtensor_swap_interface::PriceCandle;
 
     const DYNAMIC_NETUID_U16: u16 = 1;
     const SUM_PRICE_NETUID_U16: u16 = 2;
@@ -613,4 +683,78 @@ mod tests {
             );
         });
     }
+
+    #[test]
+    fn alpha_precompile_reads_price_candles() {
+        new_test_ext().execute_with(|| {
+            let precompiles = precompiles::<AlphaPrecompile<Runtime>>();
+            let caller = addr_from_index(1);
+            let precompile_addr = addr_from_index(AlphaPrecompile::<Runtime>::INDEX);
+            let netuid = NetUid::from(DYNAMIC_NETUID_U16);
+
+            let older = PriceCandle {
+                start_block: 0,
+                end_block: 359,
+                open: 1_000_000_000,
+                high: 1_200_000_000,
+                low: 900_000_000,
+                close: 1_100_000_000,
+                price_sum: 360 * 1_000_000_000,
+                tao_volume: 5_000,
+            };
+            let newer = PriceCandle {
+                start_block: 360,
+                end_block: 719,
+                open: 1_100_000_000,
+                high: 2_100_000_000,
+                low: 1_100_000_000,
+                close: 2_000_000_000,
+                price_sum: 360 * 2_000_000_000,
+                tao_volume: 7_000,
+            };
+            pallet_subtensor_swap::PriceCandles::<Runtime>::insert(netuid, 0, older);
+            pallet_subtensor_swap::PriceCandles::<Runtime>::insert(netuid, 1, newer.clone());
+            pallet_subtensor_swap::PriceCandleCount::<Runtime>::insert(netuid, 2);
+
+            assert_static_call(
+                &precompiles,
+                caller,
+                precompile_addr,
+                encode_with_selector(
+                    selector_u32("getPriceCandleCount(uint16)"),
+                    (DYNAMIC_NETUID_U16,),
+                ),
+                2_u64.into(),
+            );
+            precompiles
+                .prepare_test(
+                    caller,
+                    precompile_addr,
+                    encode_with_selector(
+                        selector_u32("getPriceCandle(uint16,uint32)"),
+                        (DYNAMIC_NETUID_U16, 0_u32),
+                    ),
+                )
+                .with_static_call(true)
+                .execute_returns_raw(encode_return_value((
+                    newer.start_block,
+                    newer.end_block,
+                    substrate_to_evm(newer.open),
+                    substrate_to_evm(newer.high),
+                    substrate_to_evm(newer.low),
+                    substrate_to_evm(newer.close),
+                    newer.tao_volume,
+                )));
+            assert_static_call(
+                &precompiles,
+                caller,
+                precompile_addr,
+                encode_with_selector(
+                    selector_u32("getTwapAlphaPrice(uint16,uint32)"),
+                    (DYNAMIC_NETUID_U16, 10_u32),
+                ),
+                substrate_to_evm(1_500_000_000),
+            );
+        });
+    }
 }
@@ -0,0 +1,387 @@
+use core::marker::PhantomData;
+
+use fp_evm::{ExitError, PrecompileFailure};
+use frame_support::dispatch::{DispatchInfo, GetDispatchInfo, PostDispatchInfo};
+use frame_support::traits::{Get, IsSubType};
+use frame_system::RawOrigin;
+use pallet_evm::AddressMapping;
+use pallet_evm::PrecompileHandle;
+use pallet_limit_orders::{Order, OrderStatus, OrderType, VersionedOrder};
+use precompile_utils::EvmResult;
+use sp_core::{ByteArray, H256};
+use sp_runtime::{
+    AccountId32, Perbill,
+    traits::{AsSystemOriginSigner, Dispatchable},
+};
+use subtensor_runtime_common::NetUid;
+
+use crate::{PrecompileExt, PrecompileHandleExt};
+
+/// Limit orders precompile.
+///
+/// Lets a contract place and cancel orders in the on-chain resting book of
+/// `pallet_limit_orders`. The caller's mapped account is the order's signer,
+/// so no off-chain signature is needed. Orders placed here are `V1` one-shot
+/// orders without a relayer fee; the same arguments identify the order for
+/// `cancelOrder` and `getOrderId`.
+pub struct LimitOrdersPrecompile<R>(PhantomData<R>);
+
+impl<R> PrecompileExt<R::AccountId> for LimitOrdersPrecompile<R>
+where
+    R: frame_system::Config
+        + pallet_evm::Config
+        + pallet_limit_orders::Config
+        + pallet_subtensor::Config
+        + pallet_shield::Config
+        + pallet_subtensor_proxy::Config
+        + Send
+        + Sync
+        + scale_info::TypeInfo,
+    R::AccountId: From<[u8; 32]> + ByteArray,
+    <R as frame_system::Config>::RuntimeOrigin: AsSystemOriginSigner<R::AccountId> + Clone,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_limit_orders::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>
+        + IsSubType<pallet_subtensor::Call<R>>
+        + IsSubType<pallet_shield::Call<R>>
+        + IsSubType<pallet_subtensor_proxy::Call<R>>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+{
+    const INDEX: u64 = 2062;
+}
+
+#[precompile_utils::precompile]
+impl<R> LimitOrdersPrecompile<R>
+where
+    R: frame_system::Config
+        + pallet_evm::Config
+        + pallet_limit_orders::Config
+        + pallet_subtensor::Config
+        + pallet_shield::Config
+        + pallet_subtensor_proxy::Config
+        + Send
+        + Sync
+        + scale_info::TypeInfo,
+    R::AccountId: From<[u8; 32]> + ByteArray,
+    <R as frame_system::Config>::RuntimeOrigin: AsSystemOriginSigner<R::AccountId> + Clone,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_limit_orders::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>
+        + IsSubType<pallet_subtensor::Call<R>>
+        + IsSubType<pallet_shield::Call<R>>
+        + IsSubType<pallet_subtensor_proxy::Call<R>>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+{
+    /// Place an order in the resting book.
+    ///
+    /// # Arguments
+    /// * `hotkey` - The hotkey the alpha is staked to (bytes32)
+    /// * `netuid` - The subnet identifier (u16)
+    /// * `order_type` - 0 = LimitBuy, 1 = TakeProfit, 2 = StopLoss
+    /// * `amount` - TAO in for a buy, alpha in for a sell (RAO)
+    /// * `limit_price` - Trigger price in RAO per alpha
+    /// * `expiry` - Unix timestamp in milliseconds
+    /// * `max_slippage` - Swap price bound in parts per billion; 0 means no bound
+    #[precompile::public("placeOrder(bytes32,uint16,uint8,uint64,uint64,uint64,uint32)")]
+    #[precompile::payable]
+    #[allow(clippy::too_many_arguments)]
+    fn place_order(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: u16,
+        order_type: u8,
+        amount: u64,
+        limit_price: u64,
+        expiry: u64,
+        max_slippage: u32,
+    ) -> EvmResult<()> {
+        let who = handle.caller_account_id::<R>();
+        let order = Self::order(
+            who.clone(),
+            hotkey,
+            netuid,
+            order_type,
+            amount,
+            limit_price,
+            expiry,
+            max_slippage,
+        )?;
+        let call = pallet_limit_orders::Call::<R>::place_order { order };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(who))
+    }
+
+    /// Cancel an order placed with the same arguments by the caller.
+    #[precompile::public("cancelOrder(bytes32,uint16,uint8,uint64,uint64,uint64,uint32)")]
+    #[precompile::payable]
+    #[allow(clippy::too_many_arguments)]
+    fn cancel_order(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: u16,
+        order_type: u8,
+        amount: u64,
+        limit_price: u64,
+        expiry: u64,
+        max_slippage: u32,
+    ) -> EvmResult<()> {
+        let who = handle.caller_account_id::<R>();
+        let order = Self::order(
+            who.clone(),
+            hotkey,
+            netuid,
+            order_type,
+            amount,
+            limit_price,
+            expiry,
+            max_slippage,
+        )?;
+        let call = pallet_limit_orders::Call::<R>::cancel_order { order };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(who))
+    }
+
+    /// Get the `OrderId` of the order the caller would place with these
+    /// arguments.
+    #[precompile::public("getOrderId(bytes32,uint16,uint8,uint64,uint64,uint64,uint32)")]
+    #[precompile::view]
+    #[allow(clippy::too_many_arguments)]
+    fn get_order_id(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: u16,
+        order_type: u8,
+        amount: u64,
+        limit_price: u64,
+        expiry: u64,
+        max_slippage: u32,
+    ) -> EvmResult<H256> {
+        let order = Self::order(
+            handle.caller_account_id::<R>(),
+            hotkey,
+            netuid,
+            order_type,
+            amount,
+            limit_price,
+            expiry,
+            max_slippage,
+        )?;
+        Ok(pallet_limit_orders::Pallet::<R>::derive_order_id(&order))
+    }
+
+    /// Get the status of an order.
+    ///
+    /// # Returns
+    /// * `uint8` - 0 = unknown, 1 = resting or scheduled as a TWAP order,
+    ///   2 = partially filled, 3 = fulfilled, 4 = cancelled
+    /// * `uint64` - The amount filled so far (partially filled orders only)
+    #[precompile::public("getOrderStatus(bytes32)")]
+    #[precompile::view]
+    fn get_order_status(
+        handle: &mut impl PrecompileHandle,
+        order_id: H256,
+    ) -> EvmResult<(u8, u64)> {
+        handle.record_db_reads::<R>(3)?;
+        let status = match pallet_limit_orders::Orders::<R>::get(order_id) {
+            Some(OrderStatus::PartiallyFilled(filled)) => (2, filled),
+            Some(OrderStatus::Fulfilled) => (3, 0),
+            Some(OrderStatus::Cancelled) => (4, 0),
+            None if pallet_limit_orders::RestingOrders::<R>::contains_key(order_id)
+                || pallet_limit_orders::TwapOrders::<R>::contains_key(order_id) =>
+            {
+                (1, 0)
+            }
+            None => (0, 0),
+        };
+        Ok(status)
+    }
+}
+
+impl<R> LimitOrdersPrecompile<R>
+where
+    R: frame_system::Config + pallet_limit_orders::Config,
+{
+    #[allow(clippy::too_many_arguments)]
+    fn order(
+        signer: AccountId32,
+        hotkey: H256,
+        netuid: u16,
+        order_type: u8,
+        amount: u64,
+        limit_price: u64,
+        expiry: u64,
+        max_slippage: u32,
+    ) -> EvmResult<VersionedOrder<AccountId32>> {
+        let order_type = match order_type {
+            0 => OrderType::LimitBuy,
+            1 => OrderType::TakeProfit,
+            2 => OrderType::StopLoss,
+            _ => {
+                return Err(PrecompileFailure::Error {
+                    exit_status: ExitError::Other("Invalid order type".into()),
+                });
+            }
+        };
+
+        Ok(VersionedOrder::V1(Order {
+            fee_recipient: signer.clone(),
+            signer,
+            hotkey: hotkey.0.into(),
+            netuid: NetUid::from(netuid),
+            order_type,
+            amount,
+            limit_price,
+            expiry,
+            fee_rate: Perbill::zero(),
+            relayer: None,
+            max_slippage: (max_slippage != 0).then(|| Perbill::from_parts(max_slippage)),
+            chain_id: <R as pallet_limit_orders::Config>::ChainId::get(),
+            partial_fills_enabled: false,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use super::*;
+    use crate::PrecompileExt;
+    use crate::mock::{
+        AccountId, Runtime, RuntimeOrigin, addr_from_index, fund_account, mapped_account,
+        new_test_ext, precompiles, selector_u32,
+    };
+    use pallet_limit_orders::{RestingOrders, TwapOrders};
+    use precompile_utils::solidity::{encode_return_value, encode_with_selector};
+    use precompile_utils::testing::PrecompileTesterExt;
+    use sp_core::H160;
+
+    const PLACE: &str = "placeOrder(bytes32,uint16,uint8,uint64,uint64,uint64,uint32)";
+    const CANCEL: &str = "cancelOrder(bytes32,uint16,uint8,uint64,uint64,uint64,uint32)";
+
+    fn args() -> (H256, u16, u8, u64, u64, u64, u32) {
+        (
+            H256::repeat_byte(2),
+            1,
+            1,
+            1_000,
+            2_000_000_000,
+            u64::MAX,
+            0,
+        )
+    }
+
+    fn call(caller: H160, signature: &str) {
+        precompiles::<LimitOrdersPrecompile<Runtime>>()
+            .prepare_test(
+                caller,
+                addr_from_index(LimitOrdersPrecompile::<Runtime>::INDEX),
+                encode_with_selector(selector_u32(signature), args()),
+            )
+            .execute_returns(());
+    }
+
+    fn order_id(signer: AccountId) -> H256 {
+        let (hotkey, netuid, order_type, amount, limit_price, expiry, max_slippage) = args();
+        let order = LimitOrdersPrecompile::<Runtime>::order(
+            signer,
+            hotkey,
+            netuid,
+            order_type,
+            amount,
+            limit_price,
+            expiry,
+            max_slippage,
+        )
+        .expect("order type should be valid");
+        pallet_limit_orders::Pallet::<Runtime>::derive_order_id(&order)
+    }
+
+    fn assert_status(caller: H160, order_id: H256, expected: (u8, u64)) {
+        precompiles::<LimitOrdersPrecompile<Runtime>>()
+            .prepare_test(
+                caller,
+                addr_from_index(LimitOrdersPrecompile::<Runtime>::INDEX),
+                encode_with_selector(selector_u32("getOrderStatus(bytes32)"), (order_id,)),
+            )
+            .with_static_call(true)
+            .execute_returns_raw(encode_return_value(expected));
+    }
+
+    #[test]
+    fn get_order_id_matches_pallet_order_id() {
+        new_test_ext().execute_with(|| {
+            let caller = H160::from_low_u64_be(0x1234);
+
+            precompiles::<LimitOrdersPrecompile<Runtime>>()
+                .prepare_test(
+                    caller,
+                    addr_from_index(LimitOrdersPrecompile::<Runtime>::INDEX),
+                    encode_with_selector(
+                        selector_u32(
+                            "getOrderId(bytes32,uint16,uint8,uint64,uint64,uint64,uint32)",
+                        ),
+                        args(),
+                    ),
+                )
+                .with_static_call(true)
+                .execute_returns(order_id(mapped_account(caller)));
+        });
+    }
+
+    #[test]
+    fn place_and_cancel_order() {
+        new_test_ext().execute_with(|| {
+            pallet_limit_orders::LimitOrdersEnabled::<Runtime>::set(true);
+            let caller = H160::from_low_u64_be(0x1234);
+            let signer = mapped_account(caller);
+            fund_account(&signer, 1_000_000_000);
+            let id = order_id(signer);
+
+            call(caller, PLACE);
+            assert!(RestingOrders::<Runtime>::contains_key(id));
+            assert_status(caller, id, (1, 0));
+
+            call(caller, CANCEL);
+            assert!(!RestingOrders::<Runtime>::contains_key(id));
+            assert_status(caller, id, (4, 0));
+        });
+    }
+
+    #[test]
+    fn scheduled_twap_order_reported_as_resting() {
+        new_test_ext().execute_with(|| {
+            pallet_limit_orders::LimitOrdersEnabled::<Runtime>::set(true);
+            let caller = H160::from_low_u64_be(0x1234);
+            let signer = mapped_account(caller);
+            fund_account(&signer, 1_000_000_000);
+
+            let (hotkey, netuid, order_type, amount, limit_price, expiry, max_slippage) = args();
+            let mut order = LimitOrdersPrecompile::<Runtime>::order(
+                signer.clone(),
+                hotkey,
+                netuid,
+                order_type,
+                amount,
+                limit_price,
+                expiry,
+                max_slippage,
+            )
+            .expect("order type should be valid");
+            if let VersionedOrder::V1(inner) = &mut order {
+                inner.partial_fills_enabled = true;
+            }
+            let id = pallet_limit_orders::Pallet::<Runtime>::derive_order_id(&order);
+            pallet_limit_orders::Pallet::<Runtime>::place_twap_order(
+                RuntimeOrigin::signed(signer),
+                order,
+                2,
+                10,
+            )
+            .expect("TWAP order should be scheduled");
+
+            assert!(TwapOrders::<Runtime>::contains_key(id));
+            assert_status(caller, id, (1, 0));
+        });
+    }
+}
@@ -48,6 +48,7 @@ frame_support::construct_runtime!(
         Evm: pallet_evm = 12,
         AdminUtils: pallet_admin_utils = 13,
         EVMChainId: pallet_evm_chain_id = 14,
+        LimitOrders: pallet_limit_orders = 16,
     }
 );
 
@@ -72,10 +73,15 @@ parameter_types! {
     pub const MaximumBlockDuration: u64 = 100;
     pub const RefundContributorsLimit: u32 = 5;
     pub const MaxContributors: u32 = 10;
+    pub const LimitOrdersPalletId: PalletId = PalletId(*b"bt/limit");
+    pub const LimitOrdersRestingOrderDeposit: TaoBalance = TaoBalance::new(100);
     pub const SwapProtocolId: PalletId = PalletId(*b"ten/swap");
     pub const SwapMaxFeeRate: u16 = 10000;
     pub const SwapMinimumLiquidity: u64 = 1_000;
     pub const SwapMinimumReserve: NonZeroU64 = NonZeroU64::new(1_000_000).unwrap();
+    pub const SwapPriceCandlePeriod: u32 = 360;
+    pub const SwapMaxPriceCandles: u32 = 240;
+    pub const SwapMaxPriceSamplesPerBlock: u32 = 32;
     pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) *
         BlockWeights::get().max_block;
     pub const MaxScheduledPerBlock: u32 = 50;
@@ -158,6 +164,7 @@ parameter_types! {
     pub const SubtensorPalletId: PalletId = PalletId(*b"subtensr");
     pub const BurnAccountId: PalletId = PalletId(*b"burntnsr");
     pub const MaxEpochsPerBlock: u8 = 32;
+    pub const EpochHistoryLength: u32 = 20;
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
@@ -291,6 +298,34 @@ impl pallet_crowdloan::Config for Runtime {
     type MaxContributors = MaxContributors;
 }
 
+pub struct LimitOrdersPalletHotkey;
+impl frame_support::traits::Get<AccountId> for LimitOrdersPalletHotkey {
+    fn get() -> AccountId {
+        AccountId::new([0x42; 32])
+    }
+}
+
+pub struct LimitOrdersEvmAddressMapping;
+impl sp_runtime::traits::Convert<H160, AccountId> for LimitOrdersEvmAddressMapping {
+    fn convert(address: H160) -> AccountId {
+        <Runtime as pallet_evm::Config>::AddressMapping::into_account_id(address)
+    }
+}
+
+impl pallet_limit_orders::Config for Runtime {
+    type SwapInterface = SubtensorModule;
+    type TimeProvider = Timestamp;
+    type MaxOrdersPerBatch = ConstU32<64>;
+    type PalletId = LimitOrdersPalletId;
+    type PalletHotkey = LimitOrdersPalletHotkey;
+    type WeightInfo = ();
+    type ChainId = ConstU64<945>;
+    type MaxRestingOrdersPerBook = ConstU32<16>;
+    type RestingOrderDeposit = LimitOrdersRestingOrderDeposit;
+    type MaxTwapOrders = ConstU32<16>;
+    type EvmAddressMapping = LimitOrdersEvmAddressMapping;
+}
+
 impl pallet_subtensor_swap::Config for Runtime {
     type SubnetInfo = SubtensorModule;
     type BalanceOps = SubtensorModule;
@@ -300,6 +335,9 @@ impl pallet_subtensor_swap::Config for Runtime {
     type MaxFeeRate = SwapMaxFeeRate;
     type MinimumLiquidity = SwapMinimumLiquidity;
     type MinimumReserve = SwapMinimumReserve;
+    type PriceCandlePeriod = SwapPriceCandlePeriod;
+    type MaxPriceCandles = SwapMaxPriceCandles;
+    type MaxPriceSamplesPerBlock = SwapMaxPriceSamplesPerBlock;
     type WeightInfo = ();
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = ();
@@ -492,6 +530,7 @@ impl pallet_subtensor::Config for Runtime {
     type SubtensorPalletId = SubtensorPalletId;
     type BurnAccountId = BurnAccountId;
     type InitialMaxEpochsPerBlock = MaxEpochsPerBlock;
+    type EpochHistoryLength = EpochHistoryLength;
     type WeightInfo = ();
 }
 
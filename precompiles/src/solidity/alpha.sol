@@ -98,4 +98,44 @@ interface IAlpha {
     /// @dev Returns the CK burn rate.
     /// @return The CK burn rate.
     function getCKBurn() external view returns (uint256);
+
+    /// @dev Returns the number of price candles closed for a subnet.
+    /// @param netuid The subnet identifier.
+    /// @return The number of closed candles.
+    function getPriceCandleCount(uint16 netuid) external view returns (uint64);
+
+    /// @dev Returns a closed price candle of a subnet. Reverts if it is not stored.
+    /// @param netuid The subnet identifier.
+    /// @param age Number of periods back, 0 being the most recently closed candle.
+    /// @return startBlock The first block sampled.
+    /// @return endBlock The last block sampled.
+    /// @return open The opening alpha price.
+    /// @return high The highest alpha price.
+    /// @return low The lowest alpha price.
+    /// @return close The closing alpha price.
+    /// @return taoVolume The TAO bought and sold, fees included.
+    function getPriceCandle(
+        uint16 netuid,
+        uint32 age
+    )
+        external
+        view
+        returns (
+            uint64 startBlock,
+            uint64 endBlock,
+            uint256 open,
+            uint256 high,
+            uint256 low,
+            uint256 close,
+            uint64 taoVolume
+        );
+
+    /// @dev Returns the time-weighted average alpha price over the most recently closed candles.
+    /// @param netuid The subnet identifier.
+    /// @param candles The number of candles to average over.
+    /// @return The average alpha price, or 0 if no candle has been closed yet.
+    function getTwapAlphaPrice(
+        uint16 netuid,
+        uint32 candles
+    ) external view returns (uint256);
 }
@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+address constant ILIMIT_ORDERS_ADDRESS = 0x000000000000000000000000000000000000080E;
+
+interface ILimitOrders {
+    /**
+     * @dev Places an order in the on-chain resting book, signed by the caller.
+     *
+     * The order executes in full once the pool price crosses `limitPrice`.
+     * A deposit of `RestingOrderDeposit` TAO is held until the order leaves the book.
+     *
+     * @param hotkey The hotkey the alpha is staked to.
+     * @param netuid The subnet to trade on.
+     * @param orderType 0 = LimitBuy, 1 = TakeProfit, 2 = StopLoss.
+     * @param amount TAO in for a buy, alpha in for a sell (in RAO).
+     * @param limitPrice The trigger price in RAO per alpha.
+     * @param expiry The unix timestamp in milliseconds after which the order expires.
+     * @param maxSlippage The swap price bound in parts per billion; 0 means no bound.
+     */
+    function placeOrder(
+        bytes32 hotkey,
+        uint16 netuid,
+        uint8 orderType,
+        uint64 amount,
+        uint64 limitPrice,
+        uint64 expiry,
+        uint32 maxSlippage
+    ) external payable;
+
+    /**
+     * @dev Cancels an order placed by the caller with the same arguments.
+     */
+    function cancelOrder(
+        bytes32 hotkey,
+        uint16 netuid,
+        uint8 orderType,
+        uint64 amount,
+        uint64 limitPrice,
+        uint64 expiry,
+        uint32 maxSlippage
+    ) external payable;
+
+    /**
+     * @dev Returns the id of the order the caller would place with these arguments.
+     */
+    function getOrderId(
+        bytes32 hotkey,
+        uint16 netuid,
+        uint8 orderType,
+        uint64 amount,
+        uint64 limitPrice,
+        uint64 expiry,
+        uint32 maxSlippage
+    ) external view returns (bytes32);
+
+    /**
+     * @dev Returns the status of an order.
+     * @param orderId The id of the order.
+     * @return status 0 = unknown, 1 = resting or scheduled as a TWAP order, 2 = partially filled,
+     *         3 = fulfilled, 4 = cancelled.
+     * @return filled The amount filled so far, for a partially filled order.
+     */
+    function getOrderStatus(
+        bytes32 orderId
+    ) external view returns (uint8 status, uint64 filled);
+}
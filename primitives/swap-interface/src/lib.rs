@@ -3,6 +3,7 @@
 use core::ops::Neg;
 
 use frame_support::pallet_prelude::*;
+use frame_support::sp_runtime::Perbill;
 use substrate_fixed::types::U64F64;
 use subtensor_macros::freeze_struct;
 use subtensor_runtime_common::{AlphaBalance, NetUid, TaoBalance, Token};
@@ -48,6 +49,7 @@ pub trait SwapHandler {
         alpha_delta: AlphaBalance,
     ) -> (TaoBalance, AlphaBalance);
     fn clear_protocol_liquidity(netuid: NetUid) -> DispatchResult;
+    fn dissolve_user_liquidity(netuid: NetUid) -> DispatchResult;
     fn init_swap(netuid: NetUid, maybe_price: Option<U64F64>);
     fn get_alpha_amount_for_tao(netuid: NetUid, tao_amount: TaoBalance) -> AlphaBalance;
 }
@@ -195,7 +197,7 @@ where
 }
 
 /// Externally used swap result (for RPC)
-#[freeze_struct("6a03533fc53ccfb8")]
+#[freeze_struct("b9deb8086297b3fc")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
 pub struct SwapResult<PaidIn, PaidOut>
 where
@@ -206,6 +208,7 @@ where
     pub amount_paid_out: PaidOut,
     pub fee_paid: PaidIn,
     pub fee_to_block_author: PaidIn,
+    pub fee_to_liquidity_providers: PaidIn,
 }
 
 impl<PaidIn, PaidOut> SwapResult<PaidIn, PaidOut>
@@ -232,3 +235,50 @@ where
             .clamp(i64::MIN as i128, i64::MAX as i128) as i64
     }
 }
+
+/// Result of moving alpha between two subnets through TAO (for RPC)
+#[freeze_struct("c3248883ad495d93")]
+#[derive(Decode, Default, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct AlphaSwapResult {
+    /// Origin alpha sold, fee included
+    pub alpha_in: AlphaBalance,
+    /// TAO received for the origin alpha and staked on the destination subnet
+    pub tao_amount: TaoBalance,
+    /// Destination alpha received
+    pub alpha_out: AlphaBalance,
+    /// Fee paid in origin alpha
+    pub origin_fee: AlphaBalance,
+    /// Fee paid in TAO on the destination subnet
+    pub destination_fee: TaoBalance,
+    /// Shortfall of `alpha_out` against the value of `alpha_in` at the current prices,
+    /// fees included
+    pub price_impact: Perbill,
+}
+
+/// Open, high, low and close of the alpha price sampled over a range of blocks, and the TAO
+/// volume swapped in the meantime. Prices are in RAO per alpha.
+#[freeze_struct("8cb44a25f4055939")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, Default, TypeInfo, MaxEncodedLen)]
+pub struct PriceCandle {
+    /// Block of the first sample
+    pub start_block: u64,
+    /// Block of the last sample
+    pub end_block: u64,
+    pub open: u64,
+    pub high: u64,
+    pub low: u64,
+    pub close: u64,
+    /// Sum over the blocks of the candle of the latest price sampled
+    pub price_sum: u128,
+    /// TAO bought and sold, fees included
+    pub tao_volume: u64,
+}
+
+impl PriceCandle {
+    /// Number of blocks covered
+    pub fn blocks(&self) -> u64 {
+        self.end_block
+            .saturating_sub(self.start_block)
+            .saturating_add(1)
+    }
+}
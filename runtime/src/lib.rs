@@ -32,20 +32,28 @@ use frame_system::{EnsureRoot, EnsureRootWithSuccess, EnsureSigned};
 use pallet_commitments::{CanCommit, OnMetadataCommitment};
 use pallet_grandpa::{AuthorityId as GrandpaId, fg_primitives};
 pub use pallet_shield;
+use pallet_subtensor::epoch::history::EpochRecord;
 use pallet_subtensor::rpc_info::{
+    childkey_graph::ChildkeyGraph,
     delegate_info::DelegateInfo,
     dynamic_info::DynamicInfo,
+    epoch_simulation::EpochSimulation,
     metagraph::{Metagraph, SelectiveMetagraph},
     neuron_info::{NeuronInfo, NeuronInfoLite},
     show_subnet::SubnetState,
-    stake_info::{StakeAvailability, StakeInfo},
+    stake_info::{StakeAvailability, StakeInfo, StakeInfoWithCostBasis},
     subnet_info::{
         SubnetHyperparams, SubnetHyperparamsV2, SubnetHyperparamsV3, SubnetInfo, SubnetInfov2,
     },
+    validator_agreement::ValidatorAgreementReport,
+    weight_commits::WeightCommitStatus,
 };
-use pallet_subtensor::{CommitmentsInterface, ProxyInterface};
+use pallet_subtensor::utils::rate_limiting::Hyperparameter;
+use pallet_subtensor::{CommitmentsInterface, ProxyInterface, ScheduledHyperparameterChange};
 use pallet_subtensor_proxy as pallet_proxy;
-use pallet_subtensor_swap_runtime_api::{SimSwapResult, SubnetPrice};
+use pallet_subtensor_swap_runtime_api::{
+    AlphaSwapResult, LiquidityPositionInfo, PriceCandle, SimSwapResult, SubnetPrice,
+};
 use pallet_subtensor_utility as pallet_utility;
 use runtime_common::prod_or_fast;
 use safe_math::FixedExt;
@@ -234,7 +242,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     //   `spec_version`, and `authoring_version` are the same between Wasm and native.
     // This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
     //   the compatible custom types.
-    spec_version: 425,
+    spec_version: 426,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
     transaction_version: 1,
@@ -636,6 +644,7 @@ subtensor_macros::define_proxy_filters! {
         SubtensorModule::unstake_all_alpha,
         SubtensorModule::swap_stake,
         SubtensorModule::swap_stake_limit,
+        SubtensorModule::swap_stake_min_out,
         SubtensorModule::move_stake,
         SubtensorModule::transfer_stake,
         SubtensorModule::burned_register,
@@ -648,6 +657,8 @@ subtensor_macros::define_proxy_filters! {
         SubtensorModule::dispute_coldkey_swap,
         SubtensorModule::swap_hotkey,
         SubtensorModule::swap_hotkey_v2,
+        SubtensorModule::schedule_recurring_stake,
+        SubtensorModule::rebalance_portfolio,
     }
 
     Transfer => allow {
@@ -694,11 +705,16 @@ subtensor_macros::define_proxy_filters! {
         SubtensorModule::unstake_all_alpha,
         SubtensorModule::swap_stake,
         SubtensorModule::swap_stake_limit,
+        SubtensorModule::swap_stake_min_out,
         SubtensorModule::move_stake,
         SubtensorModule::add_stake_limit,
         SubtensorModule::remove_stake_limit,
         SubtensorModule::remove_stake_full_limit,
         SubtensorModule::set_root_claim_type,
+        SubtensorModule::schedule_recurring_stake,
+        SubtensorModule::cancel_recurring_stake,
+        SubtensorModule::rebalance_portfolio,
+        SubtensorModule::set_cost_basis_tracking,
     }
 
     Registration => allow {
@@ -1040,6 +1056,7 @@ parameter_types! {
     pub const SubtensorPalletId: PalletId = PalletId(*b"subtensr");
     pub const BurnAccountId: PalletId = PalletId(*b"burntnsr");
     pub const SubtensorMaxEpochsPerBlock: u8 = prod_or_fast!(2, 32);
+    pub const SubtensorEpochHistoryLength: u32 = 20;
 }
 
 impl pallet_subtensor::Config for Runtime {
@@ -1124,6 +1141,7 @@ impl pallet_subtensor::Config for Runtime {
     type SubtensorPalletId = SubtensorPalletId;
     type BurnAccountId = BurnAccountId;
     type InitialMaxEpochsPerBlock = SubtensorMaxEpochsPerBlock;
+    type EpochHistoryLength = SubtensorEpochHistoryLength;
     type WeightInfo = pallet_subtensor::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1132,6 +1150,9 @@ parameter_types! {
     pub const SwapMaxFeeRate: u16 = 10000; // 15.26%
     pub const SwapMinimumLiquidity: u64 = 1_000;
     pub const SwapMinimumReserve: NonZeroU64 = unsafe { NonZeroU64::new_unchecked(1_000_000) };
+    pub const SwapPriceCandlePeriod: u32 = 360;
+    pub const SwapMaxPriceCandles: u32 = 240;
+    pub const SwapMaxPriceSamplesPerBlock: u32 = 32;
 }
 
 impl pallet_subtensor_swap::Config for Runtime {
@@ -1143,6 +1164,9 @@ impl pallet_subtensor_swap::Config for Runtime {
     type MaxFeeRate = SwapMaxFeeRate;
     type MinimumLiquidity = SwapMinimumLiquidity;
     type MinimumReserve = SwapMinimumReserve;
+    type PriceCandlePeriod = SwapPriceCandlePeriod;
+    type MaxPriceCandles = SwapMaxPriceCandles;
+    type MaxPriceSamplesPerBlock = SwapMaxPriceSamplesPerBlock;
     type WeightInfo = pallet_subtensor_swap::weights::SubstrateWeight<Runtime>;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = SwapBenchmarkHelper;
@@ -1160,6 +1184,31 @@ impl pallet_subtensor_swap::BenchmarkHelper<AccountId> for SwapBenchmarkHelper {
     fn register_hotkey(hotkey: &AccountId, coldkey: &AccountId) {
         pallet_subtensor::Owner::<Runtime>::insert(hotkey, coldkey);
     }
+    fn setup_liquidity_provider(
+        coldkey: &AccountId,
+        hotkey: &AccountId,
+        netuid: subtensor_runtime_common::NetUid,
+        tao: TaoBalance,
+        alpha: AlphaBalance,
+    ) {
+        Self::setup_subnet(netuid);
+        Self::register_hotkey(hotkey, coldkey);
+        pallet_subtensor::SubnetMechanism::<Runtime>::insert(netuid, 1);
+        pallet_subtensor::SubnetTAO::<Runtime>::insert(
+            netuid,
+            TaoBalance::from(1_000_000_000_000_u64),
+        );
+        pallet_subtensor::SubnetAlphaIn::<Runtime>::insert(
+            netuid,
+            AlphaBalance::from(1_000_000_000_000_u64),
+        );
+        let balance = tao.saturating_add(ExistentialDeposit::get());
+        let credit = SubtensorModule::mint_tao(balance);
+        let _ = SubtensorModule::spend_tao(coldkey, credit, balance);
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            hotkey, coldkey, netuid, alpha,
+        );
+    }
 }
 
 use crate::sudo_wrapper::SudoTransactionExtension;
@@ -1482,6 +1531,9 @@ impl pallet_crowdloan::Config for Runtime {
 parameter_types! {
     pub const LimitOrdersPalletId: PalletId = PalletId(*b"bt/limit");
     pub const LimitOrdersMaxOrdersPerBatch: u32 = 100;
+    pub const LimitOrdersMaxRestingOrdersPerBook: u32 = 256;
+    pub const LimitOrdersRestingOrderDeposit: TaoBalance = TaoBalance::new(100_000_000); // 0.1 TAO
+    pub const LimitOrdersMaxTwapOrders: u32 = 256;
 }
 
 pub struct LimitOrdersPalletHotkey;
@@ -1491,6 +1543,16 @@ impl Get<AccountId> for LimitOrdersPalletHotkey {
     }
 }
 
+/// Accounts of EVM wallets signing limit orders, as `pallet_evm` maps them.
+pub struct LimitOrdersEvmAddressMapping;
+impl sp_runtime::traits::Convert<H160, AccountId> for LimitOrdersEvmAddressMapping {
+    fn convert(address: H160) -> AccountId {
+        <<Runtime as pallet_evm::Config>::AddressMapping as pallet_evm::AddressMapping<
+            AccountId,
+        >>::into_account_id(address)
+    }
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 pub struct LimitOrdersUnixTime;
 
@@ -1512,6 +1574,10 @@ impl pallet_limit_orders::Config for Runtime {
     type PalletHotkey = LimitOrdersPalletHotkey;
     type WeightInfo = pallet_limit_orders::weights::SubstrateWeight<Runtime>;
     type ChainId = ConfigurableChainId;
+    type MaxRestingOrdersPerBook = LimitOrdersMaxRestingOrdersPerBook;
+    type RestingOrderDeposit = LimitOrdersRestingOrderDeposit;
+    type MaxTwapOrders = LimitOrdersMaxTwapOrders;
+    type EvmAddressMapping = LimitOrdersEvmAddressMapping;
 }
 
 fn contracts_schedule<T: pallet_contracts::Config>() -> pallet_contracts::Schedule<T> {
@@ -2537,6 +2603,30 @@ impl_runtime_apis! {
         fn get_next_epoch_start_block(netuid: NetUid) -> Option<u64> {
             SubtensorModule::get_next_epoch_start_block(netuid)
         }
+
+        fn simulate_epoch(netuid: NetUid, mecid: MechId, override_weights: Option<Vec<(u16, Vec<(u16, u16)>)>>) -> Option<EpochSimulation<AccountId32>> {
+            SubtensorModule::simulate_epoch(netuid, mecid, override_weights)
+        }
+
+        fn get_epoch_history(netuid: NetUid, mecid: MechId) -> Vec<EpochRecord<AccountId32>> {
+            SubtensorModule::get_epoch_history(netuid, mecid)
+        }
+
+        fn get_weight_commit_status(hotkey: AccountId32, netuid: NetUid) -> Vec<WeightCommitStatus> {
+            SubtensorModule::get_weight_commit_status(hotkey, netuid)
+        }
+
+        fn get_validator_agreement(netuid: NetUid, mecid: MechId, similarity_threshold: u16) -> Option<ValidatorAgreementReport<AccountId32>> {
+            SubtensorModule::get_validator_agreement(netuid, mecid, similarity_threshold)
+        }
+
+        fn get_scheduled_hyperparameter_changes(netuid: NetUid) -> Vec<(Hyperparameter, ScheduledHyperparameterChange)> {
+            SubtensorModule::get_scheduled_hyperparameter_changes(netuid)
+        }
+
+        fn get_childkey_graph(netuid: NetUid) -> Option<ChildkeyGraph<AccountId32>> {
+            SubtensorModule::get_childkey_graph(netuid)
+        }
     }
 
     impl subtensor_custom_rpc_runtime_api::StakeInfoRuntimeApi<Block> for Runtime {
@@ -2564,6 +2654,15 @@ impl_runtime_apis! {
             SubtensorModule::get_coldkey_lock(&coldkey, netuid)
         }
 
+        fn get_coldkey_lock_projection(
+            coldkey: AccountId32,
+            netuid: NetUid,
+            blocks: Vec<u64>,
+            action: Option<pallet_subtensor::staking::lock::LockAction<AccountId32>>,
+        ) -> Result<Vec<pallet_subtensor::staking::lock::LockProjection>, sp_runtime::DispatchError> {
+            SubtensorModule::project_coldkey_lock(&coldkey, netuid, blocks, action)
+        }
+
         fn get_hotkey_conviction(hotkey: AccountId32, netuid: NetUid) -> U64F64 {
             SubtensorModule::hotkey_conviction(&hotkey, netuid)
         }
@@ -2571,6 +2670,14 @@ impl_runtime_apis! {
         fn get_most_convicted_hotkey_on_subnet(netuid: NetUid) -> Option<AccountId32> {
             SubtensorModule::subnet_king(netuid)
         }
+
+        fn get_recurring_stakes(coldkey: AccountId32) -> Vec<(u64, pallet_subtensor::RecurringStake<AccountId32>)> {
+            SubtensorModule::get_recurring_stakes(coldkey)
+        }
+
+        fn get_stake_info_with_cost_basis(coldkey: AccountId32) -> Vec<StakeInfoWithCostBasis> {
+            SubtensorModule::get_stake_info_with_cost_basis(coldkey)
+        }
     }
 
     impl subtensor_custom_rpc_runtime_api::SubnetRegistrationRuntimeApi<Block> for Runtime {
@@ -2726,6 +2833,66 @@ impl_runtime_apis! {
                 },
             )
         }
+
+        fn sim_swap_alpha_for_alpha(
+            origin_netuid: NetUid,
+            destination_netuid: NetUid,
+            alpha: AlphaBalance,
+        ) -> AlphaSwapResult {
+            SubtensorModule::sim_swap_alpha_for_alpha(origin_netuid, destination_netuid, alpha)
+                .unwrap_or_default()
+        }
+
+        fn sim_swap_alpha_for_alpha_limit(
+            origin_netuid: NetUid,
+            destination_netuid: NetUid,
+            alpha: AlphaBalance,
+            min_alpha_out: AlphaBalance,
+            allow_partial: bool,
+        ) -> AlphaSwapResult {
+            SubtensorModule::sim_swap_alpha_for_alpha_limit(
+                origin_netuid,
+                destination_netuid,
+                alpha,
+                min_alpha_out,
+                allow_partial,
+            )
+            .unwrap_or_default()
+        }
+
+        fn liquidity_positions(netuid: NetUid, coldkey: AccountId32) -> Vec<LiquidityPositionInfo<AccountId32>> {
+            pallet_subtensor_swap::Pallet::<Runtime>::liquidity_positions(netuid, &coldkey)
+        }
+
+        fn price_candles(netuid: NetUid, count: u32) -> Vec<PriceCandle> {
+            pallet_subtensor_swap::Pallet::<Runtime>::price_candles(netuid, count)
+        }
+
+        fn current_price_candle(netuid: NetUid) -> Option<PriceCandle> {
+            pallet_subtensor_swap::CurrentPriceCandle::<Runtime>::get(netuid)
+        }
+
+        fn twap_price(netuid: NetUid, candles: u32) -> Option<u64> {
+            pallet_subtensor_swap::Pallet::<Runtime>::twap_price(netuid, candles)
+        }
+    }
+
+    impl pallet_limit_orders_runtime_api::LimitOrdersRuntimeApi<Block> for Runtime {
+        fn order_status(order_id: H256) -> Option<pallet_limit_orders::OrderStatus> {
+            pallet_limit_orders::Orders::<Runtime>::get(order_id)
+        }
+
+        fn derive_order_id(order: pallet_limit_orders::VersionedOrder<AccountId32>) -> H256 {
+            LimitOrders::derive_order_id(&order)
+        }
+
+        fn simulate_execution(
+            netuid: NetUid,
+            orders: Vec<pallet_limit_orders::SignedOrder<AccountId32>>,
+            relayer: AccountId32,
+        ) -> Result<pallet_limit_orders::BatchExecutionSummary, sp_runtime::DispatchError> {
+            LimitOrders::simulate_execution(netuid, orders, relayer)
+        }
     }
 
     impl stp_shield::ShieldApi<Block> for Runtime {